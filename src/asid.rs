@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Address Space IDentifier allocation.
+//!
+//! [`Allocator`] hands out ASIDs to [`AddressSpace`]s. An address space keeps its ASID for as long
+//! as the ASID generation does not change. When all ASIDs of a generation are in use, the
+//! allocator starts a new generation with a single `TLBI VMALLE1IS`, keeping only the ASIDs that
+//! are active on a CPU at that moment. Address spaces that were not active get a new ASID the next
+//! time they are activated.
+//!
+//! The ASID is taken from `TTBR0_EL1`, so `TCR_EL1.A1` must be clear. `TCR_EL1.AS` must be set
+//! when using 16-bit ASIDs.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::asid::{AddressSpace, Allocator, AsidBits};
+//!
+//! static ASIDS: Allocator<4> = Allocator::new();
+//!
+//! ASIDS.init(AsidBits::supported());
+//!
+//! # let cpu = 0;
+//! # let table = 0x8_0000;
+//! let space = AddressSpace::new(table);
+//!
+//! // On context switch to a thread of `space`, with exceptions masked.
+//! ASIDS.activate(cpu, &space);
+//! ```
+
+use crate::{
+    asm::barrier,
+    lock::SpinMutex,
+    registers::{ID_AA64MMFR0_EL1, TTBR0_EL1},
+};
+use core::{
+    cell::UnsafeCell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// Number of bits of the ASID field in a context. Contexts hold the generation above it.
+const ASID_BITS: u32 = 16;
+const ASID_MASK: u64 = (1 << ASID_BITS) - 1;
+const FIRST_GENERATION: u64 = 1 << ASID_BITS;
+
+/// Size of the ASIDs supported by the implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AsidBits {
+    Bits8,
+    Bits16,
+}
+
+impl AsidBits {
+    /// Return the ASID size supported by the executing PE, as reported by
+    /// `ID_AA64MMFR0_EL1.ASIDBits`.
+    pub fn supported() -> AsidBits {
+        match ID_AA64MMFR0_EL1.read_as_enum(ID_AA64MMFR0_EL1::ASIDBits) {
+            Some(ID_AA64MMFR0_EL1::ASIDBits::Value::Bits_16) => AsidBits::Bits16,
+            _ => AsidBits::Bits8,
+        }
+    }
+
+    /// Return the number of ASIDs, including the reserved ASID 0.
+    pub const fn count(self) -> usize {
+        match self {
+            AsidBits::Bits8 => 1 << 8,
+            AsidBits::Bits16 => 1 << 16,
+        }
+    }
+}
+
+/// An address space that is tagged with an ASID while it is active.
+#[derive(Debug)]
+pub struct AddressSpace {
+    context: AtomicU64,
+    table: u64,
+}
+
+impl AddressSpace {
+    /// Create an address space with the translation table at physical address `table`, which
+    /// has not been assigned an ASID yet.
+    pub const fn new(table: u64) -> AddressSpace {
+        AddressSpace {
+            context: AtomicU64::new(0),
+            table,
+        }
+    }
+
+    /// Return the physical address of the translation table.
+    pub fn table(&self) -> u64 {
+        self.table
+    }
+
+    /// Return the ASID last assigned to the address space, which is only valid while it is
+    /// active.
+    pub fn asid(&self) -> Option<u16> {
+        match self.context.load(Ordering::Relaxed) {
+            0 => None,
+            context => Some((context & ASID_MASK) as u16),
+        }
+    }
+}
+
+struct State<const CPUS: usize> {
+    count: usize,
+    used: [u64; 1 << (ASID_BITS - 6)],
+    next: usize,
+    reserved: [u64; CPUS],
+}
+
+impl<const CPUS: usize> State<CPUS> {
+    fn test_and_set(&mut self, asid: usize) -> bool {
+        let word = &mut self.used[asid / 64];
+        let bit = 1 << (asid & 63);
+        let was_set = *word & bit != 0;
+        *word |= bit;
+
+        was_set
+    }
+
+    /// Return the first unused ASID in `start..end`.
+    fn find_unused(&self, start: usize, end: usize) -> Option<usize> {
+        let mut i = start;
+        while i < end {
+            let word = self.used[i / 64] | ((1 << (i & 63)) - 1);
+            if word != u64::MAX {
+                let asid = (i & !63) + (!word).trailing_zeros() as usize;
+                return Some(asid).filter(|&asid| asid < end);
+            }
+            i = (i & !63) + 64;
+        }
+
+        None
+    }
+}
+
+/// An ASID allocator for up to `CPUS` CPUs.
+///
+/// ASID 0 is never handed out, and can be used for global mappings or while switching.
+pub struct Allocator<const CPUS: usize> {
+    lock: SpinMutex,
+    generation: AtomicU64,
+    active: [AtomicU64; CPUS],
+    state: UnsafeCell<State<CPUS>>,
+}
+
+unsafe impl<const CPUS: usize> Sync for Allocator<CPUS> {}
+
+impl<const CPUS: usize> Allocator<CPUS> {
+    /// Create an allocator that uses 8-bit ASIDs until [`Allocator::init`] is called.
+    pub const fn new() -> Allocator<CPUS> {
+        let mut used = [0; 1 << (ASID_BITS - 6)];
+        used[0] = 1;
+
+        Allocator {
+            lock: SpinMutex::new(),
+            generation: AtomicU64::new(FIRST_GENERATION),
+            active: [const { AtomicU64::new(0) }; CPUS],
+            state: UnsafeCell::new(State {
+                count: AsidBits::Bits8.count(),
+                used,
+                next: 1,
+                reserved: [0; CPUS],
+            }),
+        }
+    }
+
+    /// Set the size of the ASIDs to hand out.
+    ///
+    /// Must be called before the first ASID is allocated.
+    pub fn init(&self, bits: AsidBits) {
+        self.lock.lock();
+        unsafe {
+            (*self.state.get()).count = bits.count();
+            self.lock.unlock();
+        }
+    }
+
+    /// Return the ASID that `space` is to be tagged with when activated on `cpu`.
+    ///
+    /// Calls `flush` with the allocator locked when a new generation begins. `flush` must then
+    /// invalidate all non-global TLB entries of all CPUs, and complete before it returns.
+    ///
+    /// Must be called with exceptions masked, and the returned ASID must be used on `cpu` until
+    /// the next allocation on it.
+    pub fn allocate<F: FnOnce()>(&self, cpu: usize, space: &AddressSpace, flush: F) -> u16 {
+        (self.allocate_context(cpu, &space.context, flush) & ASID_MASK) as u16
+    }
+
+    pub(crate) fn allocate_context<F: FnOnce()>(
+        &self,
+        cpu: usize,
+        context: &AtomicU64,
+        flush: F,
+    ) -> u64 {
+        let current = context.load(Ordering::Relaxed);
+        let generation = self.generation.load(Ordering::Acquire);
+
+        // A concurrent rollover clears the active context of every CPU, which makes the exchange
+        // fail and forces the slow path.
+        let active = self.active[cpu].load(Ordering::Relaxed);
+        if active != 0
+            && current & !ASID_MASK == generation
+            && self.active[cpu]
+                .compare_exchange(active, current, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            return current;
+        }
+
+        self.lock.lock();
+
+        let state = unsafe { &mut *self.state.get() };
+        let mut current = context.load(Ordering::Relaxed);
+        if current & !ASID_MASK != self.generation.load(Ordering::Relaxed) {
+            current = self.new_context(state, current, flush);
+            context.store(current, Ordering::Relaxed);
+        }
+        self.active[cpu].store(current, Ordering::Relaxed);
+
+        unsafe { self.lock.unlock() };
+
+        current
+    }
+
+    fn new_context<F: FnOnce()>(&self, state: &mut State<CPUS>, old: u64, flush: F) -> u64 {
+        let mut generation = self.generation.load(Ordering::Relaxed);
+
+        if old != 0 {
+            let asid = (old & ASID_MASK) as usize;
+            let new = generation | asid as u64;
+
+            // The ASID survived a rollover because the address space was active on a CPU.
+            let mut reserved = false;
+            for context in state.reserved.iter_mut().filter(|context| **context == old) {
+                *context = new;
+                reserved = true;
+            }
+
+            if reserved || !state.test_and_set(asid) {
+                return new;
+            }
+        }
+
+        let asid = match state
+            .find_unused(state.next, state.count)
+            .or_else(|| state.find_unused(1, state.next))
+        {
+            Some(asid) => asid,
+            None => {
+                generation += FIRST_GENERATION;
+                self.generation.store(generation, Ordering::Release);
+                self.rollover(state);
+                flush();
+
+                state.find_unused(1, state.count).unwrap()
+            }
+        };
+
+        state.test_and_set(asid);
+        state.next = asid;
+
+        generation | asid as u64
+    }
+
+    /// Free all ASIDs except those active on a CPU.
+    fn rollover(&self, state: &mut State<CPUS>) {
+        state.used = [0; 1 << (ASID_BITS - 6)];
+        state.used[0] = 1;
+
+        for (cpu, active) in self.active.iter().enumerate() {
+            // A CPU that has not switched since the previous rollover still runs with its
+            // reserved ASID.
+            let context = match active.swap(0, Ordering::Relaxed) {
+                0 => state.reserved[cpu],
+                context => context,
+            };
+
+            state.test_and_set((context & ASID_MASK) as usize);
+            state.reserved[cpu] = context;
+        }
+    }
+
+    /// Switch `cpu` to `space`, which must be the executing CPU.
+    ///
+    /// Allocates an ASID for `space` and writes it to `TTBR0_EL1` together with the address of the
+    /// translation table, after all TLB maintenance of a rollover has completed.
+    ///
+    /// Must be called with exceptions masked. The translation table must have been made visible to
+    /// the table walker beforehand.
+    pub fn activate(&self, cpu: usize, space: &AddressSpace) {
+        let asid = self.allocate(cpu, space, flush_tlb);
+
+        TTBR0_EL1.write(TTBR0_EL1::ASID.val(asid as u64) + TTBR0_EL1::BADDR.val(space.table >> 1));
+        barrier::isb(barrier::SY);
+    }
+}
+
+impl<const CPUS: usize> Default for Allocator<CPUS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Invalidate all stage 1 EL1&0 TLB entries of the Inner Shareable domain.
+fn flush_tlb() {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => unsafe {
+            core::arch::asm!(
+                "dsb ishst",
+                "tlbi vmalle1is",
+                "dsb ish",
+                "isb",
+                options(nostack)
+            )
+        },
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{boxed::Box, vec::Vec};
+
+    fn spaces(n: usize) -> Vec<AddressSpace> {
+        (0..n)
+            .map(|i| AddressSpace::new(i as u64 * 0x1000))
+            .collect()
+    }
+
+    #[test]
+    fn unique_within_generation() {
+        let asids: Box<Allocator<2>> = Box::default();
+        let spaces = spaces(255);
+        let mut seen = [false; 256];
+
+        for space in spaces.iter() {
+            let asid = asids.allocate(0, space, || panic!("rollover"));
+            assert_ne!(asid, 0);
+            assert!(!seen[asid as usize]);
+            seen[asid as usize] = true;
+        }
+
+        // Reactivation keeps the ASID.
+        for space in spaces.iter() {
+            let asid = space.asid();
+            assert_eq!(Some(asids.allocate(1, space, || panic!("rollover"))), asid);
+        }
+    }
+
+    #[test]
+    fn rollover() {
+        let asids: Box<Allocator<2>> = Box::default();
+        let spaces = spaces(300);
+        let mut flushes = 0;
+
+        let kept = asids.allocate(1, &spaces[0], || flushes += 1);
+        for space in spaces[1..255].iter() {
+            asids.allocate(0, space, || flushes += 1);
+        }
+        assert_eq!(flushes, 0);
+
+        // CPU 1 keeps running `spaces[0]` across the rollover.
+        let running = asids.allocate(0, &spaces[255], || flushes += 1);
+        assert_eq!(flushes, 1);
+        assert_eq!(
+            asids.generation.load(Ordering::Relaxed),
+            2 * FIRST_GENERATION
+        );
+
+        for space in spaces[256..].iter() {
+            let asid = asids.allocate(0, space, || flushes += 1);
+            assert_ne!(asid, kept);
+            assert_ne!(asid, running);
+        }
+        assert_eq!(flushes, 1);
+
+        // Both survive into the new generation.
+        assert_eq!(asids.allocate(1, &spaces[0], || flushes += 1), kept);
+        assert_eq!(asids.allocate(1, &spaces[255], || flushes += 1), running);
+        assert_eq!(flushes, 1);
+    }
+
+    #[test]
+    fn sixteen_bits() {
+        let asids: Box<Allocator<1>> = Box::default();
+        asids.init(AsidBits::Bits16);
+
+        let spaces = spaces(1000);
+        for (i, space) in spaces.iter().enumerate() {
+            assert_eq!(
+                asids.allocate(0, space, || panic!("rollover")),
+                i as u16 + 1
+            );
+        }
+    }
+}
@@ -9,19 +9,19 @@
 //! Wrappers around ARMv8-A instructions.
 
 pub mod barrier;
-
-#[cfg(feature = "nightly")]
+pub mod mte;
+pub mod pauth;
 pub mod random;
 
 /// The classic no-op
 #[inline(always)]
 pub fn nop() {
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
     unsafe {
         core::arch::asm!("nop", options(nomem, nostack))
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
@@ -30,12 +30,12 @@ pub fn nop() {
 /// For more details on wfi, refer to [here](http://infocenter.arm.com/help/index.jsp?topic=/com.arm.doc.dui0802a/CIHEGBBF.html).
 #[inline(always)]
 pub fn wfi() {
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
     unsafe {
         core::arch::asm!("wfi", options(nomem, nostack))
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
@@ -44,12 +44,12 @@ pub fn wfi() {
 /// For more details of wfe - sev pair, refer to [here](http://infocenter.arm.com/help/index.jsp?topic=/com.arm.doc.dui0802a/CIHEGBBF.html).
 #[inline(always)]
 pub fn wfe() {
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
     unsafe {
         core::arch::asm!("wfe", options(nomem, nostack))
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
@@ -57,6 +57,9 @@ pub fn wfe() {
 ///
 /// SEV causes an event to be signaled to the local core within a multiprocessor system.
 ///
+/// In AArch32 state, SEVL was added in ARMv8. It is emitted as its A32 or T32 encoding, which is
+/// a NOP hint on earlier cores.
+///
 /// For more details of wfe - sev/sevl pair, refer to [here](http://infocenter.arm.com/help/index.jsp?topic=/com.arm.doc.dui0802a/CIHEGBBF.html).
 #[inline(always)]
 pub fn sevl() {
@@ -65,7 +68,17 @@ pub fn sevl() {
         core::arch::asm!("sevl", options(nomem, nostack))
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(all(target_arch = "arm", not(target_feature = "thumb-mode")))]
+    unsafe {
+        core::arch::asm!(".inst 0xe320f005", options(nomem, nostack))
+    }
+
+    #[cfg(all(target_arch = "arm", target_feature = "thumb-mode"))]
+    unsafe {
+        core::arch::asm!(".inst.w 0xf3af8005", options(nomem, nostack))
+    }
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
@@ -76,18 +89,21 @@ pub fn sevl() {
 /// For more details of wfe - sev pair, refer to [here](http://infocenter.arm.com/help/index.jsp?topic=/com.arm.doc.dui0802a/CIHEGBBF.html).
 #[inline(always)]
 pub fn sev() {
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
     unsafe {
         core::arch::asm!("sev", options(nomem, nostack))
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
 /// Exception return
 ///
 /// Will jump to wherever the corresponding link register points to, and therefore never return.
+///
+/// In AArch32 state, ERET behaves like `SUBS PC, LR, #0` outside of Hyp mode. The A32 encoding
+/// requires the Virtualization Extensions or ARMv8, while in T32 both share one encoding.
 #[inline(always)]
 pub fn eret() -> ! {
     #[cfg(target_arch = "aarch64")]
@@ -96,7 +112,22 @@ pub fn eret() -> ! {
         core::hint::unreachable_unchecked()
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    // Emitted as its A32 encoding, as assemblers only accept ERET when targeting the
+    // Virtualization Extensions or ARMv8.
+    #[cfg(all(target_arch = "arm", not(target_feature = "thumb-mode")))]
+    unsafe {
+        core::arch::asm!(".inst 0xe160006e", options(nomem, nostack));
+        core::hint::unreachable_unchecked()
+    }
+
+    // In T32, ERET is the same encoding as `SUBS PC, LR, #0`, which all Thumb-2 cores implement.
+    #[cfg(all(target_arch = "arm", target_feature = "thumb-mode"))]
+    unsafe {
+        core::arch::asm!(".inst.w 0xf3de8f00", options(nomem, nostack));
+        core::hint::unreachable_unchecked()
+    }
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
 
@@ -111,6 +142,12 @@ pub fn ret() -> ! {
         core::hint::unreachable_unchecked()
     }
 
-    #[cfg(not(target_arch = "aarch64"))]
+    #[cfg(target_arch = "arm")]
+    unsafe {
+        core::arch::asm!("bx lr", options(nomem, nostack));
+        core::hint::unreachable_unchecked()
+    }
+
+    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
     unimplemented!()
 }
@@ -23,6 +23,27 @@ mod sealed {
 
 macro_rules! dmb_dsb {
     ($A:ident) => {
+        dmb_dsb!(
+            $A,
+            concat!("DMB ", stringify!($A)),
+            concat!("DSB ", stringify!($A)),
+            concat!("DMB ", stringify!($A)),
+            concat!("DSB ", stringify!($A))
+        );
+    };
+    // The load-only variants were added to AArch32 in ARMv8, and assemblers targeting earlier
+    // architectures reject them. They are emitted as A32 or T32 encodings with option `$opt`
+    // instead, which earlier cores execute as the full system barrier.
+    ($A:ident, $opt:literal) => {
+        dmb_dsb!(
+            $A,
+            concat!(".inst 0xf57ff05", $opt),
+            concat!(".inst 0xf57ff04", $opt),
+            concat!(".inst.w 0xf3bf8f5", $opt),
+            concat!(".inst.w 0xf3bf8f4", $opt)
+        );
+    };
+    ($A:ident, $dmb_a32:expr, $dsb_a32:expr, $dmb_t32:expr, $dsb_t32:expr) => {
         impl sealed::Dmb for $A {
             #[inline(always)]
             fn __dmb(&self) {
@@ -32,7 +53,13 @@ macro_rules! dmb_dsb {
                         core::arch::asm!(concat!("DMB ", stringify!($A)), options(nostack))
                     },
 
-                    #[cfg(not(target_arch = "aarch64"))]
+                    #[cfg(all(target_arch = "arm", not(target_feature = "thumb-mode")))]
+                    () => unsafe { core::arch::asm!($dmb_a32, options(nostack)) },
+
+                    #[cfg(all(target_arch = "arm", target_feature = "thumb-mode"))]
+                    () => unsafe { core::arch::asm!($dmb_t32, options(nostack)) },
+
+                    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
                     () => unimplemented!(),
                 }
             }
@@ -46,7 +73,13 @@ macro_rules! dmb_dsb {
                         core::arch::asm!(concat!("DSB ", stringify!($A)), options(nostack))
                     },
 
-                    #[cfg(not(target_arch = "aarch64"))]
+                    #[cfg(all(target_arch = "arm", not(target_feature = "thumb-mode")))]
+                    () => unsafe { core::arch::asm!($dsb_a32, options(nostack)) },
+
+                    #[cfg(all(target_arch = "arm", target_feature = "thumb-mode"))]
+                    () => unsafe { core::arch::asm!($dsb_t32, options(nostack)) },
+
+                    #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
                     () => unimplemented!(),
                 }
             }
@@ -69,25 +102,25 @@ pub struct OSHLD;
 
 dmb_dsb!(SY);
 dmb_dsb!(ST);
-dmb_dsb!(LD);
+dmb_dsb!(LD, "d");
 dmb_dsb!(ISH);
 dmb_dsb!(ISHST);
-dmb_dsb!(ISHLD);
+dmb_dsb!(ISHLD, "9");
 dmb_dsb!(NSH);
 dmb_dsb!(NSHST);
-dmb_dsb!(NSHLD);
+dmb_dsb!(NSHLD, "5");
 dmb_dsb!(OSH);
 dmb_dsb!(OSHST);
-dmb_dsb!(OSHLD);
+dmb_dsb!(OSHLD, "1");
 
 impl sealed::Isb for SY {
     #[inline(always)]
     fn __isb(&self) {
         match () {
-            #[cfg(target_arch = "aarch64")]
+            #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
             () => unsafe { core::arch::asm!("ISB SY", options(nostack)) },
 
-            #[cfg(not(target_arch = "aarch64"))]
+            #[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
             () => unimplemented!(),
         }
     }
@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Memory Tagging Extension instructions.
+//!
+//! Addresses carry their Logical Address Tag in bits\[59:56\]. Memory is tagged in granules of 16
+//! bytes. See [`crate::mte`] for range helpers built on top of these wrappers.
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::asm;
+
+/// Insert Random Tag.
+///
+/// Returns `addr` with a random Logical Address Tag that is neither set in `exclude` nor in
+/// `GCR_EL1.Exclude`.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub fn irg(addr: u64, exclude: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let tagged: u64;
+            unsafe {
+                asm!(
+                    ".arch_extension memtag",
+                    "irg {t}, {a}, {e}",
+                    t = out(reg) tagged,
+                    a = in(reg) addr,
+                    e = in(reg) exclude,
+                    options(nomem, nostack)
+                );
+            }
+            tagged
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Tag Mask Insert.
+///
+/// Returns `exclude` with the bit corresponding to the Logical Address Tag of `addr` set.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub fn gmi(addr: u64, exclude: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let mask: u64;
+            unsafe {
+                asm!(
+                    ".arch_extension memtag",
+                    "gmi {m}, {a}, {e}",
+                    m = out(reg) mask,
+                    a = in(reg) addr,
+                    e = in(reg) exclude,
+                    options(pure, nomem, nostack)
+                );
+            }
+            mask
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Add with Tag.
+///
+/// Adds `OFFSET` to `addr` and `TAG` to its Logical Address Tag, skipping tags excluded in
+/// `GCR_EL1.Exclude`. `OFFSET` must be a multiple of 16 in `0..=1008` and `TAG` must be in
+/// `0..=15`, otherwise assembly fails.
+///
+/// The result depends on `GCR_EL1`, so repeated calls with the same arguments are not merged.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub fn addg<const OFFSET: u16, const TAG: u8>(addr: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let tagged: u64;
+            unsafe {
+                asm!(
+                    ".arch_extension memtag",
+                    "addg {t}, {a}, #{o}, #{i}",
+                    t = out(reg) tagged,
+                    a = in(reg) addr,
+                    o = const OFFSET,
+                    i = const TAG,
+                    options(nomem, nostack)
+                );
+            }
+            tagged
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Subtract with Tag.
+///
+/// Subtracts `OFFSET` from `addr` and adds `TAG` to its Logical Address Tag, skipping tags
+/// excluded in `GCR_EL1.Exclude`. The same operand constraints as for [`addg`] apply.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub fn subg<const OFFSET: u16, const TAG: u8>(addr: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let tagged: u64;
+            unsafe {
+                asm!(
+                    ".arch_extension memtag",
+                    "subg {t}, {a}, #{o}, #{i}",
+                    t = out(reg) tagged,
+                    a = in(reg) addr,
+                    o = const OFFSET,
+                    i = const TAG,
+                    options(nomem, nostack)
+                );
+            }
+            tagged
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+macro_rules! store_tag {
+    ($(#[$attr:meta])* $name:ident, $insn:literal) => {
+        $(#[$attr])*
+        ///
+        /// # Safety
+        ///
+        /// `addr` must be 16-byte aligned and point to mapped, Tagged Normal memory that is not
+        /// concurrently accessed through differently tagged pointers.
+        #[inline(always)]
+        #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+        pub unsafe fn $name(addr: u64) {
+            match () {
+                #[cfg(target_arch = "aarch64")]
+                () => asm!(
+                    ".arch_extension memtag",
+                    concat!($insn, " {a}, [{a}]"),
+                    a = in(reg) addr,
+                    options(nostack)
+                ),
+
+                #[cfg(not(target_arch = "aarch64"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+store_tag!(
+    /// Store Allocation Tag. Sets the Allocation Tag of the granule at `addr` to the Logical
+    /// Address Tag of `addr`.
+    stg,
+    "stg"
+);
+
+store_tag!(
+    /// Store Allocation Tags. Like [`stg`], but for the two granules starting at `addr`.
+    st2g,
+    "st2g"
+);
+
+store_tag!(
+    /// Store Allocation Tag, Zeroing. Like [`stg`], and additionally zeroes the granule.
+    stzg,
+    "stzg"
+);
+
+store_tag!(
+    /// Store Allocation Tags, Zeroing. Like [`st2g`], and additionally zeroes both granules.
+    stz2g,
+    "stz2g"
+);
+
+/// Load Allocation Tag.
+///
+/// Returns `addr` with its Logical Address Tag replaced by the Allocation Tag of the granule at
+/// `addr`.
+///
+/// # Safety
+///
+/// `addr` must point to mapped memory.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub unsafe fn ldg(addr: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let mut tagged = addr;
+            asm!(
+                ".arch_extension memtag",
+                "ldg {t}, [{a}]",
+                t = inout(reg) tagged,
+                a = in(reg) addr,
+                options(readonly, nostack)
+            );
+            tagged
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Store Tag Multiple.
+///
+/// Writes the Allocation Tags of the naturally aligned block of `4 << GMID_EL1.BS` bytes
+/// containing `addr`. The tag for granule `n` of the block is taken from bits\[4n+3:4n\] of `tags`.
+///
+/// Only available at EL1 and above.
+///
+/// # Safety
+///
+/// The block must be mapped, Tagged Normal memory that is not concurrently accessed through
+/// differently tagged pointers.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub unsafe fn stgm(addr: u64, tags: u64) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => asm!(
+            ".arch_extension memtag",
+            "stgm {t}, [{a}]",
+            t = in(reg) tags,
+            a = in(reg) addr,
+            options(nostack)
+        ),
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Load Tag Multiple.
+///
+/// Reads the Allocation Tags of the naturally aligned block of `4 << GMID_EL1.BS` bytes
+/// containing `addr`, in the layout used by [`stgm`].
+///
+/// Only available at EL1 and above.
+///
+/// # Safety
+///
+/// The block must be mapped.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+pub unsafe fn ldgm(addr: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let tags: u64;
+            asm!(
+                ".arch_extension memtag",
+                "ldgm {t}, [{a}]",
+                t = out(reg) tags,
+                a = in(reg) addr,
+                options(readonly, nostack)
+            );
+            tags
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
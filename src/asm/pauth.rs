@@ -0,0 +1,387 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Pointer authentication.
+//!
+//! Keys are installed with [`set_key`] and enabled for the EL1&0 translation regime with
+//! [`enable_key`]. Signing, authenticating and stripping is done through a [`PointerAuth`] token,
+//! which can only be obtained when FEAT_PAuth is implemented.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::asm::{
+//!     pauth::{self, Key, KeyId, PointerAuth},
+//!     random::ArmRng,
+//! };
+//!
+//! let rng = ArmRng::new().unwrap();
+//! let key = Key::generate(&rng).unwrap();
+//!
+//! unsafe {
+//!     pauth::set_key(KeyId::DA, &key);
+//!     pauth::enable_key(KeyId::DA);
+//! }
+//!
+//! if let Some(pa) = PointerAuth::new() {
+//!     let ptr = 0xffff_0000_0008_0000;
+//!     let signed = pa.pacda(ptr, 0);
+//!
+//!     assert_eq!(pa.autda(signed, 0), Some(ptr));
+//!     assert_eq!(pa.xpacd(signed), ptr);
+//! }
+//! ```
+
+use super::random::ArmRng;
+use crate::{
+    asm::barrier,
+    registers::{
+        APDAKEYHI_EL1, APDAKEYLO_EL1, APDBKEYHI_EL1, APDBKEYLO_EL1, APGAKEYHI_EL1, APGAKEYLO_EL1,
+        APIAKEYHI_EL1, APIAKEYLO_EL1, APIBKEYHI_EL1, APIBKEYLO_EL1, SCTLR_EL1,
+    },
+};
+use tock_registers::interfaces::{ReadWriteable, Writeable};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::asm;
+
+/// One of the five pointer authentication keys.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KeyId {
+    /// Instruction key A.
+    IA,
+    /// Instruction key B.
+    IB,
+    /// Data key A.
+    DA,
+    /// Data key B.
+    DB,
+    /// Generic key A, used by `PACGA`.
+    GA,
+}
+
+/// A 128-bit pointer authentication key.
+///
+/// Intentionally does not implement `Debug` so that keys do not end up in logs.
+#[derive(Copy, Clone)]
+pub struct Key {
+    /// Bits\[63:0\] of the key.
+    pub lo: u64,
+    /// Bits\[127:64\] of the key.
+    pub hi: u64,
+}
+
+impl Key {
+    /// Generate a key from the Arm v8.5 RNG.
+    ///
+    /// Returns `None` if the RNG failed to deliver random numbers.
+    pub fn generate(rng: &ArmRng) -> Option<Self> {
+        Some(Key {
+            lo: rng.rndr()?,
+            hi: rng.rndr()?,
+        })
+    }
+}
+
+/// Install `key` as the key selected by `id`.
+///
+/// # Safety
+///
+/// Pointers signed with the previous key no longer authenticate. In particular, changing the IA or
+/// IB key while return addresses signed with it are live on the stack (e.g. code built with
+/// `-Z branch-protection=pac-ret`) causes the corresponding function returns to fail
+/// authentication. Install keys before enabling them, or from a function that never returns.
+pub unsafe fn set_key(id: KeyId, key: &Key) {
+    match id {
+        KeyId::IA => {
+            APIAKEYLO_EL1.set(key.lo);
+            APIAKEYHI_EL1.set(key.hi);
+        }
+        KeyId::IB => {
+            APIBKEYLO_EL1.set(key.lo);
+            APIBKEYHI_EL1.set(key.hi);
+        }
+        KeyId::DA => {
+            APDAKEYLO_EL1.set(key.lo);
+            APDAKEYHI_EL1.set(key.hi);
+        }
+        KeyId::DB => {
+            APDBKEYLO_EL1.set(key.lo);
+            APDBKEYHI_EL1.set(key.hi);
+        }
+        KeyId::GA => {
+            APGAKEYLO_EL1.set(key.lo);
+            APGAKEYHI_EL1.set(key.hi);
+        }
+    }
+
+    barrier::isb(barrier::SY);
+}
+
+/// Enable pointer authentication with the key selected by `id` in the EL1&0 translation regime.
+///
+/// The generic key has no enable control and is always in use, so [`KeyId::GA`] is a no-op.
+///
+/// # Safety
+///
+/// Enabling the IA or IB key while unsigned return addresses of functions built with
+/// `-Z branch-protection=pac-ret` are live on the stack causes those returns to fail
+/// authentication.
+pub unsafe fn enable_key(id: KeyId) {
+    match id {
+        KeyId::IA => SCTLR_EL1.modify(SCTLR_EL1::EnIA::Enable),
+        KeyId::IB => SCTLR_EL1.modify(SCTLR_EL1::EnIB::Enable),
+        KeyId::DA => SCTLR_EL1.modify(SCTLR_EL1::EnDA::Enable),
+        KeyId::DB => SCTLR_EL1.modify(SCTLR_EL1::EnDB::Enable),
+        KeyId::GA => return,
+    }
+
+    barrier::isb(barrier::SY);
+}
+
+/// Disable pointer authentication with the key selected by `id` in the EL1&0 translation regime.
+///
+/// [`KeyId::GA`] is a no-op.
+///
+/// # Safety
+///
+/// Disabling the IA or IB key while signed return addresses are live on the stack causes those
+/// returns to branch to the signed, non-canonical address.
+pub unsafe fn disable_key(id: KeyId) {
+    match id {
+        KeyId::IA => SCTLR_EL1.modify(SCTLR_EL1::EnIA::Disable),
+        KeyId::IB => SCTLR_EL1.modify(SCTLR_EL1::EnIB::Disable),
+        KeyId::DA => SCTLR_EL1.modify(SCTLR_EL1::EnDA::Disable),
+        KeyId::DB => SCTLR_EL1.modify(SCTLR_EL1::EnDB::Disable),
+        KeyId::GA => return,
+    }
+
+    barrier::isb(barrier::SY);
+}
+
+// The result depends on the keys in the APxxKey system registers, which `set_key` changes behind
+// the compiler's back, so the key-dependent operations must not be `pure`.
+macro_rules! pac_op {
+    ($(#[$attr:meta])* $name:ident, $insn:literal) => {
+        $(#[$attr])*
+        #[inline(always)]
+        #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+        pub fn $name(&self, ptr: u64, modifier: u64) -> u64 {
+            match () {
+                #[cfg(target_arch = "aarch64")]
+                () => {
+                    let mut ptr = ptr;
+                    unsafe {
+                        asm!(
+                            ".arch_extension pauth",
+                            concat!($insn, " {p}, {m}"),
+                            p = inout(reg) ptr,
+                            m = in(reg) modifier,
+                            options(nomem, nostack)
+                        );
+                    }
+                    ptr
+                }
+
+                #[cfg(not(target_arch = "aarch64"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+macro_rules! xpac_op {
+    ($(#[$attr:meta])* $name:ident, $insn:literal) => {
+        $(#[$attr])*
+        #[inline(always)]
+        #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+        pub fn $name(&self, ptr: u64) -> u64 {
+            match () {
+                #[cfg(target_arch = "aarch64")]
+                () => {
+                    let mut ptr = ptr;
+                    unsafe {
+                        asm!(
+                            ".arch_extension pauth",
+                            concat!($insn, " {p}"),
+                            p = inout(reg) ptr,
+                            options(pure, nomem, nostack)
+                        );
+                    }
+                    ptr
+                }
+
+                #[cfg(not(target_arch = "aarch64"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Token confirming that the PE implements address authentication.
+///
+/// Signing with a key that is not enabled in `SCTLR_EL1` returns the pointer unchanged.
+#[derive(Copy, Clone, Debug)]
+pub struct PointerAuth {
+    generic: bool,
+}
+
+impl PointerAuth {
+    /// Return a token if FEAT_PAuth is implemented.
+    #[cfg(target_arch = "aarch64")]
+    #[inline]
+    pub fn new() -> Option<Self> {
+        use crate::registers::{ID_AA64ISAR1_EL1, ID_AA64ISAR2_EL1};
+        use tock_registers::interfaces::Readable;
+
+        let isar1 = ID_AA64ISAR1_EL1.extract();
+        let isar2 = ID_AA64ISAR2_EL1.extract();
+
+        let address = isar1.read(ID_AA64ISAR1_EL1::APA) != 0
+            || isar1.read(ID_AA64ISAR1_EL1::API) != 0
+            || isar2.read(ID_AA64ISAR2_EL1::APA3) != 0;
+        let generic = isar1.read(ID_AA64ISAR1_EL1::GPA) != 0
+            || isar1.read(ID_AA64ISAR1_EL1::GPI) != 0
+            || isar2.read(ID_AA64ISAR2_EL1::GPA3) != 0;
+
+        if address {
+            Some(PointerAuth { generic })
+        } else {
+            None
+        }
+    }
+
+    #[cfg(not(target_arch = "aarch64"))]
+    pub fn new() -> Option<Self> {
+        None
+    }
+
+    /// Return whether generic authentication, i.e. [`pacga`](Self::pacga), is implemented.
+    #[inline]
+    pub fn has_generic(&self) -> bool {
+        self.generic
+    }
+
+    pac_op!(
+        /// Sign an instruction address with the IA key and `modifier`.
+        pacia,
+        "pacia"
+    );
+
+    pac_op!(
+        /// Sign an instruction address with the IB key and `modifier`.
+        pacib,
+        "pacib"
+    );
+
+    pac_op!(
+        /// Sign a data address with the DA key and `modifier`.
+        pacda,
+        "pacda"
+    );
+
+    pac_op!(
+        /// Sign a data address with the DB key and `modifier`.
+        pacdb,
+        "pacdb"
+    );
+
+    pac_op!(
+        /// Authenticate an instruction address with the IA key and `modifier`, without
+        /// checking the result.
+        ///
+        /// On failure, the returned pointer is non-canonical. With FEAT_FPAC, a failure
+        /// generates an exception instead.
+        autia_raw,
+        "autia"
+    );
+
+    pac_op!(
+        /// Authenticate a data address with the DA key and `modifier`, without checking the
+        /// result.
+        ///
+        /// On failure, the returned pointer is non-canonical. With FEAT_FPAC, a failure
+        /// generates an exception instead.
+        autda_raw,
+        "autda"
+    );
+
+    xpac_op!(
+        /// Strip the pointer authentication code from an instruction address.
+        xpaci,
+        "xpaci"
+    );
+
+    xpac_op!(
+        /// Strip the pointer authentication code from a data address.
+        xpacd,
+        "xpacd"
+    );
+
+    /// Authenticate an instruction address with the IA key and `modifier`.
+    ///
+    /// Returns the authenticated pointer, or `None` if authentication failed. With FEAT_FPAC, a
+    /// failure generates an exception instead.
+    #[inline(always)]
+    pub fn autia(&self, ptr: u64, modifier: u64) -> Option<u64> {
+        let auth = self.autia_raw(ptr, modifier);
+
+        if auth == self.xpaci(auth) {
+            Some(auth)
+        } else {
+            None
+        }
+    }
+
+    /// Authenticate a data address with the DA key and `modifier`.
+    ///
+    /// Returns the authenticated pointer, or `None` if authentication failed. With FEAT_FPAC, a
+    /// failure generates an exception instead.
+    #[inline(always)]
+    pub fn autda(&self, ptr: u64, modifier: u64) -> Option<u64> {
+        let auth = self.autda_raw(ptr, modifier);
+
+        if auth == self.xpacd(auth) {
+            Some(auth)
+        } else {
+            None
+        }
+    }
+
+    /// Compute a 32-bit generic authentication code for `value` with the GA key and `modifier`.
+    ///
+    /// The code is returned in the upper 32 bits. Returns `None` if generic authentication is not
+    /// implemented.
+    #[inline(always)]
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    pub fn pacga(&self, value: u64, modifier: u64) -> Option<u64> {
+        if !self.generic {
+            return None;
+        }
+
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => {
+                let code: u64;
+                unsafe {
+                    asm!(
+                        ".arch_extension pauth",
+                        "pacga {c}, {v}, {m}",
+                        c = out(reg) code,
+                        v = in(reg) value,
+                        m = in(reg) modifier,
+                        options(nomem, nostack)
+                    );
+                }
+                Some(code)
+            }
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
@@ -0,0 +1,370 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Frame-pointer based stack unwinding.
+//!
+//! Code compiled with frame pointers (e.g. `-C force-frame-pointers=yes`) stores a frame record
+//! of two double-words, the caller's frame pointer and the return address, at the address held in
+//! `x29`. [`Backtrace`] walks this chain of records.
+//!
+//! Every frame record is checked against caller-supplied stack ranges before it is read, so that
+//! a corrupted chain stops the walk instead of faulting. The reason for stopping is available
+//! from [`Backtrace::stop_reason`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::backtrace::Backtrace;
+//!
+//! extern "C" {
+//!     static __boot_core_stack_start: u8;
+//!     static __boot_core_stack_end_exclusive: u8;
+//! }
+//!
+//! let stack = unsafe {
+//!     &__boot_core_stack_start as *const u8 as u64
+//!         ..&__boot_core_stack_end_exclusive as *const u8 as u64
+//! };
+//! let stacks = [stack];
+//!
+//! for frame in unsafe { Backtrace::from_current(&stacks) } {
+//!     // Print frame.pc
+//! }
+//! ```
+
+use core::ops::Range;
+
+/// A stack frame found while unwinding.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// Return address of the frame, with any pointer authentication code removed. This is the
+    /// address of the instruction following the call, except for the first frame of
+    /// [`Backtrace::from_exception`], where it is the exception return address.
+    pub pc: u64,
+
+    /// Address of the frame record that held `pc`, or `0` for the first frame of
+    /// [`Backtrace::from_exception`].
+    pub fp: u64,
+}
+
+/// The reason why a [`Backtrace`] stopped producing frames.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StopReason {
+    /// The frame record chain was terminated by a null frame pointer.
+    End,
+
+    /// A frame record was not contained in any of the stack ranges.
+    OutOfBounds,
+
+    /// A frame pointer was not aligned to 8 bytes.
+    Misaligned,
+
+    /// A frame record did not lie above the previous one on the same stack, or pointed back into
+    /// a stack that was already left.
+    NotProgressing,
+}
+
+/// Removes a pointer authentication code from `addr` in software, for a virtual address size of
+/// `va_bits`.
+///
+/// Like `XPACI`, this replaces the bits above the virtual address with copies of bit 55, which
+/// selects between the lower and upper virtual address range. A `va_bits` of 64 or more leaves
+/// `addr` unchanged.
+#[inline]
+pub fn strip_pac(addr: u64, va_bits: u32) -> u64 {
+    let mask = (!0u64).checked_shl(va_bits).unwrap_or(0);
+
+    if addr & (1 << 55) != 0 {
+        addr | mask
+    } else {
+        addr & !mask
+    }
+}
+
+/// Removes a pointer authentication code from the instruction address `addr` using `XPACLRI`.
+///
+/// `XPACLRI` is in the hint space and executes as a NOP on PEs without pointer authentication, so
+/// this is safe to call unconditionally.
+#[inline(always)]
+fn xpaclri(addr: u64) -> u64 {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let mut addr = addr;
+            unsafe {
+                core::arch::asm!("hint #7", inout("x30") addr, options(nomem, nostack, preserves_flags));
+            }
+            addr
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => addr,
+    }
+}
+
+/// Iterator over the frames of a frame record chain.
+#[derive(Clone, Debug)]
+pub struct Backtrace<'a> {
+    fp: u64,
+    first_pc: Option<u64>,
+    stacks: &'a [Range<u64>],
+    current_stack: Option<usize>,
+    last_fp: u64,
+    left_stacks: u64,
+    va_bits: Option<u32>,
+    stop_reason: Option<StopReason>,
+}
+
+impl<'a> Backtrace<'a> {
+    /// Creates a backtrace that starts at the frame record pointed to by `fp`.
+    ///
+    /// At most 64 stack ranges are supported.
+    ///
+    /// # Safety
+    ///
+    /// All memory in `stacks` must be readable for the lifetime of the iterator.
+    pub unsafe fn new(fp: u64, stacks: &'a [Range<u64>]) -> Self {
+        Backtrace {
+            fp,
+            first_pc: None,
+            stacks: &stacks[..stacks.len().min(64)],
+            current_stack: None,
+            last_fp: 0,
+            left_stacks: 0,
+            va_bits: None,
+            stop_reason: None,
+        }
+    }
+
+    /// Creates a backtrace for a saved exception context.
+    ///
+    /// The first frame is the exception return address `elr`, followed by the frames found by
+    /// walking the frame records starting at the saved frame pointer `fp` (`x29`).
+    ///
+    /// # Safety
+    ///
+    /// All memory in `stacks` must be readable for the lifetime of the iterator.
+    pub unsafe fn from_exception(elr: u64, fp: u64, stacks: &'a [Range<u64>]) -> Self {
+        let mut backtrace = Self::new(fp, stacks);
+        backtrace.first_pc = Some(elr);
+
+        backtrace
+    }
+
+    /// Creates a backtrace of the caller, starting at the current frame pointer.
+    ///
+    /// # Safety
+    ///
+    /// All memory in `stacks` must be readable for the lifetime of the iterator.
+    #[inline(always)]
+    pub unsafe fn from_current(stacks: &'a [Range<u64>]) -> Self {
+        use tock_registers::interfaces::Readable;
+
+        Self::new(crate::registers::FP.get(), stacks)
+    }
+
+    /// Strips pointer authentication codes in software for a virtual address size of `va_bits`,
+    /// instead of using `XPACLRI`.
+    ///
+    /// This is needed when unwinding a stack of a different translation regime, or on a host.
+    pub fn with_va_bits(mut self, va_bits: u32) -> Self {
+        self.va_bits = Some(va_bits);
+        self
+    }
+
+    /// Returns why the iterator stopped, or `None` if it has not stopped yet.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason
+    }
+
+    fn strip(&self, addr: u64) -> u64 {
+        match self.va_bits {
+            Some(va_bits) => strip_pac(addr, va_bits),
+            None => xpaclri(addr),
+        }
+    }
+
+    /// Checks that the 16 byte frame record at `fp` lies within a stack range, and above the
+    /// previous one.
+    fn check(&mut self, fp: u64) -> Result<(), StopReason> {
+        if fp == 0 {
+            return Err(StopReason::End);
+        }
+        if fp % 8 != 0 {
+            return Err(StopReason::Misaligned);
+        }
+
+        let end = fp.checked_add(16).ok_or(StopReason::OutOfBounds)?;
+        let stack = self
+            .stacks
+            .iter()
+            .position(|s| s.start <= fp && end <= s.end)
+            .ok_or(StopReason::OutOfBounds)?;
+
+        match self.current_stack {
+            Some(current) if current == stack => {
+                if fp <= self.last_fp {
+                    return Err(StopReason::NotProgressing);
+                }
+            }
+            Some(current) => {
+                if self.left_stacks & (1 << stack) != 0 {
+                    return Err(StopReason::NotProgressing);
+                }
+                self.left_stacks |= 1 << current;
+            }
+            None => (),
+        }
+        self.current_stack = Some(stack);
+        self.last_fp = fp;
+
+        Ok(())
+    }
+}
+
+impl Iterator for Backtrace<'_> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        if let Some(pc) = self.first_pc.take() {
+            return Some(Frame { pc, fp: 0 });
+        }
+        if self.stop_reason.is_some() {
+            return None;
+        }
+
+        let fp = self.fp;
+        if let Err(reason) = self.check(fp) {
+            self.stop_reason = Some(reason);
+            return None;
+        }
+
+        // Safety: The record was checked to be within the stack ranges, which the creator of the
+        // iterator guaranteed to be readable.
+        let [next_fp, lr] = unsafe { core::ptr::read(fp as *const [u64; 2]) };
+        self.fp = next_fp;
+
+        // A zero return address terminates the chain, e.g. in the record set up by the boot code.
+        if lr == 0 {
+            self.stop_reason = Some(StopReason::End);
+            return None;
+        }
+
+        Some(Frame {
+            pc: self.strip(lr),
+            fp,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds frame records in `stack` and returns the address of the first one. Each entry of
+    /// `records` is the index in `stack` at which the record is placed, the index of the next
+    /// record (or `None` for the end) and the return address.
+    fn build(stack: &mut [u64; 32], records: &[(usize, Option<usize>, u64)]) -> u64 {
+        let base = stack.as_ptr() as u64;
+        for &(index, next, lr) in records {
+            stack[index] = next.map_or(0, |n| base + 8 * n as u64);
+            stack[index + 1] = lr;
+        }
+
+        base + 8 * records[0].0 as u64
+    }
+
+    fn range(stack: &[u64; 32]) -> Range<u64> {
+        let base = stack.as_ptr() as u64;
+        base..base + 8 * 32
+    }
+
+    #[test]
+    fn walks_chain() {
+        let mut stack = [0; 32];
+        let fp = build(
+            &mut stack,
+            &[
+                (2, Some(8), 0x1000),
+                (8, Some(20), 0x2000),
+                (20, None, 0x3000),
+            ],
+        );
+        let stacks = [range(&stack)];
+
+        let mut backtrace = unsafe { Backtrace::new(fp, &stacks) }.with_va_bits(48);
+        let pcs = [
+            backtrace.next().unwrap().pc,
+            backtrace.next().unwrap().pc,
+            backtrace.next().unwrap().pc,
+        ];
+        assert_eq!(pcs, [0x1000, 0x2000, 0x3000]);
+        assert_eq!(backtrace.next(), None);
+        assert_eq!(backtrace.stop_reason(), Some(StopReason::End));
+    }
+
+    #[test]
+    fn exception_context() {
+        let mut stack = [0; 32];
+        let fp = build(&mut stack, &[(4, None, 0x2000)]);
+        let stacks = [range(&stack)];
+
+        let mut backtrace = unsafe { Backtrace::from_exception(0x1234, fp, &stacks) };
+        assert_eq!(backtrace.next(), Some(Frame { pc: 0x1234, fp: 0 }));
+        assert_eq!(backtrace.next(), Some(Frame { pc: 0x2000, fp }));
+        assert_eq!(backtrace.next(), None);
+    }
+
+    #[test]
+    fn stops_on_corruption() {
+        let mut stack = [0; 32];
+        let fp = build(&mut stack, &[(8, Some(2), 0x1000), (2, Some(8), 0x2000)]);
+        let stacks = [range(&stack)];
+
+        let mut backtrace = unsafe { Backtrace::new(fp, &stacks) };
+        assert!(backtrace.next().is_some());
+        assert_eq!(backtrace.next(), None);
+        assert_eq!(backtrace.stop_reason(), Some(StopReason::NotProgressing));
+
+        let mut backtrace = unsafe { Backtrace::new(fp + 4, &stacks) };
+        assert_eq!(backtrace.next(), None);
+        assert_eq!(backtrace.stop_reason(), Some(StopReason::Misaligned));
+
+        let end = stacks[0].end;
+        let mut backtrace = unsafe { Backtrace::new(end - 8, &stacks) };
+        assert_eq!(backtrace.next(), None);
+        assert_eq!(backtrace.stop_reason(), Some(StopReason::OutOfBounds));
+    }
+
+    #[test]
+    fn switches_stacks_once() {
+        let mut irq_stack = [0; 32];
+        let mut task_stack = [0; 32];
+        let task_fp = build(&mut task_stack, &[(10, None, 0x3000)]);
+        let irq_fp = build(&mut irq_stack, &[(2, None, 0x1000)]);
+        irq_stack[2] = task_fp;
+        let stacks = [range(&irq_stack), range(&task_stack)];
+
+        let backtrace = unsafe { Backtrace::new(irq_fp, &stacks) };
+        assert_eq!(backtrace.map(|f| f.pc).sum::<u64>(), 0x4000);
+
+        // Pointing back into the interrupt stack is rejected.
+        task_stack[10] = irq_fp;
+        let stacks = [range(&irq_stack), range(&task_stack)];
+        let mut backtrace = unsafe { Backtrace::new(irq_fp, &stacks) };
+        assert_eq!(backtrace.by_ref().count(), 2);
+        assert_eq!(backtrace.stop_reason(), Some(StopReason::NotProgressing));
+    }
+
+    #[test]
+    fn strips_pac() {
+        assert_eq!(strip_pac(0x007f_0000_0040_1000, 48), 0x0000_0000_0040_1000);
+        assert_eq!(strip_pac(0xff8f_ffff_0040_1000, 48), 0xffff_ffff_0040_1000);
+        assert_eq!(strip_pac(0x0012_0000_0040_1000, 52), 0x0002_0000_0040_1000);
+        assert_eq!(strip_pac(0x0012_0000_0040_1000, 64), 0x0012_0000_0040_1000);
+    }
+}
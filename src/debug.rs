@@ -0,0 +1,409 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Self-hosted debug.
+//!
+//! Programming of hardware breakpoints and watchpoints, and software step control, for debuggers
+//! running on the target itself.
+//!
+//! Debug exceptions are only generated if they are enabled with [`enable`] and not masked by
+//! `PSTATE.D` (see [`DAIF`](crate::registers::DAIF)).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::debug::{self, Access, ExceptionLevels};
+//!
+//! debug::enable();
+//!
+//! // Break when EL1 executes the instruction at this address.
+//! debug::set_breakpoint(0, 0xffff_0000_0008_1000, ExceptionLevels::El1).unwrap();
+//!
+//! // Trap any 4 byte store by EL0 to this address.
+//! debug::set_watchpoint(0, 0x4_1000, 4, Access::Store, ExceptionLevels::El0).unwrap();
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{
+        DBGBCR0_EL1, DBGBCR10_EL1, DBGBCR11_EL1, DBGBCR12_EL1, DBGBCR13_EL1, DBGBCR14_EL1,
+        DBGBCR15_EL1, DBGBCR1_EL1, DBGBCR2_EL1, DBGBCR3_EL1, DBGBCR4_EL1, DBGBCR5_EL1, DBGBCR6_EL1,
+        DBGBCR7_EL1, DBGBCR8_EL1, DBGBCR9_EL1, DBGBVR0_EL1, DBGBVR10_EL1, DBGBVR11_EL1,
+        DBGBVR12_EL1, DBGBVR13_EL1, DBGBVR14_EL1, DBGBVR15_EL1, DBGBVR1_EL1, DBGBVR2_EL1,
+        DBGBVR3_EL1, DBGBVR4_EL1, DBGBVR5_EL1, DBGBVR6_EL1, DBGBVR7_EL1, DBGBVR8_EL1, DBGBVR9_EL1,
+        DBGWCR0_EL1, DBGWCR10_EL1, DBGWCR11_EL1, DBGWCR12_EL1, DBGWCR13_EL1, DBGWCR14_EL1,
+        DBGWCR15_EL1, DBGWCR1_EL1, DBGWCR2_EL1, DBGWCR3_EL1, DBGWCR4_EL1, DBGWCR5_EL1, DBGWCR6_EL1,
+        DBGWCR7_EL1, DBGWCR8_EL1, DBGWCR9_EL1, DBGWVR0_EL1, DBGWVR10_EL1, DBGWVR11_EL1,
+        DBGWVR12_EL1, DBGWVR13_EL1, DBGWVR14_EL1, DBGWVR15_EL1, DBGWVR1_EL1, DBGWVR2_EL1,
+        DBGWVR3_EL1, DBGWVR4_EL1, DBGWVR5_EL1, DBGWVR6_EL1, DBGWVR7_EL1, DBGWVR8_EL1, DBGWVR9_EL1,
+        ID_AA64DFR0_EL1, MDSCR_EL1, OSDLR_EL1, OSLAR_EL1, SPSR_EL1,
+    },
+};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// Writes `$value` to the register with number `$index` out of the given register family.
+macro_rules! write_indexed {
+    ($index:expr, $value:expr, [$($reg:ident),*]) => {{
+        let regs: [fn(u64); 16] = [$(|v| $reg.set(v)),*];
+        regs[$index as usize]($value)
+    }};
+}
+
+/// Errors reported when programming a breakpoint or watchpoint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The requested breakpoint or watchpoint is not implemented.
+    InvalidIndex,
+
+    /// The address does not have the alignment required by the breakpoint or watchpoint.
+    Misaligned,
+
+    /// The watched region can not be described by a single watchpoint. It must either be 1 to 8
+    /// bytes large and not cross a double-word boundary, or be a power of two of at least 8 bytes
+    /// in size and aligned to its size.
+    UnsupportedLength,
+}
+
+/// Exception levels at which a breakpoint or watchpoint generates a debug event.
+///
+/// The variants map to the `HMC`, `SSC` and `PMC`/`PAC` fields of `DBGBCR<n>_EL1` and
+/// `DBGWCR<n>_EL1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExceptionLevels {
+    El0,
+    El1,
+    El0El1,
+    /// Non-secure EL2.
+    El2,
+    /// EL0, EL1, EL2 and EL3, in both Security states.
+    All,
+}
+
+impl ExceptionLevels {
+    /// Returns the `HMC`, `SSC` and `PMC`/`PAC` bits, which are at the same position in
+    /// `DBGBCR<n>_EL1` and `DBGWCR<n>_EL1`.
+    fn bits(self) -> u64 {
+        let (hmc, ssc, pmc) = match self {
+            ExceptionLevels::El0 => (0, 0b00, 0b10),
+            ExceptionLevels::El1 => (0, 0b00, 0b01),
+            ExceptionLevels::El0El1 => (0, 0b00, 0b11),
+            ExceptionLevels::El2 => (1, 0b01, 0b00),
+            ExceptionLevels::All => (1, 0b00, 0b11),
+        };
+
+        (hmc << 13) | (ssc << 14) | (pmc << 1)
+    }
+}
+
+/// Type of data access that triggers a watchpoint.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Access {
+    Load,
+    Store,
+    LoadStore,
+}
+
+/// Returns the number of implemented hardware breakpoints.
+#[inline]
+pub fn num_breakpoints() -> u8 {
+    ID_AA64DFR0_EL1.read(ID_AA64DFR0_EL1::BRPs) as u8 + 1
+}
+
+/// Returns the number of implemented hardware watchpoints.
+#[inline]
+pub fn num_watchpoints() -> u8 {
+    ID_AA64DFR0_EL1.read(ID_AA64DFR0_EL1::WRPs) as u8 + 1
+}
+
+/// Unlocks the OS Lock and OS Double Lock, and enables Breakpoint, Watchpoint and Software Step
+/// exceptions at the current Exception level.
+#[inline]
+pub fn enable() {
+    OSDLR_EL1.write(OSDLR_EL1::DLK::Unlocked);
+    OSLAR_EL1.write(OSLAR_EL1::OSLK::Unlocked);
+    MDSCR_EL1.modify(MDSCR_EL1::MDE::Enable + MDSCR_EL1::KDE::Enable);
+    barrier::isb(barrier::SY);
+}
+
+/// Disables Breakpoint, Watchpoint and Software Step exceptions.
+#[inline]
+pub fn disable() {
+    MDSCR_EL1.modify(MDSCR_EL1::MDE::Disable + MDSCR_EL1::KDE::Disable);
+    barrier::isb(barrier::SY);
+}
+
+/// Returns the `DBGBCR<n>_EL1` value of an enabled instruction address breakpoint.
+fn breakpoint_control(levels: ExceptionLevels) -> u64 {
+    let bas = 0b1111 << 5;
+
+    levels.bits() | bas | 1
+}
+
+/// Returns the `DBGWVR<n>_EL1` and `DBGWCR<n>_EL1` values of an enabled watchpoint that watches
+/// `len` bytes starting at `addr`.
+fn watchpoint_value_control(
+    addr: u64,
+    len: u64,
+    access: Access,
+    levels: ExceptionLevels,
+) -> Result<(u64, u64), Error> {
+    let lsc = match access {
+        Access::Load => 0b01,
+        Access::Store => 0b10,
+        Access::LoadStore => 0b11,
+    };
+    let control = levels.bits() | (lsc << 3) | 1;
+
+    match len {
+        1..=8 => {
+            let offset = addr & 0b111;
+            if offset + len > 8 {
+                return Err(Error::UnsupportedLength);
+            }
+            let bas = ((1 << len) - 1) << offset;
+
+            Ok((addr & !0b111, control | (bas << 5)))
+        }
+        _ if len.is_power_of_two() && len <= 1 << 31 => {
+            if addr & (len - 1) != 0 {
+                return Err(Error::Misaligned);
+            }
+            let mask = len.trailing_zeros() as u64;
+
+            Ok((addr, control | (mask << 24) | (0xff << 5)))
+        }
+        _ => Err(Error::UnsupportedLength),
+    }
+}
+
+/// Installs hardware breakpoint `index` on the instruction at `addr`.
+#[inline]
+pub fn set_breakpoint(index: u8, addr: u64, levels: ExceptionLevels) -> Result<(), Error> {
+    if index >= num_breakpoints() {
+        return Err(Error::InvalidIndex);
+    }
+    if addr & 0b11 != 0 {
+        return Err(Error::Misaligned);
+    }
+
+    clear_breakpoint(index)?;
+    write_indexed!(
+        index,
+        addr,
+        [
+            DBGBVR0_EL1,
+            DBGBVR1_EL1,
+            DBGBVR2_EL1,
+            DBGBVR3_EL1,
+            DBGBVR4_EL1,
+            DBGBVR5_EL1,
+            DBGBVR6_EL1,
+            DBGBVR7_EL1,
+            DBGBVR8_EL1,
+            DBGBVR9_EL1,
+            DBGBVR10_EL1,
+            DBGBVR11_EL1,
+            DBGBVR12_EL1,
+            DBGBVR13_EL1,
+            DBGBVR14_EL1,
+            DBGBVR15_EL1
+        ]
+    );
+    write_breakpoint_control(index, breakpoint_control(levels));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Removes hardware breakpoint `index`.
+#[inline]
+pub fn clear_breakpoint(index: u8) -> Result<(), Error> {
+    if index >= num_breakpoints() {
+        return Err(Error::InvalidIndex);
+    }
+
+    write_breakpoint_control(index, 0);
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+fn write_breakpoint_control(index: u8, value: u64) {
+    write_indexed!(
+        index,
+        value,
+        [
+            DBGBCR0_EL1,
+            DBGBCR1_EL1,
+            DBGBCR2_EL1,
+            DBGBCR3_EL1,
+            DBGBCR4_EL1,
+            DBGBCR5_EL1,
+            DBGBCR6_EL1,
+            DBGBCR7_EL1,
+            DBGBCR8_EL1,
+            DBGBCR9_EL1,
+            DBGBCR10_EL1,
+            DBGBCR11_EL1,
+            DBGBCR12_EL1,
+            DBGBCR13_EL1,
+            DBGBCR14_EL1,
+            DBGBCR15_EL1
+        ]
+    );
+}
+
+/// Installs hardware watchpoint `index` on the `len` bytes starting at `addr`.
+///
+/// Regions of 1 to 8 bytes are watched using byte address select and must not cross a
+/// double-word boundary. Larger regions must be a power of two in size, at most 2GiB, and aligned
+/// to their size.
+#[inline]
+pub fn set_watchpoint(
+    index: u8,
+    addr: u64,
+    len: u64,
+    access: Access,
+    levels: ExceptionLevels,
+) -> Result<(), Error> {
+    if index >= num_watchpoints() {
+        return Err(Error::InvalidIndex);
+    }
+    let (value, control) = watchpoint_value_control(addr, len, access, levels)?;
+
+    clear_watchpoint(index)?;
+    write_indexed!(
+        index,
+        value,
+        [
+            DBGWVR0_EL1,
+            DBGWVR1_EL1,
+            DBGWVR2_EL1,
+            DBGWVR3_EL1,
+            DBGWVR4_EL1,
+            DBGWVR5_EL1,
+            DBGWVR6_EL1,
+            DBGWVR7_EL1,
+            DBGWVR8_EL1,
+            DBGWVR9_EL1,
+            DBGWVR10_EL1,
+            DBGWVR11_EL1,
+            DBGWVR12_EL1,
+            DBGWVR13_EL1,
+            DBGWVR14_EL1,
+            DBGWVR15_EL1
+        ]
+    );
+    write_watchpoint_control(index, control);
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Removes hardware watchpoint `index`.
+#[inline]
+pub fn clear_watchpoint(index: u8) -> Result<(), Error> {
+    if index >= num_watchpoints() {
+        return Err(Error::InvalidIndex);
+    }
+
+    write_watchpoint_control(index, 0);
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+fn write_watchpoint_control(index: u8, value: u64) {
+    write_indexed!(
+        index,
+        value,
+        [
+            DBGWCR0_EL1,
+            DBGWCR1_EL1,
+            DBGWCR2_EL1,
+            DBGWCR3_EL1,
+            DBGWCR4_EL1,
+            DBGWCR5_EL1,
+            DBGWCR6_EL1,
+            DBGWCR7_EL1,
+            DBGWCR8_EL1,
+            DBGWCR9_EL1,
+            DBGWCR10_EL1,
+            DBGWCR11_EL1,
+            DBGWCR12_EL1,
+            DBGWCR13_EL1,
+            DBGWCR14_EL1,
+            DBGWCR15_EL1
+        ]
+    );
+}
+
+/// Enables software step for the next exception return.
+///
+/// Sets `MDSCR_EL1.SS` and `SPSR_EL1.SS`, so that after the next `eret` the PE executes one
+/// instruction and then takes a Software Step exception. If `SPSR_EL1` is restored from a saved
+/// exception context before returning, the `SPSR_EL1::SS` bit must be set in that context instead.
+#[inline]
+pub fn enable_single_step() {
+    MDSCR_EL1.modify(MDSCR_EL1::SS::Enable);
+    SPSR_EL1.modify(SPSR_EL1::SS::SET);
+    barrier::isb(barrier::SY);
+}
+
+/// Disables software step.
+#[inline]
+pub fn disable_single_step() {
+    MDSCR_EL1.modify(MDSCR_EL1::SS::Disable);
+    SPSR_EL1.modify(SPSR_EL1::SS::CLEAR);
+    barrier::isb(barrier::SY);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watchpoint_byte_address_select() {
+        let (value, control) =
+            watchpoint_value_control(0x1003, 4, Access::Store, ExceptionLevels::El0).unwrap();
+        assert_eq!(value, 0x1000);
+        assert_eq!((control >> 5) & 0xff, 0b0111_1000);
+        assert_eq!((control >> 3) & 0b11, 0b10);
+        assert_eq!((control >> 1) & 0b11, 0b10);
+        assert_eq!(control & 1, 1);
+
+        assert_eq!(
+            watchpoint_value_control(0x1006, 4, Access::Load, ExceptionLevels::El0),
+            Err(Error::UnsupportedLength)
+        );
+    }
+
+    #[test]
+    fn watchpoint_address_mask() {
+        let (value, control) =
+            watchpoint_value_control(0x4000, 0x1000, Access::LoadStore, ExceptionLevels::El1)
+                .unwrap();
+        assert_eq!(value, 0x4000);
+        assert_eq!((control >> 24) & 0b11111, 12);
+        assert_eq!((control >> 5) & 0xff, 0xff);
+
+        assert_eq!(
+            watchpoint_value_control(0x4800, 0x1000, Access::Load, ExceptionLevels::El1),
+            Err(Error::Misaligned)
+        );
+        assert_eq!(
+            watchpoint_value_control(0x4000, 12, Access::Load, ExceptionLevels::El1),
+            Err(Error::UnsupportedLength)
+        );
+    }
+
+    #[test]
+    fn breakpoint_exception_levels() {
+        assert_eq!(breakpoint_control(ExceptionLevels::El1), 0b1_1110_0011);
+        assert_eq!(breakpoint_control(ExceptionLevels::El2), 0x61e1);
+    }
+}
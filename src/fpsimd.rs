@@ -0,0 +1,451 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Floating-point, Advanced SIMD and SVE context save and restore.
+//!
+//! Intended for lazy context switching: keep FP/SIMD trapped through `CPACR_EL1::FPEN` (and SVE
+//! through `CPACR_EL1::ZEN`), and on the resulting trap save the previous owner's state and
+//! restore the current thread's one before disabling the trap.
+//!
+//! The restore functions declare all registers they load as clobbered, so compiled code does not
+//! keep values of its own in them across the call. Compiled code may still use the registers
+//! afterwards, so the restored state only reaches its owner if nothing in between touches FP/SIMD,
+//! e.g. in a kernel built for a soft-float target or right before the exception return. Inline
+//! assembly cannot name the Z registers, so only their low 128 bits are declared; this relies on
+//! the compiler not keeping scalable vectors live across the call.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::fpsimd::FpState;
+//!
+//! static mut PREV: FpState = FpState::new();
+//! static mut NEXT: FpState = FpState::new();
+//!
+//! unsafe {
+//!     PREV.save();
+//!     NEXT.restore();
+//! }
+//! ```
+
+use crate::{
+    registers::{FPCR, FPSR},
+    sve,
+};
+use tock_registers::interfaces::{Readable, Writeable};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::asm;
+
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+fn save_v(v: *mut u128) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => unsafe {
+            asm!(
+                ".arch_extension fp",
+                "stp q0, q1, [{v}, #0]",
+                "stp q2, q3, [{v}, #32]",
+                "stp q4, q5, [{v}, #64]",
+                "stp q6, q7, [{v}, #96]",
+                "stp q8, q9, [{v}, #128]",
+                "stp q10, q11, [{v}, #160]",
+                "stp q12, q13, [{v}, #192]",
+                "stp q14, q15, [{v}, #224]",
+                "stp q16, q17, [{v}, #256]",
+                "stp q18, q19, [{v}, #288]",
+                "stp q20, q21, [{v}, #320]",
+                "stp q22, q23, [{v}, #352]",
+                "stp q24, q25, [{v}, #384]",
+                "stp q26, q27, [{v}, #416]",
+                "stp q28, q29, [{v}, #448]",
+                "stp q30, q31, [{v}, #480]",
+                v = in(reg) v,
+                options(nostack)
+            )
+        },
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+unsafe fn load_v(v: *const u128) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => asm!(
+            ".arch_extension fp",
+            "ldp q0, q1, [{v}, #0]",
+            "ldp q2, q3, [{v}, #32]",
+            "ldp q4, q5, [{v}, #64]",
+            "ldp q6, q7, [{v}, #96]",
+            "ldp q8, q9, [{v}, #128]",
+            "ldp q10, q11, [{v}, #160]",
+            "ldp q12, q13, [{v}, #192]",
+            "ldp q14, q15, [{v}, #224]",
+            "ldp q16, q17, [{v}, #256]",
+            "ldp q18, q19, [{v}, #288]",
+            "ldp q20, q21, [{v}, #320]",
+            "ldp q22, q23, [{v}, #352]",
+            "ldp q24, q25, [{v}, #384]",
+            "ldp q26, q27, [{v}, #416]",
+            "ldp q28, q29, [{v}, #448]",
+            "ldp q30, q31, [{v}, #480]",
+            v = in(reg) v,
+            out("v0") _,
+            out("v1") _,
+            out("v2") _,
+            out("v3") _,
+            out("v4") _,
+            out("v5") _,
+            out("v6") _,
+            out("v7") _,
+            out("v8") _,
+            out("v9") _,
+            out("v10") _,
+            out("v11") _,
+            out("v12") _,
+            out("v13") _,
+            out("v14") _,
+            out("v15") _,
+            out("v16") _,
+            out("v17") _,
+            out("v18") _,
+            out("v19") _,
+            out("v20") _,
+            out("v21") _,
+            out("v22") _,
+            out("v23") _,
+            out("v24") _,
+            out("v25") _,
+            out("v26") _,
+            out("v27") _,
+            out("v28") _,
+            out("v29") _,
+            out("v30") _,
+            out("v31") _,
+            options(readonly, nostack)
+        ),
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// `z` points to the Z registers and `p` to the P registers followed by FFR.
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+fn save_zp(z: *mut u8, p: *mut u8) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => unsafe {
+            asm!(
+                ".arch_extension sve",
+                "str z0, [{z}, #0, mul vl]",
+                "str z1, [{z}, #1, mul vl]",
+                "str z2, [{z}, #2, mul vl]",
+                "str z3, [{z}, #3, mul vl]",
+                "str z4, [{z}, #4, mul vl]",
+                "str z5, [{z}, #5, mul vl]",
+                "str z6, [{z}, #6, mul vl]",
+                "str z7, [{z}, #7, mul vl]",
+                "str z8, [{z}, #8, mul vl]",
+                "str z9, [{z}, #9, mul vl]",
+                "str z10, [{z}, #10, mul vl]",
+                "str z11, [{z}, #11, mul vl]",
+                "str z12, [{z}, #12, mul vl]",
+                "str z13, [{z}, #13, mul vl]",
+                "str z14, [{z}, #14, mul vl]",
+                "str z15, [{z}, #15, mul vl]",
+                "str z16, [{z}, #16, mul vl]",
+                "str z17, [{z}, #17, mul vl]",
+                "str z18, [{z}, #18, mul vl]",
+                "str z19, [{z}, #19, mul vl]",
+                "str z20, [{z}, #20, mul vl]",
+                "str z21, [{z}, #21, mul vl]",
+                "str z22, [{z}, #22, mul vl]",
+                "str z23, [{z}, #23, mul vl]",
+                "str z24, [{z}, #24, mul vl]",
+                "str z25, [{z}, #25, mul vl]",
+                "str z26, [{z}, #26, mul vl]",
+                "str z27, [{z}, #27, mul vl]",
+                "str z28, [{z}, #28, mul vl]",
+                "str z29, [{z}, #29, mul vl]",
+                "str z30, [{z}, #30, mul vl]",
+                "str z31, [{z}, #31, mul vl]",
+                "str p0, [{p}, #0, mul vl]",
+                "str p1, [{p}, #1, mul vl]",
+                "str p2, [{p}, #2, mul vl]",
+                "str p3, [{p}, #3, mul vl]",
+                "str p4, [{p}, #4, mul vl]",
+                "str p5, [{p}, #5, mul vl]",
+                "str p6, [{p}, #6, mul vl]",
+                "str p7, [{p}, #7, mul vl]",
+                "str p8, [{p}, #8, mul vl]",
+                "str p9, [{p}, #9, mul vl]",
+                "str p10, [{p}, #10, mul vl]",
+                "str p11, [{p}, #11, mul vl]",
+                "str p12, [{p}, #12, mul vl]",
+                "str p13, [{p}, #13, mul vl]",
+                "str p14, [{p}, #14, mul vl]",
+                "str p15, [{p}, #15, mul vl]",
+                "rdffr p0.b",
+                "str p0, [{p}, #16, mul vl]",
+                "ldr p0, [{p}, #0, mul vl]",
+                z = in(reg) z,
+                p = in(reg) p,
+                options(nostack)
+            )
+        },
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+#[inline(always)]
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+unsafe fn load_zp(z: *const u8, p: *const u8) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => asm!(
+            ".arch_extension sve",
+            "ldr p0, [{p}, #16, mul vl]",
+            "wrffr p0.b",
+            "ldr p0, [{p}, #0, mul vl]",
+            "ldr p1, [{p}, #1, mul vl]",
+            "ldr p2, [{p}, #2, mul vl]",
+            "ldr p3, [{p}, #3, mul vl]",
+            "ldr p4, [{p}, #4, mul vl]",
+            "ldr p5, [{p}, #5, mul vl]",
+            "ldr p6, [{p}, #6, mul vl]",
+            "ldr p7, [{p}, #7, mul vl]",
+            "ldr p8, [{p}, #8, mul vl]",
+            "ldr p9, [{p}, #9, mul vl]",
+            "ldr p10, [{p}, #10, mul vl]",
+            "ldr p11, [{p}, #11, mul vl]",
+            "ldr p12, [{p}, #12, mul vl]",
+            "ldr p13, [{p}, #13, mul vl]",
+            "ldr p14, [{p}, #14, mul vl]",
+            "ldr p15, [{p}, #15, mul vl]",
+            "ldr z0, [{z}, #0, mul vl]",
+            "ldr z1, [{z}, #1, mul vl]",
+            "ldr z2, [{z}, #2, mul vl]",
+            "ldr z3, [{z}, #3, mul vl]",
+            "ldr z4, [{z}, #4, mul vl]",
+            "ldr z5, [{z}, #5, mul vl]",
+            "ldr z6, [{z}, #6, mul vl]",
+            "ldr z7, [{z}, #7, mul vl]",
+            "ldr z8, [{z}, #8, mul vl]",
+            "ldr z9, [{z}, #9, mul vl]",
+            "ldr z10, [{z}, #10, mul vl]",
+            "ldr z11, [{z}, #11, mul vl]",
+            "ldr z12, [{z}, #12, mul vl]",
+            "ldr z13, [{z}, #13, mul vl]",
+            "ldr z14, [{z}, #14, mul vl]",
+            "ldr z15, [{z}, #15, mul vl]",
+            "ldr z16, [{z}, #16, mul vl]",
+            "ldr z17, [{z}, #17, mul vl]",
+            "ldr z18, [{z}, #18, mul vl]",
+            "ldr z19, [{z}, #19, mul vl]",
+            "ldr z20, [{z}, #20, mul vl]",
+            "ldr z21, [{z}, #21, mul vl]",
+            "ldr z22, [{z}, #22, mul vl]",
+            "ldr z23, [{z}, #23, mul vl]",
+            "ldr z24, [{z}, #24, mul vl]",
+            "ldr z25, [{z}, #25, mul vl]",
+            "ldr z26, [{z}, #26, mul vl]",
+            "ldr z27, [{z}, #27, mul vl]",
+            "ldr z28, [{z}, #28, mul vl]",
+            "ldr z29, [{z}, #29, mul vl]",
+            "ldr z30, [{z}, #30, mul vl]",
+            "ldr z31, [{z}, #31, mul vl]",
+            z = in(reg) z,
+            p = in(reg) p,
+            out("v0") _,
+            out("v1") _,
+            out("v2") _,
+            out("v3") _,
+            out("v4") _,
+            out("v5") _,
+            out("v6") _,
+            out("v7") _,
+            out("v8") _,
+            out("v9") _,
+            out("v10") _,
+            out("v11") _,
+            out("v12") _,
+            out("v13") _,
+            out("v14") _,
+            out("v15") _,
+            out("v16") _,
+            out("v17") _,
+            out("v18") _,
+            out("v19") _,
+            out("v20") _,
+            out("v21") _,
+            out("v22") _,
+            out("v23") _,
+            out("v24") _,
+            out("v25") _,
+            out("v26") _,
+            out("v27") _,
+            out("v28") _,
+            out("v29") _,
+            out("v30") _,
+            out("v31") _,
+            out("p0") _,
+            out("p1") _,
+            out("p2") _,
+            out("p3") _,
+            out("p4") _,
+            out("p5") _,
+            out("p6") _,
+            out("p7") _,
+            out("p8") _,
+            out("p9") _,
+            out("p10") _,
+            out("p11") _,
+            out("p12") _,
+            out("p13") _,
+            out("p14") _,
+            out("p15") _,
+            out("ffr") _,
+            options(readonly, nostack)
+        ),
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// The floating-point and Advanced SIMD register state.
+#[repr(C, align(16))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FpState {
+    /// V0-V31.
+    pub v: [u128; 32],
+    /// Floating-point Control Register.
+    pub fpcr: u64,
+    /// Floating-point Status Register.
+    pub fpsr: u64,
+}
+
+impl FpState {
+    /// Return a zeroed state.
+    pub const fn new() -> Self {
+        FpState {
+            v: [0; 32],
+            fpcr: 0,
+            fpsr: 0,
+        }
+    }
+
+    /// Save the current FP/SIMD registers into `self`.
+    ///
+    /// FP/SIMD must not be trapped at the current Exception level.
+    #[inline]
+    pub fn save(&mut self) {
+        save_v(self.v.as_mut_ptr());
+
+        self.fpcr = FPCR.get();
+        self.fpsr = FPSR.get();
+    }
+
+    /// Load the FP/SIMD registers from `self`.
+    ///
+    /// FP/SIMD must not be trapped at the current Exception level.
+    ///
+    /// # Safety
+    ///
+    /// All FP/SIMD registers are overwritten without the compiler knowing. The caller must not
+    /// rely on values held in them, see the [module documentation](self).
+    #[inline]
+    pub unsafe fn restore(&self) {
+        load_v(self.v.as_ptr());
+
+        FPCR.set(self.fpcr);
+        FPSR.set(self.fpsr);
+    }
+}
+
+/// The SVE register state, for the vector length that was current when it was created.
+///
+/// The Z registers hold the V registers in their lower 128 bits, so this includes the full
+/// [`FpState`].
+///
+/// Only valid outside of Streaming SVE mode.
+#[derive(Debug)]
+pub struct SveState<'a> {
+    regs: &'a mut [u8],
+    vl: usize,
+    /// Floating-point Control Register.
+    pub fpcr: u64,
+    /// Floating-point Status Register.
+    pub fpsr: u64,
+}
+
+impl<'a> SveState<'a> {
+    /// Use `buf` as storage for the Z, P and FFR registers at the current vector length.
+    ///
+    /// Returns `None` if `buf` is smaller than [`sve::context_size`] of the current vector length.
+    /// SVE must not be trapped at the current Exception level.
+    pub fn new(buf: &'a mut [u8]) -> Option<Self> {
+        let vl = sve::vector_length();
+
+        if buf.len() < sve::context_size(vl) {
+            return None;
+        }
+
+        Some(SveState {
+            regs: buf,
+            vl,
+            fpcr: 0,
+            fpsr: 0,
+        })
+    }
+
+    /// Return the vector length in bytes this state was sized for.
+    pub fn vector_length(&self) -> usize {
+        self.vl
+    }
+
+    /// Save the current SVE registers into `self`.
+    ///
+    /// # Panics
+    ///
+    /// If the current vector length differs from the one this state was created for.
+    #[inline]
+    pub fn save(&mut self) {
+        assert_eq!(sve::vector_length(), self.vl);
+
+        let (z, p) = self.regs.split_at_mut(32 * self.vl);
+        save_zp(z.as_mut_ptr(), p.as_mut_ptr());
+
+        self.fpcr = FPCR.get();
+        self.fpsr = FPSR.get();
+    }
+
+    /// Load the SVE registers from `self`.
+    ///
+    /// # Safety
+    ///
+    /// The current vector length must be the one this state was created for. All SVE and
+    /// FP/SIMD registers are overwritten without the compiler knowing, see the
+    /// [module documentation](self).
+    #[inline]
+    pub unsafe fn restore(&self) {
+        let (z, p) = self.regs.split_at(32 * self.vl);
+        load_zp(z.as_ptr(), p.as_ptr());
+
+        FPCR.set(self.fpcr);
+        FPSR.set(self.fpsr);
+    }
+}
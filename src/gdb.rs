@@ -0,0 +1,771 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! GDB Remote Serial Protocol support for on-target debug stubs.
+//!
+//! Provides packet framing, the AArch64 register layout used by the `g`, `G`, `p` and `P`
+//! packets and its conversion from and to an exception context, the matching target description,
+//! and the translation of `Z`/`z` hardware breakpoint and watchpoint requests into the
+//! [`debug`](crate::debug) module. Transport and command dispatch are left to the stub.
+//!
+//! # Example
+//!
+//! ```
+//! use cortex_a::gdb::{self, RegisterFile};
+//!
+//! let mut regs = RegisterFile::default();
+//! regs.x[0] = 0x1234;
+//! regs.pc = 0x8_0000;
+//!
+//! // Reply to a `g` packet.
+//! let mut hex = [0; RegisterFile::HEX_LEN];
+//! regs.to_hex(&mut hex);
+//!
+//! let mut packet = [0; RegisterFile::HEX_LEN + 4];
+//! let len = gdb::encode_packet(&hex, &mut packet).unwrap();
+//! assert_eq!(&packet[..5], b"$3412");
+//! # let _ = len;
+//! ```
+
+use crate::fpsimd::FpState;
+
+/// Errors reported when decoding packets.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The packet is not framed as `$<data>#<checksum>`.
+    Framing,
+
+    /// The checksum does not match the packet data.
+    Checksum,
+
+    /// The packet contains a malformed hexadecimal number or is too short or too long.
+    Malformed,
+
+    /// The register number does not exist.
+    InvalidRegister,
+
+    /// The output buffer is too small.
+    BufferTooSmall,
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn hex_value(c: u8) -> Result<u8, Error> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::Malformed),
+    }
+}
+
+/// Parses a big-endian hexadecimal number, as used for addresses and lengths in packets.
+pub fn parse_hex(s: &[u8]) -> Result<u64, Error> {
+    if s.is_empty() || s.len() > 16 {
+        return Err(Error::Malformed);
+    }
+
+    s.iter()
+        .try_fold(0, |acc, &c| Ok((acc << 4) | hex_value(c)? as u64))
+}
+
+/// Writes `bytes` as hexadecimal digit pairs into `out`, which must be twice as long as `bytes`.
+fn bytes_to_hex(bytes: &[u8], out: &mut [u8]) {
+    for (i, byte) in bytes.iter().enumerate() {
+        out[2 * i] = HEX_DIGITS[(byte >> 4) as usize];
+        out[2 * i + 1] = HEX_DIGITS[(byte & 0xf) as usize];
+    }
+}
+
+/// Reads hexadecimal digit pairs from `hex` into `out`, which must be half as long as `hex`.
+fn hex_to_bytes(hex: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = (hex_value(hex[2 * i])? << 4) | hex_value(hex[2 * i + 1])?;
+    }
+
+    Ok(())
+}
+
+/// Returns the checksum of the packet data, the modulo 256 sum of all bytes.
+pub fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0, |sum: u8, &c| sum.wrapping_add(c))
+}
+
+/// Frames `data` as `$<data>#<checksum>` into `out`, escaping `#`, `$`, `}` and `*`.
+///
+/// Returns the length of the packet, or `None` if `out` is too small.
+pub fn encode_packet(data: &[u8], out: &mut [u8]) -> Option<usize> {
+    let mut len = 0;
+    let mut push = |c: u8| -> Option<()> {
+        *out.get_mut(len)? = c;
+        len += 1;
+        Some(())
+    };
+
+    push(b'$')?;
+    let mut sum: u8 = 0;
+    for &c in data {
+        if matches!(c, b'#' | b'$' | b'}' | b'*') {
+            push(b'}')?;
+            push(c ^ 0x20)?;
+            sum = sum.wrapping_add(b'}').wrapping_add(c ^ 0x20);
+        } else {
+            push(c)?;
+            sum = sum.wrapping_add(c);
+        }
+    }
+    push(b'#')?;
+    push(HEX_DIGITS[(sum >> 4) as usize])?;
+    push(HEX_DIGITS[(sum & 0xf) as usize])?;
+
+    Some(len)
+}
+
+/// Verifies the framing and checksum of a received `$<data>#<checksum>` packet and unescapes
+/// its data in place.
+///
+/// Returns the unescaped packet data.
+pub fn decode_packet(packet: &mut [u8]) -> Result<&[u8], Error> {
+    let len = packet.len();
+    if len < 4 || packet[0] != b'$' || packet[len - 3] != b'#' {
+        return Err(Error::Framing);
+    }
+
+    let expected = (hex_value(packet[len - 2])? << 4) | hex_value(packet[len - 1])?;
+    if checksum(&packet[1..len - 3]) != expected {
+        return Err(Error::Checksum);
+    }
+
+    let mut read = 1;
+    let mut write = 0;
+    while read < len - 3 {
+        let c = packet[read];
+        if c == b'}' {
+            read += 1;
+            if read == len - 3 {
+                return Err(Error::Malformed);
+            }
+            packet[write] = packet[read] ^ 0x20;
+        } else {
+            packet[write] = c;
+        }
+        read += 1;
+        write += 1;
+    }
+
+    Ok(&packet[..write])
+}
+
+/// The AArch64 registers as exchanged with GDB.
+///
+/// The field order matches GDB's register numbering for the `org.gnu.gdb.aarch64.core` and
+/// `org.gnu.gdb.aarch64.fpu` features, see [`TARGET_XML`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegisterFile {
+    /// General purpose registers `x0` to `x30`.
+    pub x: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    /// `PSTATE`, as saved in `SPSR_ELx` when the exception was taken.
+    pub cpsr: u32,
+    /// SIMD&FP registers `v0` to `v31`.
+    pub v: [u128; 32],
+    pub fpsr: u32,
+    pub fpcr: u32,
+}
+
+impl RegisterFile {
+    /// Number of registers known to GDB.
+    pub const NUM_REGS: usize = 68;
+
+    /// Length in bytes of the register file in the `g`/`G` packet layout.
+    pub const LEN: usize = 31 * 8 + 8 + 8 + 4 + 32 * 16 + 4 + 4;
+
+    /// Length of the hexadecimal payload of the `g`/`G` packets.
+    pub const HEX_LEN: usize = 2 * Self::LEN;
+
+    /// GDB register number of `sp`.
+    pub const SP: usize = 31;
+
+    /// GDB register number of `pc`.
+    pub const PC: usize = 32;
+
+    /// GDB register number of `cpsr`.
+    pub const CPSR: usize = 33;
+
+    /// GDB register number of `v0`.
+    pub const V0: usize = 34;
+
+    /// GDB register number of `fpsr`.
+    pub const FPSR: usize = 66;
+
+    /// GDB register number of `fpcr`.
+    pub const FPCR: usize = 67;
+
+    /// Builds the register file from an exception context.
+    ///
+    /// `x` and `sp` are the general purpose registers and stack pointer of the interrupted code,
+    /// `elr` and `spsr` the values of `ELR_ELx` and `SPSR_ELx` on exception entry, and `fp` the
+    /// SIMD&FP registers saved with [`FpState::save`].
+    pub fn from_context(x: &[u64; 31], sp: u64, elr: u64, spsr: u64, fp: &FpState) -> Self {
+        RegisterFile {
+            x: *x,
+            sp,
+            pc: elr,
+            cpsr: spsr as u32,
+            v: fp.v,
+            fpsr: fp.fpsr as u32,
+            fpcr: fp.fpcr as u32,
+        }
+    }
+
+    /// Returns the SIMD&FP registers, to be loaded with [`FpState::restore`].
+    ///
+    /// The general purpose registers map directly to the exception context, with `pc` going to
+    /// `ELR_ELx` and `cpsr` to `SPSR_ELx`.
+    pub fn fp_state(&self) -> FpState {
+        FpState {
+            v: self.v,
+            fpcr: self.fpcr.into(),
+            fpsr: self.fpsr.into(),
+        }
+    }
+
+    /// Returns the size in bytes of register `regnum`.
+    pub fn register_size(regnum: usize) -> Result<usize, Error> {
+        match regnum {
+            0..=32 => Ok(8),
+            Self::CPSR => Ok(4),
+            34..=65 => Ok(16),
+            Self::FPSR | Self::FPCR => Ok(4),
+            _ => Err(Error::InvalidRegister),
+        }
+    }
+
+    /// Writes the target byte order representation of register `regnum` into `out`.
+    ///
+    /// Returns the number of bytes written.
+    fn register_bytes(&self, regnum: usize, out: &mut [u8; 16]) -> Result<usize, Error> {
+        match regnum {
+            0..=30 => out[..8].copy_from_slice(&self.x[regnum].to_le_bytes()),
+            Self::SP => out[..8].copy_from_slice(&self.sp.to_le_bytes()),
+            Self::PC => out[..8].copy_from_slice(&self.pc.to_le_bytes()),
+            Self::CPSR => out[..4].copy_from_slice(&self.cpsr.to_le_bytes()),
+            34..=65 => out.copy_from_slice(&self.v[regnum - Self::V0].to_le_bytes()),
+            Self::FPSR => out[..4].copy_from_slice(&self.fpsr.to_le_bytes()),
+            Self::FPCR => out[..4].copy_from_slice(&self.fpcr.to_le_bytes()),
+            _ => return Err(Error::InvalidRegister),
+        }
+
+        Self::register_size(regnum)
+    }
+
+    /// Sets register `regnum` from its target byte order representation.
+    fn set_register_bytes(&mut self, regnum: usize, bytes: &[u8]) -> Result<(), Error> {
+        if bytes.len() != Self::register_size(regnum)? {
+            return Err(Error::Malformed);
+        }
+
+        let mut buf = [0; 16];
+        buf[..bytes.len()].copy_from_slice(bytes);
+        let u64_val = || {
+            u64::from_le_bytes([
+                buf[0], buf[1], buf[2], buf[3], buf[4], buf[5], buf[6], buf[7],
+            ])
+        };
+        let u32_val = || u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]);
+
+        match regnum {
+            0..=30 => self.x[regnum] = u64_val(),
+            Self::SP => self.sp = u64_val(),
+            Self::PC => self.pc = u64_val(),
+            Self::CPSR => self.cpsr = u32_val(),
+            34..=65 => self.v[regnum - Self::V0] = u128::from_le_bytes(buf),
+            Self::FPSR => self.fpsr = u32_val(),
+            Self::FPCR => self.fpcr = u32_val(),
+            _ => return Err(Error::InvalidRegister),
+        }
+
+        Ok(())
+    }
+
+    /// Encodes all registers as the hexadecimal payload of a `g` reply.
+    ///
+    /// `out` must be [`RegisterFile::HEX_LEN`] bytes long.
+    pub fn to_hex(&self, out: &mut [u8; Self::HEX_LEN]) {
+        let mut pos = 0;
+        for regnum in 0..Self::NUM_REGS {
+            let mut bytes = [0; 16];
+            let size = self.register_bytes(regnum, &mut bytes).unwrap();
+            bytes_to_hex(&bytes[..size], &mut out[pos..pos + 2 * size]);
+            pos += 2 * size;
+        }
+    }
+
+    /// Decodes all registers from the hexadecimal payload of a `G` packet.
+    pub fn from_hex(hex: &[u8]) -> Result<Self, Error> {
+        if hex.len() != Self::HEX_LEN {
+            return Err(Error::Malformed);
+        }
+
+        let mut regs = Self::default();
+        let mut pos = 0;
+        for regnum in 0..Self::NUM_REGS {
+            let size = Self::register_size(regnum)?;
+            let mut bytes = [0; 16];
+            hex_to_bytes(&hex[pos..pos + 2 * size], &mut bytes[..size])?;
+            regs.set_register_bytes(regnum, &bytes[..size])?;
+            pos += 2 * size;
+        }
+
+        Ok(regs)
+    }
+
+    /// Encodes register `regnum` as the hexadecimal payload of a `p` reply.
+    ///
+    /// Returns the number of bytes written to `out`.
+    pub fn register_to_hex(&self, regnum: usize, out: &mut [u8]) -> Result<usize, Error> {
+        let mut bytes = [0; 16];
+        let size = self.register_bytes(regnum, &mut bytes)?;
+        let out = out.get_mut(..2 * size).ok_or(Error::BufferTooSmall)?;
+        bytes_to_hex(&bytes[..size], out);
+
+        Ok(2 * size)
+    }
+
+    /// Sets register `regnum` from the hexadecimal value of a `P` packet.
+    pub fn set_register_from_hex(&mut self, regnum: usize, hex: &[u8]) -> Result<(), Error> {
+        let size = Self::register_size(regnum)?;
+        if hex.len() != 2 * size {
+            return Err(Error::Malformed);
+        }
+
+        let mut bytes = [0; 16];
+        hex_to_bytes(hex, &mut bytes[..size])?;
+        self.set_register_bytes(regnum, &bytes[..size])
+    }
+}
+
+/// Target description matching the layout of [`RegisterFile`], to be served in reply to
+/// `qXfer:features:read:target.xml`.
+pub const TARGET_XML: &str = r#"<?xml version="1.0"?>
+<!DOCTYPE target SYSTEM "gdb-target.dtd">
+<target version="1.0">
+  <architecture>aarch64</architecture>
+  <feature name="org.gnu.gdb.aarch64.core">
+    <reg name="x0" bitsize="64"/>
+    <reg name="x1" bitsize="64"/>
+    <reg name="x2" bitsize="64"/>
+    <reg name="x3" bitsize="64"/>
+    <reg name="x4" bitsize="64"/>
+    <reg name="x5" bitsize="64"/>
+    <reg name="x6" bitsize="64"/>
+    <reg name="x7" bitsize="64"/>
+    <reg name="x8" bitsize="64"/>
+    <reg name="x9" bitsize="64"/>
+    <reg name="x10" bitsize="64"/>
+    <reg name="x11" bitsize="64"/>
+    <reg name="x12" bitsize="64"/>
+    <reg name="x13" bitsize="64"/>
+    <reg name="x14" bitsize="64"/>
+    <reg name="x15" bitsize="64"/>
+    <reg name="x16" bitsize="64"/>
+    <reg name="x17" bitsize="64"/>
+    <reg name="x18" bitsize="64"/>
+    <reg name="x19" bitsize="64"/>
+    <reg name="x20" bitsize="64"/>
+    <reg name="x21" bitsize="64"/>
+    <reg name="x22" bitsize="64"/>
+    <reg name="x23" bitsize="64"/>
+    <reg name="x24" bitsize="64"/>
+    <reg name="x25" bitsize="64"/>
+    <reg name="x26" bitsize="64"/>
+    <reg name="x27" bitsize="64"/>
+    <reg name="x28" bitsize="64"/>
+    <reg name="x29" bitsize="64"/>
+    <reg name="x30" bitsize="64"/>
+    <reg name="sp" bitsize="64" type="data_ptr"/>
+    <reg name="pc" bitsize="64" type="code_ptr"/>
+    <reg name="cpsr" bitsize="32"/>
+  </feature>
+  <feature name="org.gnu.gdb.aarch64.fpu">
+    <reg name="v0" bitsize="128" type="uint128"/>
+    <reg name="v1" bitsize="128" type="uint128"/>
+    <reg name="v2" bitsize="128" type="uint128"/>
+    <reg name="v3" bitsize="128" type="uint128"/>
+    <reg name="v4" bitsize="128" type="uint128"/>
+    <reg name="v5" bitsize="128" type="uint128"/>
+    <reg name="v6" bitsize="128" type="uint128"/>
+    <reg name="v7" bitsize="128" type="uint128"/>
+    <reg name="v8" bitsize="128" type="uint128"/>
+    <reg name="v9" bitsize="128" type="uint128"/>
+    <reg name="v10" bitsize="128" type="uint128"/>
+    <reg name="v11" bitsize="128" type="uint128"/>
+    <reg name="v12" bitsize="128" type="uint128"/>
+    <reg name="v13" bitsize="128" type="uint128"/>
+    <reg name="v14" bitsize="128" type="uint128"/>
+    <reg name="v15" bitsize="128" type="uint128"/>
+    <reg name="v16" bitsize="128" type="uint128"/>
+    <reg name="v17" bitsize="128" type="uint128"/>
+    <reg name="v18" bitsize="128" type="uint128"/>
+    <reg name="v19" bitsize="128" type="uint128"/>
+    <reg name="v20" bitsize="128" type="uint128"/>
+    <reg name="v21" bitsize="128" type="uint128"/>
+    <reg name="v22" bitsize="128" type="uint128"/>
+    <reg name="v23" bitsize="128" type="uint128"/>
+    <reg name="v24" bitsize="128" type="uint128"/>
+    <reg name="v25" bitsize="128" type="uint128"/>
+    <reg name="v26" bitsize="128" type="uint128"/>
+    <reg name="v27" bitsize="128" type="uint128"/>
+    <reg name="v28" bitsize="128" type="uint128"/>
+    <reg name="v29" bitsize="128" type="uint128"/>
+    <reg name="v30" bitsize="128" type="uint128"/>
+    <reg name="v31" bitsize="128" type="uint128"/>
+    <reg name="fpsr" bitsize="32"/>
+    <reg name="fpcr" bitsize="32"/>
+  </feature>
+</target>
+"#;
+
+/// Writes the `qXfer` reply for reading `len` bytes at `offset` of `document` into `out`.
+///
+/// The reply is `m<data>` if more data follows, or `l<data>` for the last chunk. Returns the
+/// length of the reply.
+pub fn xfer_reply(document: &[u8], offset: usize, len: usize, out: &mut [u8]) -> usize {
+    let start = offset.min(document.len());
+    let end = start.saturating_add(len).min(document.len());
+    let chunk = &document[start..end];
+    let chunk = &chunk[..chunk.len().min(out.len().saturating_sub(1))];
+
+    if out.is_empty() {
+        return 0;
+    }
+    out[0] = if start + chunk.len() < document.len() {
+        b'm'
+    } else {
+        b'l'
+    };
+    out[1..=chunk.len()].copy_from_slice(chunk);
+
+    1 + chunk.len()
+}
+
+/// Kind of a hardware breakpoint or watchpoint requested with a `Z` or `z` packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BreakpointKind {
+    /// `Z1`: hardware breakpoint.
+    Hardware,
+    /// `Z2`: write watchpoint.
+    WriteWatch,
+    /// `Z3`: read watchpoint.
+    ReadWatch,
+    /// `Z4`: access watchpoint.
+    AccessWatch,
+}
+
+/// A parsed `Z<type>,<addr>,<kind>` or `z<type>,<addr>,<kind>` request.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BreakpointRequest {
+    /// `true` for `Z` (insert), `false` for `z` (remove).
+    pub insert: bool,
+    pub kind: BreakpointKind,
+    pub addr: u64,
+    /// Instruction size for breakpoints, number of watched bytes for watchpoints.
+    pub len: u64,
+}
+
+impl BreakpointRequest {
+    /// Parses the data of a `Z`/`z` packet.
+    ///
+    /// Returns `Ok(None)` for software breakpoints (`Z0`), which the stub implements itself by
+    /// patching memory.
+    pub fn parse(data: &[u8]) -> Result<Option<Self>, Error> {
+        let insert = match data.first() {
+            Some(b'Z') => true,
+            Some(b'z') => false,
+            _ => return Err(Error::Malformed),
+        };
+
+        let mut fields = data[1..].split(|&c| c == b',');
+        let kind = match fields.next() {
+            Some(b"0") => return Ok(None),
+            Some(b"1") => BreakpointKind::Hardware,
+            Some(b"2") => BreakpointKind::WriteWatch,
+            Some(b"3") => BreakpointKind::ReadWatch,
+            Some(b"4") => BreakpointKind::AccessWatch,
+            _ => return Err(Error::Malformed),
+        };
+        let addr = parse_hex(fields.next().ok_or(Error::Malformed)?)?;
+        // Conditions and commands may follow after a `;`.
+        let len = fields.next().ok_or(Error::Malformed)?;
+        let len = len.split(|&c| c == b';').next().unwrap_or(len);
+        let len = parse_hex(len)?;
+
+        Ok(Some(BreakpointRequest {
+            insert,
+            kind,
+            addr,
+            len,
+        }))
+    }
+}
+
+/// Tracks which hardware breakpoints and watchpoints are in use by the debugger.
+///
+/// Slot allocation is independent of the hardware, so that [`HwBreakpoints::apply`] can program
+/// `DBGBCR<n>_EL1`/`DBGWCR<n>_EL1` with the allocated index.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct HwBreakpoints {
+    breakpoints: [Option<u64>; 16],
+    watchpoints: [Option<(u64, u64, BreakpointKind)>; 16],
+}
+
+impl HwBreakpoints {
+    /// Returns an empty set of slots.
+    pub const fn new() -> Self {
+        HwBreakpoints {
+            breakpoints: [None; 16],
+            watchpoints: [None; 16],
+        }
+    }
+
+    /// Allocates or frees the slot for `req`, considering only the first `num_slots` slots.
+    ///
+    /// Returns the affected slot index, or `None` if no slot is free on insertion or no matching
+    /// slot is found on removal.
+    pub fn allocate(&mut self, req: &BreakpointRequest, num_slots: u8) -> Option<u8> {
+        let num_slots = (num_slots as usize).min(16);
+
+        match req.kind {
+            BreakpointKind::Hardware => {
+                let slots = &mut self.breakpoints[..num_slots];
+                let (wanted, new) = if req.insert {
+                    (None, Some(req.addr))
+                } else {
+                    (Some(req.addr), None)
+                };
+                let index = slots.iter().position(|&s| s == wanted)?;
+                slots[index] = new;
+
+                Some(index as u8)
+            }
+            _ => {
+                let slots = &mut self.watchpoints[..num_slots];
+                let entry = Some((req.addr, req.len, req.kind));
+                let (wanted, new) = if req.insert {
+                    (None, entry)
+                } else {
+                    (entry, None)
+                };
+                let index = slots.iter().position(|&s| s == wanted)?;
+                slots[index] = new;
+
+                Some(index as u8)
+            }
+        }
+    }
+
+    /// Inserts or removes a hardware breakpoint or watchpoint as requested by a `Z`/`z` packet.
+    ///
+    /// Breakpoints and watchpoints are enabled for EL0 and EL1. Returns `false` if the request
+    /// can not be fulfilled, in which case the stub should reply with an error.
+    pub fn apply(&mut self, req: &BreakpointRequest) -> bool {
+        use crate::debug::{self, Access, ExceptionLevels};
+
+        let levels = ExceptionLevels::El0El1;
+        let (num_slots, access) = match req.kind {
+            BreakpointKind::Hardware => (debug::num_breakpoints(), None),
+            BreakpointKind::WriteWatch => (debug::num_watchpoints(), Some(Access::Store)),
+            BreakpointKind::ReadWatch => (debug::num_watchpoints(), Some(Access::Load)),
+            BreakpointKind::AccessWatch => (debug::num_watchpoints(), Some(Access::LoadStore)),
+        };
+
+        let mut slots = *self;
+        let index = match slots.allocate(req, num_slots) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let result = match (req.insert, access) {
+            (true, None) => debug::set_breakpoint(index, req.addr, levels),
+            (false, None) => debug::clear_breakpoint(index),
+            (true, Some(access)) => debug::set_watchpoint(index, req.addr, req.len, access, levels),
+            (false, Some(_)) => debug::clear_watchpoint(index),
+        };
+        if result.is_err() {
+            return false;
+        }
+
+        *self = slots;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packet_round_trip() {
+        let mut out = [0; 32];
+        let len = encode_packet(b"OK", &mut out).unwrap();
+        assert_eq!(&out[..len], b"$OK#9a");
+
+        let len = encode_packet(b"a#b}", &mut out).unwrap();
+        assert_eq!(&out[..len - 3], b"$a}\x03b}]");
+        assert_eq!(decode_packet(&mut out[..len]).unwrap(), b"a#b}");
+
+        let mut bad = *b"$OK#9b";
+        assert_eq!(decode_packet(&mut bad), Err(Error::Checksum));
+        let mut bad = *b"OK#9a";
+        assert_eq!(decode_packet(&mut bad), Err(Error::Framing));
+
+        assert_eq!(encode_packet(b"OK", &mut [0; 5]), None);
+    }
+
+    #[test]
+    fn register_layout() {
+        let mut regs = RegisterFile::default();
+        for (i, x) in regs.x.iter_mut().enumerate() {
+            *x = i as u64;
+        }
+        regs.sp = 0x1122_3344_5566_7788;
+        regs.pc = 0x8_0000;
+        regs.cpsr = 0x3c5;
+        regs.v[1] = 0x0f0e_0d0c_0b0a_0908_0706_0504_0302_0100;
+        regs.fpsr = 0x1000_0000;
+        regs.fpcr = 0x0300_0000;
+
+        let mut hex = [0; RegisterFile::HEX_LEN];
+        regs.to_hex(&mut hex);
+        assert_eq!(&hex[16..32], b"0100000000000000");
+        assert_eq!(&hex[31 * 16..32 * 16], b"8877665544332211");
+        assert_eq!(&hex[33 * 16..33 * 16 + 8], b"c5030000");
+        assert_eq!(
+            &hex[33 * 16 + 8 + 32..33 * 16 + 8 + 64],
+            b"000102030405060708090a0b0c0d0e0f"
+        );
+        assert_eq!(&hex[RegisterFile::HEX_LEN - 16..], b"0000001000000003");
+
+        assert_eq!(RegisterFile::from_hex(&hex), Ok(regs));
+        assert_eq!(RegisterFile::from_hex(&hex[1..]), Err(Error::Malformed));
+    }
+
+    #[test]
+    fn context_round_trip() {
+        let mut x = [0; 31];
+        x[30] = 0x8_1234;
+        let mut fp = FpState::new();
+        fp.v[31] = 0x1234_5678;
+        fp.fpsr = 0x0800_0000;
+        fp.fpcr = 0x0040_0000;
+
+        let regs = RegisterFile::from_context(&x, 0x4_0000, 0x8_0000, 0x6000_03c5, &fp);
+        assert_eq!(regs.x[30], 0x8_1234);
+        assert_eq!(regs.sp, 0x4_0000);
+        assert_eq!(regs.pc, 0x8_0000);
+        assert_eq!(regs.cpsr, 0x6000_03c5);
+        assert_eq!(regs.fp_state(), fp);
+    }
+
+    #[test]
+    fn single_register() {
+        let mut regs = RegisterFile::default();
+        regs.set_register_from_hex(RegisterFile::PC, b"0010000000000000")
+            .unwrap();
+        assert_eq!(regs.pc, 0x1000);
+
+        let mut out = [0; 32];
+        let len = regs.register_to_hex(RegisterFile::PC, &mut out).unwrap();
+        assert_eq!(&out[..len], b"0010000000000000");
+
+        assert_eq!(
+            RegisterFile::register_size(RegisterFile::NUM_REGS),
+            Err(Error::InvalidRegister)
+        );
+        assert_eq!(
+            regs.set_register_from_hex(RegisterFile::CPSR, b"00"),
+            Err(Error::Malformed)
+        );
+    }
+
+    #[test]
+    fn target_xml_matches_layout() {
+        assert_eq!(TARGET_XML.matches("<reg ").count(), RegisterFile::NUM_REGS);
+
+        let bits: usize = TARGET_XML
+            .split("bitsize=\"")
+            .skip(1)
+            .map(|s| s.split('"').next().unwrap().parse::<usize>().unwrap())
+            .sum();
+        assert_eq!(bits / 8, RegisterFile::LEN);
+
+        let mut out = [0; 8];
+        let len = xfer_reply(TARGET_XML.as_bytes(), 0, 100, &mut out);
+        assert_eq!(&out[..len], b"m<?xml v");
+        let len = xfer_reply(TARGET_XML.as_bytes(), TARGET_XML.len() - 2, 100, &mut out);
+        assert_eq!(&out[..len], b"l>\n");
+    }
+
+    #[test]
+    fn breakpoint_requests() {
+        assert_eq!(
+            BreakpointRequest::parse(b"Z1,80000,4"),
+            Ok(Some(BreakpointRequest {
+                insert: true,
+                kind: BreakpointKind::Hardware,
+                addr: 0x80000,
+                len: 4
+            }))
+        );
+        assert_eq!(BreakpointRequest::parse(b"Z0,80000,4"), Ok(None));
+        assert_eq!(
+            BreakpointRequest::parse(b"z3,ffff00001000,8;X1,0")
+                .unwrap()
+                .unwrap()
+                .kind,
+            BreakpointKind::ReadWatch
+        );
+        assert_eq!(BreakpointRequest::parse(b"Z5,0,4"), Err(Error::Malformed));
+        assert_eq!(BreakpointRequest::parse(b"Z1,xyz,4"), Err(Error::Malformed));
+    }
+
+    #[test]
+    fn slot_allocation() {
+        let mut slots = HwBreakpoints::new();
+        let bp = |insert, addr| BreakpointRequest {
+            insert,
+            kind: BreakpointKind::Hardware,
+            addr,
+            len: 4,
+        };
+
+        assert_eq!(slots.allocate(&bp(true, 0x1000), 2), Some(0));
+        assert_eq!(slots.allocate(&bp(true, 0x2000), 2), Some(1));
+        assert_eq!(slots.allocate(&bp(true, 0x3000), 2), None);
+        assert_eq!(slots.allocate(&bp(false, 0x1000), 2), Some(0));
+        assert_eq!(slots.allocate(&bp(false, 0x1000), 2), None);
+
+        let wp = BreakpointRequest {
+            insert: true,
+            kind: BreakpointKind::WriteWatch,
+            addr: 0x1000,
+            len: 8,
+        };
+        assert_eq!(slots.allocate(&wp, 2), Some(0));
+        assert_eq!(
+            slots.allocate(
+                &BreakpointRequest {
+                    insert: false,
+                    ..wp
+                },
+                2
+            ),
+            Some(0)
+        );
+    }
+}
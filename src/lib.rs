@@ -10,11 +10,14 @@
 //! ## Currently Supported Execution States
 //!
 //! - [x] AArch64
-//! - [ ] AArch32
+//! - [x] AArch32, through the [`asm`] wrappers and the CP15 system registers in
+//!   [`registers::aarch32`], e.g. on `armv7a-none-eabi` or `armv8r-none-eabihf`
 //!
 //! ## Minimum Supported Rust Version
 //!
-//! Requires a recent nightly of Rust.
+//! Requires Rust 1.82 or later, and builds on the stable channel. The optional `nightly` feature
+//! enables extras that need a nightly compiler, currently the annotations that mark items
+//! requiring the `lock_api` feature in the documentation.
 //!
 //! ## Usage
 //!
@@ -47,14 +50,11 @@
 //! code.
 //!
 //! ```rust
-//! # #[cfg(feature = "nightly")]
 //! use cortex_a::{asm, registers::*};
-//! # #[cfg(feature = "nightly")]
 //! use tock_registers::interfaces::Writeable; // <-- Trait needed to use `write()` and `set()`.
 //!
 //! // Some parts omitted for brevity.
 //!
-//! # #[cfg(feature = "nightly")]
 //! unsafe fn prepare_el2_to_el1_transition(
 //!     virt_boot_core_stack_end_exclusive_addr: u64,
 //!     virt_kernel_init_addr: u64,
@@ -85,10 +85,28 @@
 //! ARMv8, for ARMv8-A architecture
 //! profile](https://static.docs.arm.com/ddi0487/ca/DDI0487C_a_armv8_arm.pdf?_ga=2.266626254.1122218691.1534883460-1326731866.1530967873).
 
-#![cfg_attr(feature = "nightly", feature(core_intrinsics))]
-#![cfg_attr(feature = "nightly", feature(custom_inner_attributes))]
+#![cfg_attr(feature = "nightly", feature(doc_cfg))]
 #![no_std]
 
+pub mod asid;
 pub mod asm;
-#[cfg(feature = "nightly")]
+pub mod backtrace;
+pub mod debug;
+pub mod fpsimd;
+pub mod gdb;
+pub mod lock;
+pub mod mmio;
+pub mod mmu;
+pub mod mte;
+pub mod percpu;
+pub mod pmu;
+pub mod pstate;
+#[rustfmt::skip]
 pub mod registers;
+pub mod semihosting;
+pub mod sme;
+pub mod sve;
+pub mod sysreg;
+pub mod vcpu;
+pub mod vhe;
+pub mod vmid;
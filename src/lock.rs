@@ -0,0 +1,624 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Spin locks.
+//!
+//! [`SpinMutex`], [`TicketLock`] and [`RwSpinLock`] are raw locks that do not hold data. Their
+//! atomic operations compile to `LDAXR`/`STLXR` loops, or to the LSE `CAS`, `SWP` and `LDADD`
+//! instructions when built with the `lse` target feature. Waiters are parked with `SEVL; WFE`
+//! on the exclusive monitor of the lock word, so they are woken by the store that releases the
+//! lock instead of spinning on it. Unlocking therefore does not need an explicit `SEV`.
+//!
+//! With the `lock_api` feature enabled, the locks implement `lock_api::RawMutex` and
+//! `lock_api::RawRwLock`, and [`Mutex`], [`TicketMutex`] and [`RwLock`] wrap data with them.
+//!
+//! # Before the MMU is enabled
+//!
+//! Exclusive accesses are only architecturally guaranteed to work on Normal memory that is
+//! Inner Shareable and Write-Back cacheable. With the MMU off, all data accesses are Device-nGnRnE
+//! and whether the exclusives of the lock operations succeed, fail forever or fault is
+//! implementation defined.
+//!
+//! Locks that are used while a single core runs with the MMU or the data cache disabled can be
+//! switched to plain loads and stores with [`set_early_boot`]. This is only sound as long as no
+//! other core can take the same locks.
+//!
+//! # Example
+//!
+//! ```
+//! use cortex_a::lock::SpinMutex;
+//!
+//! static LOCK: SpinMutex = SpinMutex::new();
+//!
+//! LOCK.lock();
+//! assert!(!LOCK.try_lock());
+//! unsafe { LOCK.unlock() };
+//! ```
+
+#[cfg(test)]
+extern crate std;
+
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+static EARLY_BOOT: AtomicBool = AtomicBool::new(false);
+
+/// Switch all locks to plain loads and stores, or back to exclusive accesses.
+///
+/// # Safety
+///
+/// While `early_boot` is set, only a single core may take locks. Must be cleared before other
+/// cores are started, and can only be cleared with no lock held.
+pub unsafe fn set_early_boot(early_boot: bool) {
+    EARLY_BOOT.store(early_boot, Ordering::SeqCst);
+}
+
+/// Return whether the locks use plain loads and stores, as configured by [`set_early_boot`].
+#[inline(always)]
+pub fn is_early_boot() -> bool {
+    EARLY_BOOT.load(Ordering::Relaxed)
+}
+
+#[inline]
+fn swap(atomic: &AtomicU32, value: u32) -> u32 {
+    if is_early_boot() {
+        let prev = atomic.load(Ordering::Acquire);
+        atomic.store(value, Ordering::Relaxed);
+        return prev;
+    }
+
+    atomic.swap(value, Ordering::Acquire)
+}
+
+#[inline]
+fn compare_exchange(
+    atomic: &AtomicU32,
+    current: u32,
+    new: u32,
+    success: Ordering,
+) -> Result<u32, u32> {
+    if is_early_boot() {
+        let prev = atomic.load(Ordering::Acquire);
+        if prev != current {
+            return Err(prev);
+        }
+        atomic.store(new, Ordering::Release);
+        return Ok(prev);
+    }
+
+    atomic.compare_exchange(current, new, success, Ordering::Relaxed)
+}
+
+#[inline]
+fn fetch_add(atomic: &AtomicU32, value: u32, ordering: Ordering) -> u32 {
+    if is_early_boot() {
+        let prev = atomic.load(Ordering::Acquire);
+        atomic.store(prev.wrapping_add(value), Ordering::Release);
+        return prev;
+    }
+
+    atomic.fetch_add(value, ordering)
+}
+
+#[inline]
+fn fetch_sub(atomic: &AtomicU32, value: u32, ordering: Ordering) -> u32 {
+    fetch_add(atomic, value.wrapping_neg(), ordering)
+}
+
+/// Wait until `atomic` no longer holds `value`, and return its new value with acquire ordering.
+#[inline]
+fn wait_while(atomic: &AtomicU32, value: u32) -> u32 {
+    match () {
+        // The first WFE consumes the event of the SEVL. Once the exclusive load has armed the
+        // monitor, the next WFE returns when another core writes the lock word.
+        #[cfg(target_arch = "aarch64")]
+        () if !is_early_boot() => {
+            let current: u32;
+            unsafe {
+                core::arch::asm!(
+                    "sevl",
+                    "2:",
+                    "wfe",
+                    "ldaxr {current:w}, [{ptr}]",
+                    "cmp {current:w}, {value:w}",
+                    "b.eq 2b",
+                    ptr = in(reg) atomic.as_ptr(),
+                    value = in(reg) value,
+                    current = out(reg) current,
+                    options(nostack, readonly)
+                );
+            }
+
+            current
+        }
+
+        () => loop {
+            // Host tests can run more threads than there are CPUs. Yield so that a preempted
+            // holder, or the next ticket, gets to run instead of burning the waiter's time slice.
+            #[cfg(test)]
+            std::thread::yield_now();
+            #[cfg(not(test))]
+            core::hint::spin_loop();
+
+            let current = atomic.load(Ordering::Acquire);
+            if current != value {
+                break current;
+            }
+        },
+    }
+}
+
+/// A test-and-set spin lock.
+#[derive(Debug, Default)]
+pub struct SpinMutex {
+    locked: AtomicU32,
+}
+
+impl SpinMutex {
+    /// Create an unlocked lock.
+    pub const fn new() -> SpinMutex {
+        SpinMutex {
+            locked: AtomicU32::new(0),
+        }
+    }
+
+    /// Acquire the lock, waiting until it is available.
+    #[inline]
+    pub fn lock(&self) {
+        while swap(&self.locked, 1) != 0 {
+            wait_while(&self.locked, 1);
+        }
+    }
+
+    /// Try to acquire the lock without waiting, and return whether it was acquired.
+    #[inline]
+    pub fn try_lock(&self) -> bool {
+        self.locked.load(Ordering::Relaxed) == 0 && swap(&self.locked, 1) == 0
+    }
+
+    /// Return whether the lock is held.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.locked.load(Ordering::Relaxed) != 0
+    }
+
+    /// Release the lock.
+    ///
+    /// # Safety
+    ///
+    /// The lock must be held by the caller.
+    #[inline]
+    pub unsafe fn unlock(&self) {
+        self.locked.store(0, Ordering::Release);
+    }
+}
+
+/// A fair spin lock that is acquired in the order it was requested.
+///
+/// Holds the next ticket to hand out in the upper and the ticket of the owner in the lower 16
+/// bits of the lock word. Up to 65535 cores can wait at the same time.
+#[derive(Debug, Default)]
+pub struct TicketLock {
+    state: AtomicU32,
+}
+
+impl TicketLock {
+    const NEXT: u32 = 1 << 16;
+
+    /// Create an unlocked lock.
+    pub const fn new() -> TicketLock {
+        TicketLock {
+            state: AtomicU32::new(0),
+        }
+    }
+
+    #[inline(always)]
+    const fn owner(state: u32) -> u32 {
+        state & 0xffff
+    }
+
+    #[inline(always)]
+    const fn next(state: u32) -> u32 {
+        state >> 16
+    }
+
+    /// Acquire the lock, waiting until all earlier requests have been served.
+    #[inline]
+    pub fn lock(&self) {
+        let prev = fetch_add(&self.state, Self::NEXT, Ordering::Acquire);
+        let ticket = Self::next(prev);
+        let mut state = prev.wrapping_add(Self::NEXT);
+
+        while Self::owner(state) != ticket {
+            state = wait_while(&self.state, state);
+        }
+    }
+
+    /// Try to acquire the lock without waiting, and return whether it was acquired.
+    #[inline]
+    pub fn try_lock(&self) -> bool {
+        let state = self.state.load(Ordering::Relaxed);
+
+        Self::next(state) == Self::owner(state)
+            && compare_exchange(
+                &self.state,
+                state,
+                state.wrapping_add(Self::NEXT),
+                Ordering::Acquire,
+            )
+            .is_ok()
+    }
+
+    /// Return whether the lock is held.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        let state = self.state.load(Ordering::Relaxed);
+
+        Self::next(state) != Self::owner(state)
+    }
+
+    /// Release the lock to the next waiter.
+    ///
+    /// # Safety
+    ///
+    /// The lock must be held by the caller.
+    #[inline]
+    pub unsafe fn unlock(&self) {
+        // Other cores may take tickets concurrently, and the owner must wrap without carrying
+        // into the next ticket.
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            let owner = (Self::owner(state) + 1) & 0xffff;
+            match compare_exchange(
+                &self.state,
+                state,
+                (state & !0xffff) | owner,
+                Ordering::Release,
+            ) {
+                Ok(_) => break,
+                Err(current) => state = current,
+            }
+        }
+    }
+}
+
+/// A reader-writer spin lock.
+///
+/// Holds a writer flag in bit 31 and the number of readers in the lower bits of the lock word.
+/// Readers are preferred: a writer waits until there are no readers left.
+#[derive(Debug, Default)]
+pub struct RwSpinLock {
+    state: AtomicU32,
+}
+
+impl RwSpinLock {
+    const WRITER: u32 = 1 << 31;
+
+    /// Create an unlocked lock.
+    pub const fn new() -> RwSpinLock {
+        RwSpinLock {
+            state: AtomicU32::new(0),
+        }
+    }
+
+    /// Acquire the lock for reading, waiting until there is no writer.
+    #[inline]
+    pub fn read(&self) {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            if state & Self::WRITER != 0 {
+                state = wait_while(&self.state, state);
+                continue;
+            }
+
+            match compare_exchange(&self.state, state, state + 1, Ordering::Acquire) {
+                Ok(_) => return,
+                Err(current) => state = current,
+            }
+        }
+    }
+
+    /// Try to acquire the lock for reading without waiting, and return whether it was acquired.
+    #[inline]
+    pub fn try_read(&self) -> bool {
+        let mut state = self.state.load(Ordering::Relaxed);
+        while state & Self::WRITER == 0 {
+            match compare_exchange(&self.state, state, state + 1, Ordering::Acquire) {
+                Ok(_) => return true,
+                Err(current) => state = current,
+            }
+        }
+
+        false
+    }
+
+    /// Release the lock after reading.
+    ///
+    /// # Safety
+    ///
+    /// The lock must be held for reading by the caller.
+    #[inline]
+    pub unsafe fn unlock_read(&self) {
+        fetch_sub(&self.state, 1, Ordering::Release);
+    }
+
+    /// Acquire the lock for writing, waiting until there are no readers or writer.
+    #[inline]
+    pub fn write(&self) {
+        while let Err(state) = compare_exchange(&self.state, 0, Self::WRITER, Ordering::Acquire) {
+            wait_while(&self.state, state);
+        }
+    }
+
+    /// Try to acquire the lock for writing without waiting, and return whether it was acquired.
+    #[inline]
+    pub fn try_write(&self) -> bool {
+        compare_exchange(&self.state, 0, Self::WRITER, Ordering::Acquire).is_ok()
+    }
+
+    /// Release the lock after writing.
+    ///
+    /// # Safety
+    ///
+    /// The lock must be held for writing by the caller.
+    #[inline]
+    pub unsafe fn unlock_write(&self) {
+        self.state.store(0, Ordering::Release);
+    }
+
+    /// Return whether the lock is held for reading or writing.
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        self.state.load(Ordering::Relaxed) != 0
+    }
+}
+
+#[cfg(feature = "lock_api")]
+mod raw {
+    use super::*;
+
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+    unsafe impl lock_api::RawMutex for SpinMutex {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const INIT: SpinMutex = SpinMutex::new();
+
+        type GuardMarker = lock_api::GuardSend;
+
+        fn lock(&self) {
+            SpinMutex::lock(self)
+        }
+
+        fn try_lock(&self) -> bool {
+            SpinMutex::try_lock(self)
+        }
+
+        unsafe fn unlock(&self) {
+            SpinMutex::unlock(self)
+        }
+
+        fn is_locked(&self) -> bool {
+            SpinMutex::is_locked(self)
+        }
+    }
+
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+    unsafe impl lock_api::RawMutex for TicketLock {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const INIT: TicketLock = TicketLock::new();
+
+        type GuardMarker = lock_api::GuardSend;
+
+        fn lock(&self) {
+            TicketLock::lock(self)
+        }
+
+        fn try_lock(&self) -> bool {
+            TicketLock::try_lock(self)
+        }
+
+        unsafe fn unlock(&self) {
+            TicketLock::unlock(self)
+        }
+
+        fn is_locked(&self) -> bool {
+            TicketLock::is_locked(self)
+        }
+    }
+
+    #[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+    unsafe impl lock_api::RawRwLock for RwSpinLock {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const INIT: RwSpinLock = RwSpinLock::new();
+
+        type GuardMarker = lock_api::GuardSend;
+
+        fn lock_shared(&self) {
+            self.read()
+        }
+
+        fn try_lock_shared(&self) -> bool {
+            self.try_read()
+        }
+
+        unsafe fn unlock_shared(&self) {
+            self.unlock_read()
+        }
+
+        fn lock_exclusive(&self) {
+            self.write()
+        }
+
+        fn try_lock_exclusive(&self) -> bool {
+            self.try_write()
+        }
+
+        unsafe fn unlock_exclusive(&self) {
+            self.unlock_write()
+        }
+
+        fn is_locked(&self) -> bool {
+            RwSpinLock::is_locked(self)
+        }
+    }
+}
+
+/// A mutual exclusion lock protecting data of type `T`, built on [`SpinMutex`].
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type Mutex<T> = lock_api::Mutex<SpinMutex, T>;
+
+/// A guard of a locked [`Mutex`].
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type MutexGuard<'a, T> = lock_api::MutexGuard<'a, SpinMutex, T>;
+
+/// A fair mutual exclusion lock protecting data of type `T`, built on [`TicketLock`].
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type TicketMutex<T> = lock_api::Mutex<TicketLock, T>;
+
+/// A guard of a locked [`TicketMutex`].
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type TicketMutexGuard<'a, T> = lock_api::MutexGuard<'a, TicketLock, T>;
+
+/// A reader-writer lock protecting data of type `T`, built on [`RwSpinLock`].
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type RwLock<T> = lock_api::RwLock<RwSpinLock, T>;
+
+/// A guard of an [`RwLock`] locked for reading.
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type RwLockReadGuard<'a, T> = lock_api::RwLockReadGuard<'a, RwSpinLock, T>;
+
+/// A guard of an [`RwLock`] locked for writing.
+#[cfg(feature = "lock_api")]
+#[cfg_attr(feature = "nightly", doc(cfg(feature = "lock_api")))]
+pub type RwLockWriteGuard<'a, T> = lock_api::RwLockWriteGuard<'a, RwSpinLock, T>;
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::cell::UnsafeCell;
+    use std::{sync::Arc, thread, vec::Vec};
+
+    const THREADS: usize = 4;
+    const ITERATIONS: usize = 10_000;
+
+    struct Counter<L> {
+        lock: L,
+        value: UnsafeCell<usize>,
+    }
+
+    unsafe impl<L: Sync> Sync for Counter<L> {}
+
+    fn contend<L: Default + Send + Sync + 'static>(lock: fn(&L), unlock: unsafe fn(&L)) {
+        let counter = Arc::new(Counter {
+            lock: L::default(),
+            value: UnsafeCell::new(0),
+        });
+
+        let threads: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let counter = counter.clone();
+                thread::spawn(move || {
+                    for _ in 0..ITERATIONS {
+                        lock(&counter.lock);
+                        unsafe {
+                            *counter.value.get() += 1;
+                            unlock(&counter.lock);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(unsafe { *counter.value.get() }, THREADS * ITERATIONS);
+    }
+
+    #[test]
+    fn spin_mutex() {
+        let lock = SpinMutex::new();
+        assert!(lock.try_lock());
+        assert!(lock.is_locked());
+        assert!(!lock.try_lock());
+        unsafe { lock.unlock() };
+        assert!(!lock.is_locked());
+
+        contend(SpinMutex::lock, SpinMutex::unlock);
+    }
+
+    #[test]
+    fn ticket_lock() {
+        let lock = TicketLock::new();
+        assert!(lock.try_lock());
+        assert!(lock.is_locked());
+        assert!(!lock.try_lock());
+        unsafe { lock.unlock() };
+        assert!(!lock.is_locked());
+
+        contend(TicketLock::lock, TicketLock::unlock);
+    }
+
+    #[test]
+    fn ticket_lock_wraps() {
+        let lock = TicketLock {
+            state: AtomicU32::new(0xffff_ffff),
+        };
+        assert!(!lock.is_locked());
+
+        lock.lock();
+        assert_eq!(lock.state.load(Ordering::Relaxed), 0x0000_ffff);
+        unsafe { lock.unlock() };
+        assert_eq!(lock.state.load(Ordering::Relaxed), 0x0000_0000);
+        assert!(lock.try_lock());
+    }
+
+    #[test]
+    fn rw_spin_lock() {
+        let lock = RwSpinLock::new();
+        assert!(lock.try_read());
+        assert!(lock.try_read());
+        assert!(!lock.try_write());
+        unsafe {
+            lock.unlock_read();
+            lock.unlock_read();
+        }
+
+        assert!(lock.try_write());
+        assert!(!lock.try_read());
+        assert!(!lock.try_write());
+        unsafe { lock.unlock_write() };
+        assert!(!lock.is_locked());
+
+        contend(RwSpinLock::write, RwSpinLock::unlock_write);
+    }
+
+    #[cfg(feature = "lock_api")]
+    #[test]
+    fn mutex() {
+        let mutex = Mutex::new(1);
+        *mutex.lock() += 1;
+        assert_eq!(*mutex.lock(), 2);
+
+        let rwlock = RwLock::new(1);
+        {
+            let a = rwlock.read();
+            let b = rwlock.read();
+            assert_eq!(*a + *b, 2);
+            assert!(rwlock.try_write().is_none());
+        }
+        *rwlock.write() = 3;
+        assert_eq!(*rwlock.read(), 3);
+    }
+}
@@ -0,0 +1,425 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Data abort decoding for MMIO emulation.
+//!
+//! A data abort on emulated device memory reports the access in the ISS of `ESR_ELx` only if
+//! `ISV` is set. Loads and stores with writeback, pairs and SIMD&FP accesses are reported with
+//! `ISV` clear, and the faulting instruction must be decoded instead. [`decode`] decodes the A64
+//! load/store instructions and [`Access::from_esr`] combines both sources.
+//!
+//! # Example
+//!
+//! ```
+//! use cortex_a::mmio::{Access, Addressing};
+//!
+//! // `STP X1, X2, [X0, #16]!` faulted with ISV clear.
+//! let esr = 0x9200_0050;
+//! let access = Access::from_esr(esr, || 0xa981_0801).unwrap();
+//!
+//! assert!(access.is_write);
+//! assert_eq!(access.size, 8);
+//! assert_eq!((access.rt, access.rt2), (1, Some(2)));
+//! assert_eq!(
+//!     access.addressing,
+//!     Addressing::PreIndex { rn: 0, offset: 16 }
+//! );
+//! ```
+
+/// Exception Classes of data aborts from a lower and from the current Exception level.
+const EC_DATA_ABORT_LOWER_EL: u64 = 0b10_0100;
+const EC_DATA_ABORT_CURRENT_EL: u64 = 0b10_0101;
+
+/// How the address of an access is formed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Addressing {
+    /// A base register plus an immediate or register offset, without writeback.
+    Offset,
+    /// PC-relative.
+    Literal,
+    /// `Rn` plus `offset`, written back to `Rn` before the access.
+    PreIndex { rn: u8, offset: i64 },
+    /// `Rn`, which is incremented by `offset` after the access.
+    PostIndex { rn: u8, offset: i64 },
+}
+
+impl Addressing {
+    /// Return the base register and the value to be added to it after the access, if any.
+    pub fn writeback(&self) -> Option<(u8, i64)> {
+        match *self {
+            Addressing::PreIndex { rn, offset } | Addressing::PostIndex { rn, offset } => {
+                Some((rn, offset))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A decoded data access.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Access {
+    /// Whether the access is a store.
+    pub is_write: bool,
+    /// The size in bytes of each transferred register.
+    pub size: u8,
+    /// Whether a load sign-extends the data.
+    pub sign_extend: bool,
+    /// Whether `rt` is a 64-bit general purpose register, as opposed to a 32-bit one.
+    pub sf: bool,
+    /// Whether the transferred registers are SIMD&FP registers.
+    pub simd: bool,
+    /// The transferred register. For general purpose registers, 31 is the zero register.
+    pub rt: u8,
+    /// The second transferred register of a pair.
+    pub rt2: Option<u8>,
+    /// Whether the access has acquire or release semantics.
+    pub acquire_release: bool,
+    /// How the address is formed.
+    pub addressing: Addressing,
+}
+
+/// Errors reported when decoding a data abort.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The syndrome does not report a data abort.
+    NotDataAbort,
+    /// The instruction is not a load or store that can be emulated, such as an exclusive or an
+    /// atomic access, a structure load or store, or a prefetch.
+    Unsupported(u32),
+    /// The decoded instruction contradicts the syndrome.
+    Mismatch,
+}
+
+impl Access {
+    /// Total number of bytes transferred.
+    pub fn total_size(&self) -> usize {
+        self.size as usize * if self.rt2.is_some() { 2 } else { 1 }
+    }
+
+    /// Decode the ISS of a data abort that has `ISV` set.
+    ///
+    /// Returns `None` if `ISV` is clear.
+    pub const fn from_iss(iss: u32) -> Option<Access> {
+        if iss & (1 << 24) == 0 {
+            return None;
+        }
+
+        Some(Access {
+            is_write: iss & (1 << 6) != 0,
+            size: 1 << ((iss >> 22) & 0b11),
+            sign_extend: iss & (1 << 21) != 0,
+            sf: iss & (1 << 15) != 0,
+            simd: false,
+            rt: ((iss >> 16) & 0b1_1111) as u8,
+            rt2: None,
+            acquire_release: iss & (1 << 14) != 0,
+            addressing: Addressing::Offset,
+        })
+    }
+
+    /// Decode the data abort reported by the syndrome `esr`.
+    ///
+    /// Uses the ISS if `ISV` is set, and otherwise decodes the faulting instruction returned by
+    /// `fetch`.
+    pub fn from_esr(esr: u64, fetch: impl FnOnce() -> u32) -> Result<Access, Error> {
+        let ec = (esr >> 26) & 0b11_1111;
+        if ec != EC_DATA_ABORT_LOWER_EL && ec != EC_DATA_ABORT_CURRENT_EL {
+            return Err(Error::NotDataAbort);
+        }
+
+        let iss = (esr & 0x1ff_ffff) as u32;
+        if let Some(access) = Access::from_iss(iss) {
+            return Ok(access);
+        }
+
+        let access = decode(fetch())?;
+        // WnR is valid for all data aborts on a load or store.
+        if access.is_write != (iss & (1 << 6) != 0) {
+            return Err(Error::Mismatch);
+        }
+
+        Ok(access)
+    }
+}
+
+fn sext(value: u32, bits: u32) -> i64 {
+    ((value as i64) << (64 - bits)) >> (64 - bits)
+}
+
+/// Decode `size`, `V` and `opc` of a single register load or store into `access`.
+fn single(access: &mut Access, insn: u32) -> Result<(), Error> {
+    let size = insn >> 30;
+    let opc = (insn >> 22) & 0b11;
+
+    if access.simd {
+        let scale = ((opc >> 1) << 2) | size;
+        if scale > 4 {
+            return Err(Error::Unsupported(insn));
+        }
+
+        access.size = 1 << scale;
+        access.is_write = opc & 1 == 0;
+        return Ok(());
+    }
+
+    access.size = 1 << size;
+    match (size, opc) {
+        (_, 0b00) => {
+            access.is_write = true;
+            access.sf = size == 3;
+        }
+        (_, 0b01) => access.sf = size == 3,
+        (0..=2, 0b10) => {
+            access.sign_extend = true;
+            access.sf = true;
+        }
+        (0..=1, 0b11) => access.sign_extend = true,
+        // Prefetches and unallocated encodings.
+        _ => return Err(Error::Unsupported(insn)),
+    }
+
+    Ok(())
+}
+
+/// Decode the A64 load or store instruction `insn`.
+pub fn decode(insn: u32) -> Result<Access, Error> {
+    let rt = (insn & 0b1_1111) as u8;
+    let rn = ((insn >> 5) & 0b1_1111) as u8;
+    let simd = insn & (1 << 26) != 0;
+
+    let mut access = Access {
+        is_write: false,
+        size: 0,
+        sign_extend: false,
+        sf: false,
+        simd,
+        rt,
+        rt2: None,
+        acquire_release: false,
+        addressing: Addressing::Offset,
+    };
+
+    if insn & 0x3b00_0000 == 0x3900_0000 {
+        // Load/store register (unsigned immediate).
+        single(&mut access, insn)?;
+    } else if insn & 0x3b20_0000 == 0x3800_0000 {
+        // Load/store register (unscaled immediate, immediate post-indexed, unprivileged,
+        // immediate pre-indexed).
+        single(&mut access, insn)?;
+
+        let offset = sext(insn >> 12, 9);
+        access.addressing = match (insn >> 10) & 0b11 {
+            0b01 => Addressing::PostIndex { rn, offset },
+            0b11 => Addressing::PreIndex { rn, offset },
+            _ => Addressing::Offset,
+        };
+    } else if insn & 0x3b20_0c00 == 0x3820_0800 {
+        // Load/store register (register offset).
+        single(&mut access, insn)?;
+    } else if insn & 0x3b00_0000 == 0x1800_0000 {
+        // Load register (literal).
+        access.addressing = Addressing::Literal;
+        match (insn >> 30, simd) {
+            (0b00, false) => access.size = 4,
+            (0b01, false) => {
+                access.size = 8;
+                access.sf = true;
+            }
+            (0b10, false) => {
+                access.size = 4;
+                access.sign_extend = true;
+                access.sf = true;
+            }
+            (opc @ 0b00..=0b10, true) => access.size = 4 << opc,
+            _ => return Err(Error::Unsupported(insn)),
+        }
+    } else if insn & 0x3a00_0000 == 0x2800_0000 {
+        // Load/store register pair.
+        let opc = insn >> 30;
+        let is_load = insn & (1 << 22) != 0;
+        match (opc, simd) {
+            (0b00, false) => access.size = 4,
+            (0b01, false) if is_load => {
+                access.size = 4;
+                access.sign_extend = true;
+                access.sf = true;
+            }
+            (0b10, false) => {
+                access.size = 8;
+                access.sf = true;
+            }
+            (0b00..=0b10, true) => access.size = 4 << opc,
+            _ => return Err(Error::Unsupported(insn)),
+        }
+        access.is_write = !is_load;
+        access.rt2 = Some(((insn >> 10) & 0b1_1111) as u8);
+
+        let offset = sext(insn >> 15, 7) * access.size as i64;
+        access.addressing = match (insn >> 23) & 0b11 {
+            0b01 => Addressing::PostIndex { rn, offset },
+            0b11 => Addressing::PreIndex { rn, offset },
+            _ => Addressing::Offset,
+        };
+    } else if insn & 0x3fa0_0000 == 0x0880_0000 {
+        // Load-acquire and store-release register, without exclusives (o2 == 1, o1 == 0).
+        let size = insn >> 30;
+        access.size = 1 << size;
+        access.sf = size == 3;
+        access.is_write = insn & (1 << 22) == 0;
+        access.acquire_release = true;
+    } else {
+        return Err(Error::Unsupported(insn));
+    }
+
+    Ok(access)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shorthand for the expected decoding of a general purpose register access.
+    fn gpr(is_write: bool, size: u8, sign_extend: bool, sf: bool, rt: u8) -> Access {
+        Access {
+            is_write,
+            size,
+            sign_extend,
+            sf,
+            simd: false,
+            rt,
+            rt2: None,
+            acquire_release: false,
+            addressing: Addressing::Offset,
+        }
+    }
+
+    fn fp(is_write: bool, size: u8, rt: u8) -> Access {
+        Access {
+            simd: true,
+            ..gpr(is_write, size, false, false, rt)
+        }
+    }
+
+    fn pre(rn: u8, offset: i64) -> Addressing {
+        Addressing::PreIndex { rn, offset }
+    }
+
+    fn post(rn: u8, offset: i64) -> Addressing {
+        Addressing::PostIndex { rn, offset }
+    }
+
+    #[test]
+    fn corpus() {
+        #[rustfmt::skip]
+        let corpus: &[(u32, &str, Access)] = &[
+            // Unsigned immediate.
+            (0xb9400020, "ldr w0, [x1]", gpr(false, 4, false, false, 0)),
+            (0xf9400862, "ldr x2, [x3, #16]", gpr(false, 8, false, true, 2)),
+            (0x39000485, "strb w5, [x4, #1]", gpr(true, 1, false, false, 5)),
+            (0x79000ce7, "strh w7, [x7, #6]", gpr(true, 2, false, false, 7)),
+            (0x39c00128, "ldrsb w8, [x9]", gpr(false, 1, true, false, 8)),
+            (0x3980016a, "ldrsb x10, [x11]", gpr(false, 1, true, true, 10)),
+            (0x79c001ac, "ldrsh w12, [x13]", gpr(false, 2, true, false, 12)),
+            (0xb98001ee, "ldrsw x14, [x15]", gpr(false, 4, true, true, 14)),
+            (0xf900001f, "str xzr, [x0]", gpr(true, 8, false, true, 31)),
+            (0xbd400020, "ldr s0, [x1]", fp(false, 4, 0)),
+            (0xfd000441, "str d1, [x2, #8]", fp(true, 8, 1)),
+            (0x3dc00462, "ldr q2, [x3, #16]", fp(false, 16, 2)),
+            (0x7d400083, "ldr h3, [x4]", fp(false, 2, 3)),
+            (0x3d0000a4, "str b4, [x5]", fp(true, 1, 4)),
+            // Unscaled immediate and unprivileged.
+            (0xb85fc020, "ldur w0, [x1, #-4]", gpr(false, 4, false, false, 0)),
+            (0xf8001062, "stur x2, [x3, #1]", gpr(true, 8, false, true, 2)),
+            (0xb8400820, "ldtr w0, [x1]", gpr(false, 4, false, false, 0)),
+            // Post- and pre-indexed.
+            (0xf8408420, "ldr x0, [x1], #8", Access { addressing: post(1, 8), ..gpr(false, 8, false, true, 0) }),
+            (0xb81fcc62, "str w2, [x3, #-4]!", Access { addressing: pre(3, -4), ..gpr(true, 4, false, false, 2) }),
+            (0x38c014a4, "ldrsb w4, [x5], #1", Access { addressing: post(5, 1), ..gpr(false, 1, true, false, 4) }),
+            (0x3cc10c20, "ldr q0, [x1, #16]!", Access { addressing: pre(1, 16), ..fp(false, 16, 0) }),
+            (0x3c9f07e1, "str q1, [sp], #-16", Access { addressing: post(31, -16), ..fp(true, 16, 1) }),
+            // Register offset.
+            (0xf8627820, "ldr x0, [x1, x2, lsl #3]", gpr(false, 8, false, true, 0)),
+            (0x38234845, "strb w5, [x2, w3, uxtw]", gpr(true, 1, false, false, 5)),
+            (0xbc627820, "ldr s0, [x1, x2, lsl #2]", fp(false, 4, 0)),
+            // Literal.
+            (0x18000040, "ldr w0, #8", Access { addressing: Addressing::Literal, ..gpr(false, 4, false, false, 0) }),
+            (0x58000041, "ldr x1, #8", Access { addressing: Addressing::Literal, ..gpr(false, 8, false, true, 1) }),
+            (0x98000042, "ldrsw x2, #8", Access { addressing: Addressing::Literal, ..gpr(false, 4, true, true, 2) }),
+            (0x9c000043, "ldr q3, #8", Access { addressing: Addressing::Literal, ..fp(false, 16, 3) }),
+            // Pairs.
+            (0xa9408820, "ldp x0, x2, [x1, #8]", Access { rt2: Some(2), ..gpr(false, 8, false, true, 0) }),
+            (0x29010fe2, "stp w2, w3, [sp, #8]", Access { rt2: Some(3), ..gpr(true, 4, false, false, 2) }),
+            (0xa9bf7bfd, "stp x29, x30, [sp, #-16]!", Access { rt2: Some(30), addressing: pre(31, -16), ..gpr(true, 8, false, true, 29) }),
+            (0xa8c17bfd, "ldp x29, x30, [sp], #16", Access { rt2: Some(30), addressing: post(31, 16), ..gpr(false, 8, false, true, 29) }),
+            (0x69400820, "ldpsw x0, x2, [x1]", Access { rt2: Some(2), ..gpr(false, 4, true, true, 0) }),
+            (0xad400440, "ldp q0, q1, [x2]", Access { rt2: Some(1), ..fp(false, 16, 0) }),
+            (0x6d810c62, "stp d2, d3, [x3, #16]!", Access { rt2: Some(3), addressing: pre(3, 16), ..fp(true, 8, 2) }),
+            (0xa8400820, "ldnp x0, x2, [x1]", Access { rt2: Some(2), ..gpr(false, 8, false, true, 0) }),
+            // Acquire/release.
+            (0x88dffc20, "ldar w0, [x1]", Access { acquire_release: true, ..gpr(false, 4, false, false, 0) }),
+            (0xc89ffc62, "stlr x2, [x3]", Access { acquire_release: true, ..gpr(true, 8, false, true, 2) }),
+            (0x08dffca4, "ldarb w4, [x5]", Access { acquire_release: true, ..gpr(false, 1, false, false, 4) }),
+            (0x489f7ce6, "stllrh w6, [x7]", Access { acquire_release: true, ..gpr(true, 2, false, false, 6) }),
+        ];
+
+        for (insn, text, expected) in corpus {
+            assert_eq!(decode(*insn), Ok(*expected), "{}", text);
+        }
+    }
+
+    #[test]
+    fn unsupported() {
+        #[rustfmt::skip]
+        let corpus: &[(u32, &str)] = &[
+            (0xf9800020, "prfm pldl1keep, [x1]"),
+            (0x885f7c20, "ldxr w0, [x1]"),
+            (0xc8027c20, "stxr w2, x0, [x1]"),
+            (0xb8200020, "ldadd w0, w0, [x1]"),
+            (0x88a07c41, "cas w0, w1, [x2]"),
+            (0x4c407000, "ld1 {v0.16b}, [x0]"),
+            (0xd503201f, "nop"),
+        ];
+
+        for (insn, text) in corpus {
+            assert_eq!(decode(*insn), Err(Error::Unsupported(*insn)), "{}", text);
+        }
+    }
+
+    #[test]
+    fn syndromes() {
+        // `LDR W3, [X1]` with ISV set.
+        let esr = 0x9380_0006 | (3 << 16);
+        assert_eq!(
+            Access::from_esr(esr, || unreachable!()),
+            Ok(gpr(false, 4, false, false, 3))
+        );
+
+        // `LDRSH X5, [X0]` with ISV set.
+        let esr = 0x9300_0006 | (1 << 22) | (1 << 21) | (5 << 16) | (1 << 15);
+        assert_eq!(
+            Access::from_esr(esr, || unreachable!()),
+            Ok(gpr(false, 2, true, true, 5))
+        );
+
+        // `STP X1, X2, [X0, #16]!` with ISV clear, from the current Exception level.
+        let access = Access::from_esr(0x9600_0050, || 0xa9810801).unwrap();
+        assert_eq!(access.addressing.writeback(), Some((0, 16)));
+        assert_eq!(access.total_size(), 16);
+
+        // WnR contradicting the instruction.
+        assert_eq!(
+            Access::from_esr(0x9200_0006, || 0xa9810801),
+            Err(Error::Mismatch)
+        );
+
+        // A trapped MSR.
+        assert_eq!(
+            Access::from_esr(0x6232_0461, || unreachable!()),
+            Err(Error::NotDataAbort)
+        );
+    }
+}
@@ -0,0 +1,948 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation regime configuration and MMU control.
+//!
+//! [`TranslationConfig`] builds a `TCR_EL1` or `TCR_EL2` value from the requested address sizes,
+//! granule and attributes, after checking each request against the [`Capabilities`] of the PE.
+//!
+//! [`enable_el1`], [`enable_el2`], [`disable_el1`] and [`disable_el2`] perform the architecturally
+//! required sequences to turn the MMU on and off. All register writes, maintenance operations and
+//! barriers go through a [`Backend`], which allows the sequences to be exercised without hardware.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::mmu::{self, Capabilities, Config, Granule, TranslationConfig};
+//!
+//! let tcr = TranslationConfig::new(48, Granule::KiB4)
+//!     .hardware_access_flag(true)
+//!     .tcr_el1(&Capabilities::current())
+//!     .unwrap();
+//!
+//! # let (mair, ttbr0, ttbr1) = (0xff, 0x8_0000, 0x9_0000);
+//! unsafe {
+//!     mmu::enable_el1(&Config {
+//!         mair,
+//!         tcr: tcr.value,
+//!         ttbr0,
+//!         ttbr1: Some(ttbr1),
+//!     })
+//! };
+//! ```
+
+use crate::{asm::barrier, registers::*};
+use core::fmt;
+use tock_registers::{
+    fields::FieldValue,
+    interfaces::{Readable, Writeable},
+    LocalRegisterCopy,
+};
+
+/// Translation granule size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Granule {
+    KiB4,
+    KiB16,
+    KiB64,
+}
+
+/// Cacheability of translation table walks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cacheability {
+    NonCacheable = 0b00,
+    WriteBack = 0b01,
+    WriteThrough = 0b10,
+    WriteBackNoWriteAllocate = 0b11,
+}
+
+/// Shareability of translation table walks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Shareability {
+    NonShareable = 0b00,
+    Outer = 0b10,
+    Inner = 0b11,
+}
+
+/// Memory translation features of a PE.
+#[derive(Copy, Clone, Debug)]
+pub struct Capabilities {
+    mmfr0: LocalRegisterCopy<u64, ID_AA64MMFR0_EL1::Register>,
+    mmfr1: LocalRegisterCopy<u64, ID_AA64MMFR1_EL1::Register>,
+    mmfr2: LocalRegisterCopy<u64, ID_AA64MMFR2_EL1::Register>,
+}
+
+impl Capabilities {
+    /// Return the capabilities of the executing PE.
+    pub fn current() -> Capabilities {
+        Capabilities::from_raw(
+            ID_AA64MMFR0_EL1.get(),
+            ID_AA64MMFR1_EL1.get(),
+            ID_AA64MMFR2_EL1.get(),
+        )
+    }
+
+    /// Create the capabilities from values of `ID_AA64MMFR0_EL1`, `ID_AA64MMFR1_EL1` and
+    /// `ID_AA64MMFR2_EL1`.
+    pub const fn from_raw(mmfr0: u64, mmfr1: u64, mmfr2: u64) -> Capabilities {
+        Capabilities {
+            mmfr0: LocalRegisterCopy::new(mmfr0),
+            mmfr1: LocalRegisterCopy::new(mmfr1),
+            mmfr2: LocalRegisterCopy::new(mmfr2),
+        }
+    }
+
+    /// Return whether `granule` is supported.
+    pub fn granule(&self, granule: Granule) -> bool {
+        match granule {
+            Granule::KiB4 => self.mmfr0.read(ID_AA64MMFR0_EL1::TGran4) != 0b1111,
+            Granule::KiB16 => self.mmfr0.read(ID_AA64MMFR0_EL1::TGran16) != 0b0000,
+            Granule::KiB64 => self.mmfr0.read(ID_AA64MMFR0_EL1::TGran64) != 0b1111,
+        }
+    }
+
+    /// Return whether 52-bit addresses are supported with `granule`, which is FEAT_LVA for
+    /// virtual addresses and FEAT_LPA for physical addresses with the 64KiB granule, and FEAT_LPA2
+    /// for both with the 4KiB and 16KiB granules.
+    fn large_addresses(&self, granule: Granule, va: bool) -> bool {
+        match granule {
+            Granule::KiB4 => self.mmfr0.read(ID_AA64MMFR0_EL1::TGran4) == 0b0001,
+            Granule::KiB16 => self.mmfr0.read(ID_AA64MMFR0_EL1::TGran16) == 0b0010,
+            Granule::KiB64 if va => self.mmfr2.read(ID_AA64MMFR2_EL1::VARange) == 0b0001,
+            Granule::KiB64 => true,
+        }
+    }
+
+    /// Return the supported physical address size in bits.
+    pub fn pa_bits(&self) -> u8 {
+        match self.mmfr0.read(ID_AA64MMFR0_EL1::PARange) {
+            0b0000 => 32,
+            0b0001 => 36,
+            0b0010 => 40,
+            0b0011 => 42,
+            0b0100 => 44,
+            0b0101 => 48,
+            _ => 52,
+        }
+    }
+
+    /// Return whether 16-bit ASIDs are supported.
+    pub fn asid16(&self) -> bool {
+        self.mmfr0.read(ID_AA64MMFR0_EL1::ASIDBits) == 0b0010
+    }
+
+    /// Return whether hardware updates of the Access flag are supported.
+    pub fn hardware_access_flag(&self) -> bool {
+        self.mmfr1.read(ID_AA64MMFR1_EL1::HAFDBS) >= 0b0001
+    }
+
+    /// Return whether hardware updates of the dirty state are supported.
+    pub fn hardware_dirty_state(&self) -> bool {
+        self.mmfr1.read(ID_AA64MMFR1_EL1::HAFDBS) >= 0b0010
+    }
+
+    /// Return whether hierarchical permissions can be disabled.
+    pub fn hierarchical_permission_disables(&self) -> bool {
+        self.mmfr1.read(ID_AA64MMFR1_EL1::HPDS) >= 0b0001
+    }
+}
+
+/// A configuration that is not supported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The granule is not supported.
+    Granule(Granule),
+    /// The virtual address size is outside of 25 to 52 bits.
+    VaBits(u8),
+    /// 52-bit virtual addresses are not supported with the granule.
+    LargeVa(Granule),
+    /// The physical address size is not one of 32, 36, 40, 42, 44, 48 or 52 bits.
+    PaBits(u8),
+    /// The physical address size exceeds the supported size.
+    PaRange { requested: u8, supported: u8 },
+    /// 52-bit physical addresses are not supported with the granule.
+    LargePa(Granule),
+    /// Hardware updates of the Access flag are not supported.
+    HardwareAccessFlag,
+    /// Hardware updates of the dirty state are not supported.
+    HardwareDirtyState,
+    /// Hierarchical permissions cannot be disabled.
+    HierarchicalPermissions,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Granule(granule) => write!(
+                f,
+                "{:?} granule not supported (ID_AA64MMFR0_EL1.TGran)",
+                granule
+            ),
+            Error::VaBits(bits) => write!(f, "{}-bit virtual addresses out of range", bits),
+            Error::LargeVa(Granule::KiB64) => write!(
+                f,
+                "52-bit virtual addresses need FEAT_LVA (ID_AA64MMFR2_EL1.VARange)"
+            ),
+            Error::LargeVa(granule) => write!(
+                f,
+                "52-bit virtual addresses with {:?} granule need FEAT_LPA2 (ID_AA64MMFR0_EL1.TGran)",
+                granule
+            ),
+            Error::PaBits(bits) => write!(f, "{}-bit physical addresses not encodable", bits),
+            Error::PaRange {
+                requested,
+                supported,
+            } => write!(
+                f,
+                "{}-bit physical addresses exceed the supported {} bits (ID_AA64MMFR0_EL1.PARange)",
+                requested, supported
+            ),
+            Error::LargePa(granule) => write!(
+                f,
+                "52-bit physical addresses with {:?} granule need FEAT_LPA2 (ID_AA64MMFR0_EL1.TGran)",
+                granule
+            ),
+            Error::HardwareAccessFlag => write!(
+                f,
+                "hardware Access flag updates need FEAT_HAFDBS (ID_AA64MMFR1_EL1.HAFDBS)"
+            ),
+            Error::HardwareDirtyState => write!(
+                f,
+                "hardware dirty state updates need FEAT_HAFDBS (ID_AA64MMFR1_EL1.HAFDBS)"
+            ),
+            Error::HierarchicalPermissions => write!(
+                f,
+                "disabling hierarchical permissions needs FEAT_HPDS (ID_AA64MMFR1_EL1.HPDS)"
+            ),
+        }
+    }
+}
+
+/// The TCR fields derived from a [`TranslationConfig`].
+struct Fields {
+    txsz: u64,
+    ps: u64,
+    ds: bool,
+}
+
+/// Configuration of a stage 1 translation regime.
+///
+/// Defaults to the largest supported physical address size of up to 48 bits, write-back
+/// cacheable and Inner Shareable table walks, and all optional features disabled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TranslationConfig {
+    va_bits: u8,
+    granule: Granule,
+    pa_bits: Option<u8>,
+    inner: Cacheability,
+    outer: Cacheability,
+    shareability: Shareability,
+    top_byte_ignore: bool,
+    hardware_access_flag: bool,
+    hardware_dirty_state: bool,
+    hierarchical_permissions: bool,
+}
+
+impl TranslationConfig {
+    /// Create a configuration for `va_bits` wide virtual addresses, translated with `granule`.
+    pub const fn new(va_bits: u8, granule: Granule) -> TranslationConfig {
+        TranslationConfig {
+            va_bits,
+            granule,
+            pa_bits: None,
+            inner: Cacheability::WriteBack,
+            outer: Cacheability::WriteBack,
+            shareability: Shareability::Inner,
+            top_byte_ignore: false,
+            hardware_access_flag: false,
+            hardware_dirty_state: false,
+            hierarchical_permissions: true,
+        }
+    }
+
+    /// Set the physical address size in bits.
+    pub const fn pa_bits(mut self, pa_bits: u8) -> TranslationConfig {
+        self.pa_bits = Some(pa_bits);
+        self
+    }
+
+    /// Set the inner and outer cacheability of table walks.
+    pub const fn cacheability(
+        mut self,
+        inner: Cacheability,
+        outer: Cacheability,
+    ) -> TranslationConfig {
+        self.inner = inner;
+        self.outer = outer;
+        self
+    }
+
+    /// Set the shareability of table walks.
+    pub const fn shareability(mut self, shareability: Shareability) -> TranslationConfig {
+        self.shareability = shareability;
+        self
+    }
+
+    /// Ignore the top byte of virtual addresses.
+    pub const fn top_byte_ignore(mut self, enable: bool) -> TranslationConfig {
+        self.top_byte_ignore = enable;
+        self
+    }
+
+    /// Let hardware set the Access flag of descriptors.
+    pub const fn hardware_access_flag(mut self, enable: bool) -> TranslationConfig {
+        self.hardware_access_flag = enable;
+        self
+    }
+
+    /// Let hardware manage the dirty state of descriptors with the DBM bit set. Implies
+    /// [`TranslationConfig::hardware_access_flag`].
+    pub const fn hardware_dirty_state(mut self, enable: bool) -> TranslationConfig {
+        self.hardware_dirty_state = enable;
+        self
+    }
+
+    /// Enable or disable the hierarchical permissions of table descriptors.
+    pub const fn hierarchical_permissions(mut self, enable: bool) -> TranslationConfig {
+        self.hierarchical_permissions = enable;
+        self
+    }
+
+    fn check(&self, caps: &Capabilities) -> Result<Fields, Error> {
+        let granule = self.granule;
+        if !caps.granule(granule) {
+            return Err(Error::Granule(granule));
+        }
+
+        if !(25..=52).contains(&self.va_bits) {
+            return Err(Error::VaBits(self.va_bits));
+        }
+        if self.va_bits > 48 && !caps.large_addresses(granule, true) {
+            return Err(Error::LargeVa(granule));
+        }
+
+        let supported = caps.pa_bits();
+        let pa_bits = self.pa_bits.unwrap_or_else(|| supported.min(48));
+        let ps = match pa_bits {
+            32 => 0b000,
+            36 => 0b001,
+            40 => 0b010,
+            42 => 0b011,
+            44 => 0b100,
+            48 => 0b101,
+            52 => 0b110,
+            _ => return Err(Error::PaBits(pa_bits)),
+        };
+        if pa_bits > supported {
+            return Err(Error::PaRange {
+                requested: pa_bits,
+                supported,
+            });
+        }
+        if pa_bits > 48 && !caps.large_addresses(granule, false) {
+            return Err(Error::LargePa(granule));
+        }
+
+        if (self.hardware_access_flag || self.hardware_dirty_state) && !caps.hardware_access_flag()
+        {
+            return Err(Error::HardwareAccessFlag);
+        }
+        if self.hardware_dirty_state && !caps.hardware_dirty_state() {
+            return Err(Error::HardwareDirtyState);
+        }
+        if !self.hierarchical_permissions && !caps.hierarchical_permission_disables() {
+            return Err(Error::HierarchicalPermissions);
+        }
+
+        Ok(Fields {
+            txsz: 64 - self.va_bits as u64,
+            ps,
+            ds: granule != Granule::KiB64 && (self.va_bits > 48 || pa_bits > 48),
+        })
+    }
+
+    /// Return the `TCR_EL1` value, which also applies to `TCR_EL2` with `HCR_EL2.E2H` set.
+    ///
+    /// Both the TTBR0 and the TTBR1 region use this configuration, and the ASID is taken from
+    /// `TTBR0_EL1`. 16-bit ASIDs are selected when supported.
+    pub fn tcr_el1(
+        &self,
+        caps: &Capabilities,
+    ) -> Result<FieldValue<u64, TCR_EL1::Register>, Error> {
+        let fields = self.check(caps)?;
+
+        let (tg0, tg1) = match self.granule {
+            Granule::KiB4 => (TCR_EL1::TG0::KiB_4, TCR_EL1::TG1::KiB_4),
+            Granule::KiB16 => (TCR_EL1::TG0::KiB_16, TCR_EL1::TG1::KiB_16),
+            Granule::KiB64 => (TCR_EL1::TG0::KiB_64, TCR_EL1::TG1::KiB_64),
+        };
+
+        Ok(TCR_EL1::T0SZ.val(fields.txsz)
+            + TCR_EL1::T1SZ.val(fields.txsz)
+            + tg0
+            + tg1
+            + TCR_EL1::IRGN0.val(self.inner as u64)
+            + TCR_EL1::IRGN1.val(self.inner as u64)
+            + TCR_EL1::ORGN0.val(self.outer as u64)
+            + TCR_EL1::ORGN1.val(self.outer as u64)
+            + TCR_EL1::SH0.val(self.shareability as u64)
+            + TCR_EL1::SH1.val(self.shareability as u64)
+            + TCR_EL1::IPS.val(fields.ps)
+            + TCR_EL1::AS.val(caps.asid16() as u64)
+            + TCR_EL1::TBI0.val(self.top_byte_ignore as u64)
+            + TCR_EL1::TBI1.val(self.top_byte_ignore as u64)
+            + TCR_EL1::HA.val((self.hardware_access_flag || self.hardware_dirty_state) as u64)
+            + TCR_EL1::HD.val(self.hardware_dirty_state as u64)
+            + TCR_EL1::HPD0.val(!self.hierarchical_permissions as u64)
+            + TCR_EL1::HPD1.val(!self.hierarchical_permissions as u64)
+            + TCR_EL1::DS.val(fields.ds as u64))
+    }
+
+    /// Return the `TCR_EL2` value for the EL2 translation regime, with `HCR_EL2.E2H` clear.
+    pub fn tcr_el2(
+        &self,
+        caps: &Capabilities,
+    ) -> Result<FieldValue<u64, TCR_EL2::Register>, Error> {
+        let fields = self.check(caps)?;
+
+        let tg0 = match self.granule {
+            Granule::KiB4 => TCR_EL2::TG0::KiB_4,
+            Granule::KiB16 => TCR_EL2::TG0::KiB_16,
+            Granule::KiB64 => TCR_EL2::TG0::KiB_64,
+        };
+        // Bits 31 and 23 are RES1.
+        let res1 = FieldValue::<u64, TCR_EL2::Register>::new(1, 31, 1)
+            + FieldValue::<u64, TCR_EL2::Register>::new(1, 23, 1);
+
+        Ok(res1
+            + TCR_EL2::T0SZ.val(fields.txsz)
+            + tg0
+            + TCR_EL2::IRGN0.val(self.inner as u64)
+            + TCR_EL2::ORGN0.val(self.outer as u64)
+            + TCR_EL2::SH0.val(self.shareability as u64)
+            + TCR_EL2::PS.val(fields.ps)
+            + TCR_EL2::TBI.val(self.top_byte_ignore as u64)
+            + TCR_EL2::HA.val((self.hardware_access_flag || self.hardware_dirty_state) as u64)
+            + TCR_EL2::HD.val(self.hardware_dirty_state as u64)
+            + TCR_EL2::HPD.val(!self.hierarchical_permissions as u64)
+            + TCR_EL2::DS.val(fields.ds as u64))
+    }
+}
+
+/// `SCTLR_ELx.M`, the MMU enable.
+const SCTLR_M: u64 = 1 << 0;
+/// `SCTLR_ELx.C`, the data cache enable.
+const SCTLR_C: u64 = 1 << 2;
+/// `SCTLR_ELx.I`, the instruction cache enable.
+const SCTLR_I: u64 = 1 << 12;
+
+/// The translation regime set up by [`enable_el1`] and [`enable_el2`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The `MAIR_ELx` value.
+    pub mair: u64,
+    /// The `TCR_ELx` value, e.g. from [`TranslationConfig`].
+    pub tcr: u64,
+    /// The `TTBR0_ELx` value.
+    pub ttbr0: u64,
+    /// The `TTBR1_ELx` value, which is only available at EL2 with `HCR_EL2.E2H` set.
+    pub ttbr1: Option<u64>,
+}
+
+/// A register written by the MMU sequences.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Reg {
+    Mair,
+    Tcr,
+    Ttbr0,
+    Ttbr1,
+    Sctlr,
+}
+
+/// A maintenance operation or barrier of the MMU sequences.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Op {
+    /// Invalidate all stage 1 TLB entries of the translation regime on the executing PE.
+    InvalidateTlb,
+    /// Invalidate the data and unified caches of the executing PE by set/way.
+    InvalidateDCache,
+    /// Clean and invalidate the data and unified caches of the executing PE by set/way.
+    CleanInvalidateDCache,
+    /// Invalidate all instruction caches to the Point of Unification.
+    InvalidateICache,
+    /// `DSB ISH`.
+    DsbIsh,
+    /// `DSB SY`.
+    DsbSy,
+    /// `ISB`.
+    Isb,
+}
+
+/// Access to the registers and maintenance operations of a translation regime.
+pub trait Backend {
+    /// Read `reg`.
+    fn read(&self, reg: Reg) -> u64;
+
+    /// Write `value` to `reg`.
+    fn write(&mut self, reg: Reg, value: u64);
+
+    /// Perform `op`.
+    fn execute(&mut self, op: Op);
+}
+
+/// The EL1&0 translation regime of the executing PE.
+pub struct El1;
+
+/// The EL2 translation regime of the executing PE.
+pub struct El2;
+
+impl Backend for El1 {
+    fn read(&self, reg: Reg) -> u64 {
+        match reg {
+            Reg::Mair => MAIR_EL1.get(),
+            Reg::Tcr => TCR_EL1.get(),
+            Reg::Ttbr0 => TTBR0_EL1.get(),
+            Reg::Ttbr1 => TTBR1_EL1.get(),
+            Reg::Sctlr => SCTLR_EL1.get(),
+        }
+    }
+
+    fn write(&mut self, reg: Reg, value: u64) {
+        match reg {
+            Reg::Mair => MAIR_EL1.set(value),
+            Reg::Tcr => TCR_EL1.set(value),
+            Reg::Ttbr0 => TTBR0_EL1.set(value),
+            Reg::Ttbr1 => TTBR1_EL1.set(value),
+            Reg::Sctlr => SCTLR_EL1.set(value),
+        }
+    }
+
+    fn execute(&mut self, op: Op) {
+        match op {
+            Op::InvalidateTlb => tlbi!("vmalle1"),
+            op => execute(op),
+        }
+    }
+}
+
+impl Backend for El2 {
+    fn read(&self, reg: Reg) -> u64 {
+        match reg {
+            Reg::Mair => MAIR_EL2.get(),
+            Reg::Tcr => TCR_EL2.get(),
+            Reg::Ttbr0 => TTBR0_EL2.get(),
+            Reg::Ttbr1 => TTBR1_EL2.get(),
+            Reg::Sctlr => SCTLR_EL2.get(),
+        }
+    }
+
+    fn write(&mut self, reg: Reg, value: u64) {
+        match reg {
+            Reg::Mair => MAIR_EL2.set(value),
+            Reg::Tcr => TCR_EL2.set(value),
+            Reg::Ttbr0 => TTBR0_EL2.set(value),
+            Reg::Ttbr1 => TTBR1_EL2.set(value),
+            Reg::Sctlr => SCTLR_EL2.set(value),
+        }
+    }
+
+    fn execute(&mut self, op: Op) {
+        match op {
+            Op::InvalidateTlb => tlbi!("alle2"),
+            op => execute(op),
+        }
+    }
+}
+
+macro_rules! tlbi {
+    ($op:literal) => {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe { core::arch::asm!(concat!("tlbi ", $op), options(nostack)) },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    };
+}
+use tlbi;
+
+/// Perform the operations that do not depend on the translation regime.
+fn execute(op: Op) {
+    match op {
+        Op::InvalidateTlb => unreachable!(),
+        Op::InvalidateDCache => dcache_by_set_way(false),
+        Op::CleanInvalidateDCache => dcache_by_set_way(true),
+        Op::InvalidateICache => match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe { core::arch::asm!("ic iallu", options(nostack)) },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        },
+        Op::DsbIsh => barrier::dsb(barrier::ISH),
+        Op::DsbSy => barrier::dsb(barrier::SY),
+        Op::Isb => barrier::isb(barrier::SY),
+    }
+}
+
+/// Invalidate, or clean and invalidate, all data and unified cache levels up to the Level of
+/// Coherence by set/way.
+fn dcache_by_set_way(clean: bool) {
+    let clidr = CLIDR_EL1.get();
+    let loc = CLIDR_EL1.read(CLIDR_EL1::LoC);
+
+    for level in 0..loc {
+        // Ctype of 0b010 and above has a data or unified cache.
+        if (clidr >> (3 * level)) & 0b111 < 0b010 {
+            continue;
+        }
+
+        CSSELR_EL1.set(level << 1);
+        barrier::isb(barrier::SY);
+
+        let line_shift = CCSIDR_EL1.read(CCSIDR_EL1::LineSize) + 4;
+        let ways = CCSIDR_EL1.get_associativity() + 1;
+        let sets = CCSIDR_EL1.get_num_sets() + 1;
+        let way_shift = ((ways - 1) as u32).leading_zeros();
+
+        for way in 0..ways {
+            for set in 0..sets {
+                dc_set_way(
+                    clean,
+                    (way << way_shift) | (set << line_shift) | (level << 1),
+                );
+            }
+        }
+    }
+}
+
+/// Invalidate, or clean and invalidate, one data or unified cache line by set/way.
+fn dc_set_way(clean: bool, set_way: u64) {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => unsafe {
+            if clean {
+                core::arch::asm!("dc cisw, {}", in(reg) set_way, options(nostack))
+            } else {
+                core::arch::asm!("dc isw, {}", in(reg) set_way, options(nostack))
+            }
+        },
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => {
+            let _ = (clean, set_way);
+            unimplemented!()
+        }
+    }
+}
+
+/// Set up the translation regime of `backend` from `config` and enable the MMU and caches.
+///
+/// If the MMU and data cache are off on entry, the data and instruction caches are invalidated
+/// first, so that no stale lines shadow the data, including the translation tables, that was
+/// written while they were off.
+///
+/// # Safety
+///
+/// The code, stack and data in use must be mapped at their current addresses, and the
+/// translation tables must be complete and visible to the table walker.
+pub unsafe fn enable_with<B: Backend>(backend: &mut B, config: &Config) {
+    let sctlr = backend.read(Reg::Sctlr);
+
+    if sctlr & (SCTLR_M | SCTLR_C) == 0 {
+        backend.execute(Op::InvalidateDCache);
+        backend.execute(Op::InvalidateICache);
+        backend.execute(Op::DsbSy);
+    }
+
+    backend.write(Reg::Mair, config.mair);
+    backend.write(Reg::Tcr, config.tcr);
+    backend.write(Reg::Ttbr0, config.ttbr0);
+    if let Some(ttbr1) = config.ttbr1 {
+        backend.write(Reg::Ttbr1, ttbr1);
+    }
+
+    backend.execute(Op::InvalidateTlb);
+    backend.execute(Op::DsbIsh);
+    backend.execute(Op::Isb);
+
+    backend.write(Reg::Sctlr, sctlr | SCTLR_M | SCTLR_C | SCTLR_I);
+    backend.execute(Op::Isb);
+}
+
+/// Disable the MMU and the data cache of the translation regime of `backend`.
+///
+/// The data cache is cleaned and invalidated while it is still enabled, so that the non-cacheable
+/// accesses that follow, including those to the stack, see everything written so far. Once `C`
+/// is clear, reads no longer look up the cache, so cleaning afterwards would be too late for data
+/// the sequence itself reads back. The instruction cache is left enabled.
+///
+/// Lines that are written between the clean and the `SCTLR_ELx` write stay dirty in the cache and
+/// are hidden from later accesses. The [`El1`] and [`El2`] backends only touch registers in that
+/// window.
+///
+/// # Safety
+///
+/// The code, stack and data in use must be identity mapped. Other PEs must not rely on the data
+/// cache of the executing PE being coherent afterwards.
+pub unsafe fn disable_with<B: Backend>(backend: &mut B) {
+    let sctlr = backend.read(Reg::Sctlr);
+
+    backend.execute(Op::CleanInvalidateDCache);
+    backend.execute(Op::DsbSy);
+
+    backend.write(Reg::Sctlr, sctlr & !(SCTLR_M | SCTLR_C));
+    backend.execute(Op::Isb);
+}
+
+/// Enable the MMU at EL1. See [`enable_with`].
+///
+/// # Safety
+///
+/// See [`enable_with`].
+pub unsafe fn enable_el1(config: &Config) {
+    enable_with(&mut El1, config);
+}
+
+/// Enable the MMU at EL2. See [`enable_with`].
+///
+/// # Safety
+///
+/// See [`enable_with`].
+pub unsafe fn enable_el2(config: &Config) {
+    enable_with(&mut El2, config);
+}
+
+/// Disable the MMU at EL1. See [`disable_with`].
+///
+/// # Safety
+///
+/// See [`disable_with`].
+pub unsafe fn disable_el1() {
+    disable_with(&mut El1);
+}
+
+/// Disable the MMU at EL2. See [`disable_with`].
+///
+/// # Safety
+///
+/// See [`disable_with`].
+pub unsafe fn disable_el2() {
+    disable_with(&mut El2);
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{vec, vec::Vec};
+
+    /// 40-bit PAs, 16-bit ASIDs, 4KiB and 64KiB granules, nothing else.
+    const CORTEX_A53: Capabilities = Capabilities::from_raw(0x1122, 0, 0);
+
+    /// 48-bit PAs, 16-bit ASIDs, all granules, FEAT_HAFDBS with dirty state and FEAT_HPDS2.
+    const NEOVERSE_N1: Capabilities = Capabilities::from_raw(0x10_1125, 0x1021_2122, 0);
+
+    /// 52-bit PAs, FEAT_LPA2 for the 4KiB but not the 16KiB granule, and FEAT_LVA.
+    const LPA2: Capabilities = Capabilities::from_raw(0x1010_0026, 0, 0x1_0000);
+
+    fn tcr_el1(config: TranslationConfig, caps: &Capabilities) -> Result<u64, Error> {
+        config.tcr_el1(caps).map(|tcr| tcr.value)
+    }
+
+    #[test]
+    fn el1() {
+        assert_eq!(
+            tcr_el1(TranslationConfig::new(48, Granule::KiB4), &CORTEX_A53),
+            Ok(0x0000_0012_b510_3510)
+        );
+
+        let config = TranslationConfig::new(39, Granule::KiB16)
+            .pa_bits(44)
+            .cacheability(Cacheability::NonCacheable, Cacheability::WriteThrough)
+            .shareability(Shareability::Outer)
+            .top_byte_ignore(true)
+            .hardware_dirty_state(true)
+            .hierarchical_permissions(false);
+        assert_eq!(tcr_el1(config, &NEOVERSE_N1), Ok(0x0000_07f4_6819_a819));
+    }
+
+    #[test]
+    fn el2() {
+        let tcr = TranslationConfig::new(48, Granule::KiB64)
+            .tcr_el2(&CORTEX_A53)
+            .map(|tcr| tcr.value);
+        assert_eq!(tcr, Ok(0x8082_7510));
+    }
+
+    #[test]
+    fn large_addresses() {
+        let config = TranslationConfig::new(52, Granule::KiB4).pa_bits(52);
+        let tcr = tcr_el1(config, &LPA2).unwrap();
+        assert_ne!(tcr & (1 << 59), 0);
+        assert_eq!(tcr & 0x3f, 12);
+
+        let tcr = tcr_el1(TranslationConfig::new(52, Granule::KiB64), &LPA2).unwrap();
+        assert_eq!(tcr & (1 << 59), 0);
+
+        assert_eq!(
+            tcr_el1(
+                TranslationConfig::new(48, Granule::KiB16).pa_bits(52),
+                &LPA2
+            ),
+            Err(Error::LargePa(Granule::KiB16))
+        );
+        assert_eq!(
+            tcr_el1(TranslationConfig::new(52, Granule::KiB64), &NEOVERSE_N1),
+            Err(Error::LargeVa(Granule::KiB64))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let a53 = |config| tcr_el1(config, &CORTEX_A53).unwrap_err();
+
+        assert_eq!(
+            a53(TranslationConfig::new(48, Granule::KiB16)),
+            Error::Granule(Granule::KiB16)
+        );
+        assert_eq!(
+            a53(TranslationConfig::new(24, Granule::KiB4)),
+            Error::VaBits(24)
+        );
+        assert_eq!(
+            a53(TranslationConfig::new(48, Granule::KiB4).pa_bits(46)),
+            Error::PaBits(46)
+        );
+        assert_eq!(
+            a53(TranslationConfig::new(48, Granule::KiB4).pa_bits(48)),
+            Error::PaRange {
+                requested: 48,
+                supported: 40
+            }
+        );
+        assert_eq!(
+            a53(TranslationConfig::new(48, Granule::KiB4).hardware_access_flag(true)),
+            Error::HardwareAccessFlag
+        );
+        assert_eq!(
+            a53(TranslationConfig::new(48, Granule::KiB4).hierarchical_permissions(false)),
+            Error::HierarchicalPermissions
+        );
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Event {
+        Write(Reg, u64),
+        Execute(Op),
+    }
+
+    struct Mock {
+        sctlr: u64,
+        events: Vec<Event>,
+    }
+
+    impl Backend for Mock {
+        fn read(&self, reg: Reg) -> u64 {
+            assert_eq!(reg, Reg::Sctlr);
+            self.sctlr
+        }
+
+        fn write(&mut self, reg: Reg, value: u64) {
+            if reg == Reg::Sctlr {
+                self.sctlr = value;
+            }
+            self.events.push(Event::Write(reg, value));
+        }
+
+        fn execute(&mut self, op: Op) {
+            self.events.push(Event::Execute(op));
+        }
+    }
+
+    const CONFIG: Config = Config {
+        mair: 0xff04,
+        tcr: 0x0000_0012_b510_3510,
+        ttbr0: 0x8_0000,
+        ttbr1: Some(0x9_0000),
+    };
+
+    #[test]
+    fn enable_from_reset() {
+        let mut mock = Mock {
+            sctlr: 0x30d0_0800,
+            events: Vec::new(),
+        };
+        unsafe { enable_with(&mut mock, &CONFIG) };
+
+        assert_eq!(
+            mock.events,
+            vec![
+                Event::Execute(Op::InvalidateDCache),
+                Event::Execute(Op::InvalidateICache),
+                Event::Execute(Op::DsbSy),
+                Event::Write(Reg::Mair, 0xff04),
+                Event::Write(Reg::Tcr, 0x0000_0012_b510_3510),
+                Event::Write(Reg::Ttbr0, 0x8_0000),
+                Event::Write(Reg::Ttbr1, 0x9_0000),
+                Event::Execute(Op::InvalidateTlb),
+                Event::Execute(Op::DsbIsh),
+                Event::Execute(Op::Isb),
+                Event::Write(Reg::Sctlr, 0x30d0_1805),
+                Event::Execute(Op::Isb),
+            ]
+        );
+    }
+
+    #[test]
+    fn enable_with_caches_on() {
+        let mut mock = Mock {
+            sctlr: 0x30d0_1804,
+            events: Vec::new(),
+        };
+        let config = Config {
+            ttbr1: None,
+            ..CONFIG
+        };
+        unsafe { enable_with(&mut mock, &config) };
+
+        assert_eq!(
+            mock.events,
+            vec![
+                Event::Write(Reg::Mair, 0xff04),
+                Event::Write(Reg::Tcr, 0x0000_0012_b510_3510),
+                Event::Write(Reg::Ttbr0, 0x8_0000),
+                Event::Execute(Op::InvalidateTlb),
+                Event::Execute(Op::DsbIsh),
+                Event::Execute(Op::Isb),
+                Event::Write(Reg::Sctlr, 0x30d0_1805),
+                Event::Execute(Op::Isb),
+            ]
+        );
+    }
+
+    #[test]
+    fn disable() {
+        let mut mock = Mock {
+            sctlr: 0x30d0_1805,
+            events: Vec::new(),
+        };
+        unsafe { disable_with(&mut mock) };
+
+        assert_eq!(
+            mock.events,
+            vec![
+                Event::Execute(Op::CleanInvalidateDCache),
+                Event::Execute(Op::DsbSy),
+                Event::Write(Reg::Sctlr, 0x30d0_1800),
+                Event::Execute(Op::Isb),
+            ]
+        );
+    }
+}
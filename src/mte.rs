@@ -0,0 +1,273 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Memory Tagging Extension.
+//!
+//! Configuration of tag checking, tagging of memory ranges and tag check fault status, e.g. for
+//! building a tagged heap.
+//!
+//! The memory to be tagged must be mapped with a Tagged Normal attribute (see
+//! `MAIR_EL1::Attr<n>_Normal_Tagged`), and tag checking must not be suppressed by
+//! `TCR_EL1.TBI<n>` being clear or `TCR_EL1.TCMA<n>` being set.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::mte::{self, TagCheckMode};
+//!
+//! assert!(mte::is_implemented());
+//!
+//! unsafe { mte::set_el1_mode(TagCheckMode::Sync) };
+//!
+//! let buf = 0xffff_0000_0010_0000;
+//! let tagged = mte::random_tag(buf, 0);
+//! unsafe { mte::tag_range(tagged, 256).unwrap() };
+//!
+//! // ... hand out `tagged`, then retire the allocation.
+//! unsafe { mte::untag_range(tagged, 256).unwrap() };
+//!
+//! assert!(mte::take_faults_el1().is_empty());
+//! ```
+
+use crate::{
+    asm::{barrier, mte as insn},
+    registers::{GCR_EL1, ID_AA64PFR1_EL1, SCTLR_EL1, TFSRE0_EL1, TFSR_EL1},
+};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// Size of a tag granule in bytes.
+pub const GRANULE_SIZE: u64 = 16;
+
+/// Position of the Logical Address Tag in an address.
+pub const TAG_SHIFT: u64 = 56;
+
+const TAG_MASK: u64 = 0xf << TAG_SHIFT;
+
+/// Errors reported when tagging memory ranges.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The start address or the length is not a multiple of [`GRANULE_SIZE`].
+    Misaligned,
+}
+
+/// Effect of Tag Check Faults.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TagCheckMode {
+    /// Tag Check Faults have no effect.
+    None,
+    /// Tag Check Faults cause a synchronous exception.
+    Sync,
+    /// Tag Check Faults are accumulated in the fault status registers.
+    Async,
+    /// Synchronous on reads and asynchronous on writes. Requires FEAT_MTE3.
+    Asymmetric,
+}
+
+/// Asynchronous Tag Check Faults accumulated for one Exception level.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TagFaults {
+    /// A fault occurred on an address with bit\[55\] clear, i.e. in the TTBR0 range.
+    pub lower: bool,
+    /// A fault occurred on an address with bit\[55\] set, i.e. in the TTBR1 range.
+    pub upper: bool,
+}
+
+impl TagFaults {
+    /// Return whether no fault occurred.
+    pub fn is_empty(&self) -> bool {
+        !self.lower && !self.upper
+    }
+}
+
+/// Return whether the full Memory Tagging Extension, including tag checking, is implemented.
+pub fn is_implemented() -> bool {
+    ID_AA64PFR1_EL1.read(ID_AA64PFR1_EL1::MTE) >= 2
+}
+
+/// Return `addr` with its Logical Address Tag replaced by `tag`.
+pub fn with_tag(addr: u64, tag: u8) -> u64 {
+    (addr & !TAG_MASK) | ((tag as u64 & 0xf) << TAG_SHIFT)
+}
+
+/// Return the Logical Address Tag of `addr`.
+pub fn tag(addr: u64) -> u8 {
+    ((addr & TAG_MASK) >> TAG_SHIFT) as u8
+}
+
+/// Return `addr` with its Logical Address Tag cleared.
+pub fn untagged(addr: u64) -> u64 {
+    addr & !TAG_MASK
+}
+
+/// Return `addr` with a random Logical Address Tag that is neither set in the `exclude` mask nor
+/// in `GCR_EL1.Exclude`.
+pub fn random_tag(addr: u64, exclude: u16) -> u64 {
+    insn::irg(addr, exclude as u64)
+}
+
+/// Set the tags excluded from random tag generation and from the tag increments of `ADDG` and
+/// `SUBG`.
+pub fn set_exclude_mask(exclude: u16) {
+    GCR_EL1.modify(GCR_EL1::Exclude.val(exclude as u64));
+}
+
+fn tcf(mode: TagCheckMode) -> u64 {
+    match mode {
+        TagCheckMode::None => 0b00,
+        TagCheckMode::Sync => 0b01,
+        TagCheckMode::Async => 0b10,
+        TagCheckMode::Asymmetric => 0b11,
+    }
+}
+
+/// Allow access to Allocation Tags at EL1 and set the effect of Tag Check Faults caused by EL1.
+///
+/// # Safety
+///
+/// Once tag checking is enabled, all accesses through tagged pointers into Tagged Normal memory
+/// must use the correct tag.
+pub unsafe fn set_el1_mode(mode: TagCheckMode) {
+    SCTLR_EL1.modify(SCTLR_EL1::ATA::Allow + SCTLR_EL1::TCF.val(tcf(mode)));
+    barrier::isb(barrier::SY);
+}
+
+/// Allow access to Allocation Tags at EL0 and set the effect of Tag Check Faults caused by EL0.
+///
+/// # Safety
+///
+/// Once tag checking is enabled, all accesses by EL0 through tagged pointers into Tagged Normal
+/// memory must use the correct tag.
+pub unsafe fn set_el0_mode(mode: TagCheckMode) {
+    SCTLR_EL1.modify(SCTLR_EL1::ATA0::Allow + SCTLR_EL1::TCF0.val(tcf(mode)));
+    barrier::isb(barrier::SY);
+}
+
+fn check_range(addr: u64, len: u64) -> Result<(), Error> {
+    if (addr | len) & (GRANULE_SIZE - 1) != 0 {
+        return Err(Error::Misaligned);
+    }
+
+    Ok(())
+}
+
+/// Set the Allocation Tags of `len` bytes starting at `addr` to the Logical Address Tag of
+/// `addr`.
+///
+/// # Safety
+///
+/// The range must be mapped, Tagged Normal memory. Accesses to it through pointers carrying a
+/// different tag fault afterwards.
+pub unsafe fn tag_range(addr: u64, len: u64) -> Result<(), Error> {
+    check_range(addr, len)?;
+
+    let end = addr.wrapping_add(len);
+    let mut cur = addr;
+    while end.wrapping_sub(cur) >= 2 * GRANULE_SIZE {
+        insn::st2g(cur);
+        cur = cur.wrapping_add(2 * GRANULE_SIZE);
+    }
+    if cur != end {
+        insn::stg(cur);
+    }
+
+    Ok(())
+}
+
+/// Like [`tag_range`], but additionally zeroes the range.
+///
+/// # Safety
+///
+/// See [`tag_range`].
+pub unsafe fn tag_and_zero_range(addr: u64, len: u64) -> Result<(), Error> {
+    check_range(addr, len)?;
+
+    let end = addr.wrapping_add(len);
+    let mut cur = addr;
+    while end.wrapping_sub(cur) >= 2 * GRANULE_SIZE {
+        insn::stz2g(cur);
+        cur = cur.wrapping_add(2 * GRANULE_SIZE);
+    }
+    if cur != end {
+        insn::stzg(cur);
+    }
+
+    Ok(())
+}
+
+/// Reset the Allocation Tags of `len` bytes starting at `addr` to zero.
+///
+/// # Safety
+///
+/// See [`tag_range`].
+pub unsafe fn untag_range(addr: u64, len: u64) -> Result<(), Error> {
+    tag_range(with_tag(addr, 0), len)
+}
+
+/// Return the Allocation Tag of the granule containing `addr`.
+///
+/// # Safety
+///
+/// `addr` must be mapped.
+pub unsafe fn read_tag(addr: u64) -> u8 {
+    tag(insn::ldg(addr))
+}
+
+fn sync_faults() {
+    barrier::dsb(barrier::NSH);
+    barrier::isb(barrier::SY);
+}
+
+/// Return and clear the asynchronous Tag Check Faults accumulated at EL1.
+pub fn take_faults_el1() -> TagFaults {
+    sync_faults();
+
+    let tfsr = TFSR_EL1.extract();
+    TFSR_EL1.set(0);
+
+    TagFaults {
+        lower: tfsr.is_set(TFSR_EL1::TF0),
+        upper: tfsr.is_set(TFSR_EL1::TF1),
+    }
+}
+
+/// Return and clear the asynchronous Tag Check Faults accumulated at EL0.
+pub fn take_faults_el0() -> TagFaults {
+    sync_faults();
+
+    let tfsr = TFSRE0_EL1.extract();
+    TFSRE0_EL1.set(0);
+
+    TagFaults {
+        lower: tfsr.is_set(TFSRE0_EL1::TF0),
+        upper: tfsr.is_set(TFSRE0_EL1::TF1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logical_tags() {
+        let addr = 0xffff_0000_0010_0040;
+
+        let tagged = with_tag(addr, 0xa);
+        assert_eq!(tagged, 0xfaff_0000_0010_0040);
+        assert_eq!(tag(tagged), 0xa);
+        assert_eq!(untagged(tagged), 0xf0ff_0000_0010_0040);
+        assert_eq!(with_tag(tagged, 0x1f), 0xffff_0000_0010_0040);
+    }
+
+    #[test]
+    fn misaligned_ranges() {
+        unsafe {
+            assert_eq!(tag_range(0x1008, 32), Err(Error::Misaligned));
+            assert_eq!(tag_and_zero_range(0x1000, 24), Err(Error::Misaligned));
+            assert_eq!(untag_range(0x1000, 0), Ok(()));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Per-CPU and thread-local data.
+//!
+//! Each CPU gets an [`Area`] that holds its index and the kernel's per-CPU data. The address of
+//! the area of the executing CPU is kept in a software thread ID register, the [`Anchor`], so
+//! [`PerCpu::get`] and [`PerCpu::index`] compile to a single `MRS` and a load at a fixed offset.
+//!
+//! The anchors are:
+//!
+//! - [`El1`]: `TPIDR_EL1`, for a kernel at EL1.
+//! - [`El2`]: `TPIDR_EL2`, for a kernel at EL2, with or without VHE.
+//! - [`Kernel`]: either of the above as selected by [`vhe::mode`], for a kernel that can run at EL1
+//!   and EL2. Costs an additional load and branch per access.
+//! - [`El0`] and [`El0ReadOnly`]: `TPIDR_EL0` and `TPIDRRO_EL0`, for thread-local data at EL0. The
+//!   kernel installs the area of the next thread in `TPIDRRO_EL0` on context switches, while
+//!   `TPIDR_EL0` can be written by the thread itself.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::sync::atomic::{AtomicU64, Ordering};
+//! use cortex_a::percpu::{Area, El1, PerCpu};
+//!
+//! struct Data {
+//!     ticks: AtomicU64,
+//! }
+//!
+//! const fn area(index: usize) -> Area<Data> {
+//!     Area::new(
+//!         index,
+//!         Data {
+//!             ticks: AtomicU64::new(0),
+//!         },
+//!     )
+//! }
+//!
+//! static AREAS: [Area<Data>; 2] = [area(0), area(1)];
+//! static PERCPU: PerCpu<Data, El1> = unsafe { PerCpu::new() };
+//!
+//! // On CPU `i`, early during boot.
+//! # let i = 0;
+//! unsafe { PERCPU.install(&AREAS[i]) };
+//!
+//! // Anywhere afterwards.
+//! PERCPU.get().ticks.fetch_add(1, Ordering::Relaxed);
+//! assert_eq!(PERCPU.index(), i);
+//! ```
+//!
+//! [`vhe::mode`]: crate::vhe::mode
+
+use crate::{registers::*, vhe};
+use core::marker::PhantomData;
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// A register holding the address of the current per-CPU or thread-local [`Area`].
+pub trait Anchor {
+    /// Return the address of the current area.
+    fn base() -> u64;
+
+    /// Set the address of the current area.
+    ///
+    /// # Safety
+    ///
+    /// Replaces the area that is accessed by all [`PerCpu`] using this anchor.
+    unsafe fn set_base(base: u64);
+}
+
+macro_rules! anchor {
+    ($(#[$attr:meta])* $name:ident, $reg:path) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl Anchor for $name {
+            #[inline(always)]
+            fn base() -> u64 {
+                $reg.get()
+            }
+
+            #[inline(always)]
+            unsafe fn set_base(base: u64) {
+                $reg.set(base)
+            }
+        }
+    };
+}
+
+anchor!(
+    /// `TPIDR_EL1`.
+    El1,
+    TPIDR_EL1
+);
+
+anchor!(
+    /// `TPIDR_EL2`.
+    El2,
+    TPIDR_EL2
+);
+
+anchor!(
+    /// `TPIDR_EL1` or `TPIDR_EL2`, depending on the mode recorded by [`vhe::init`].
+    Kernel,
+    vhe::TPIDR
+);
+
+anchor!(
+    /// `TPIDR_EL0`.
+    El0,
+    TPIDR_EL0
+);
+
+anchor!(
+    /// `TPIDRRO_EL0`, which is read-only at EL0.
+    El0ReadOnly,
+    TPIDRRO_EL0
+);
+
+/// The per-CPU or thread-local data of one CPU or thread.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Area<D> {
+    index: usize,
+    data: D,
+}
+
+impl<D> Area<D> {
+    /// Create the area of the CPU or thread with the given index.
+    pub const fn new(index: usize, data: D) -> Area<D> {
+        Area { index, data }
+    }
+
+    /// Return the index of the CPU or thread.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return the data of the CPU or thread.
+    pub fn data(&self) -> &D {
+        &self.data
+    }
+
+    /// Return the data of the CPU or thread mutably.
+    pub fn data_mut(&mut self) -> &mut D {
+        &mut self.data
+    }
+}
+
+/// Access to the [`Area`] of the executing CPU or thread, whose address is held in anchor `A`.
+pub struct PerCpu<D, A: Anchor> {
+    _marker: PhantomData<(fn() -> D, A)>,
+}
+
+impl<D: 'static, A: Anchor> PerCpu<D, A> {
+    /// Create the accessor.
+    ///
+    /// # Safety
+    ///
+    /// An `Area<D>` must be installed with [`PerCpu::install`] on every CPU or thread before any
+    /// other method is called, and the anchor must not be written otherwise.
+    pub const unsafe fn new() -> PerCpu<D, A> {
+        PerCpu {
+            _marker: PhantomData,
+        }
+    }
+
+    /// Make `area` the area of the executing CPU or thread.
+    ///
+    /// # Safety
+    ///
+    /// Each area must only be installed on one CPU or thread at a time.
+    #[inline(always)]
+    pub unsafe fn install(&self, area: &'static Area<D>) {
+        A::set_base(area as *const Area<D> as u64);
+    }
+
+    #[inline(always)]
+    fn area(&self) -> &'static Area<D> {
+        unsafe { &*(A::base() as *const Area<D>) }
+    }
+
+    /// Return the index of the executing CPU or thread.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.area().index
+    }
+
+    /// Return the data of the executing CPU or thread.
+    ///
+    /// The reference stays valid when the caller migrates to a different CPU, but then refers to
+    /// the data of the CPU it was obtained on.
+    #[inline(always)]
+    pub fn get(&self) -> &'static D
+    where
+        D: Sync,
+    {
+        &self.area().data
+    }
+
+    /// Return the data of the executing CPU or thread, which need not be `Sync`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must not migrate to a different CPU while using the reference, and the data
+    /// must not be accessed concurrently, e.g. from an exception handler.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self) -> &'static D {
+        &self.area().data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::{
+        cell::Cell,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+    use std::boxed::Box;
+
+    static BASE: AtomicU64 = AtomicU64::new(0);
+
+    struct Mock;
+
+    impl Anchor for Mock {
+        fn base() -> u64 {
+            BASE.load(Ordering::Relaxed)
+        }
+
+        unsafe fn set_base(base: u64) {
+            BASE.store(base, Ordering::Relaxed)
+        }
+    }
+
+    struct Data {
+        ticks: AtomicU64,
+        scratch: u8,
+    }
+
+    static AREAS: [Area<Data>; 2] = [
+        Area::new(
+            0,
+            Data {
+                ticks: AtomicU64::new(0),
+                scratch: 0xa,
+            },
+        ),
+        Area::new(
+            1,
+            Data {
+                ticks: AtomicU64::new(100),
+                scratch: 0xb,
+            },
+        ),
+    ];
+
+    static PERCPU: PerCpu<Data, Mock> = unsafe { PerCpu::new() };
+
+    #[test]
+    fn switch_areas() {
+        for area in AREAS.iter() {
+            unsafe { PERCPU.install(area) };
+            assert_eq!(PERCPU.index(), area.index());
+            assert!(core::ptr::eq(PERCPU.get(), area.data()));
+
+            PERCPU.get().ticks.fetch_add(1, Ordering::Relaxed);
+        }
+
+        assert_eq!(AREAS[0].data().ticks.load(Ordering::Relaxed), 1);
+        assert_eq!(AREAS[1].data().ticks.load(Ordering::Relaxed), 101);
+        assert_eq!(PERCPU.get().scratch, 0xb);
+
+        let local: &'static mut Area<Cell<u32>> = Box::leak(Box::new(Area::new(7, Cell::new(3))));
+        local.data_mut().set(4);
+        let percpu: PerCpu<Cell<u32>, Mock> = unsafe { PerCpu::new() };
+        unsafe {
+            percpu.install(local);
+            assert_eq!(percpu.index(), 7);
+            assert_eq!(percpu.get_unchecked().get(), 4);
+        }
+    }
+}
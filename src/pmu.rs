@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Unit.
+//!
+//! Convenience functions for the cycle counter and the event counters of the PMUv3 Performance
+//! Monitors Extension.
+//!
+//! Event counters are accessed indirectly through `PMSELR_EL0`. If counters are also used from
+//! exception handlers, the caller must ensure that no handler changes `PMSELR_EL0` concurrently,
+//! e.g. by masking interrupts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::pmu::{self, Event};
+//!
+//! let (_, sample) = pmu::measure([Event::INST_RETIRED, Event::L1D_CACHE_REFILL], || {
+//!     // Code under measurement.
+//! });
+//!
+//! let _cycles = sample.cycles;
+//! let _instructions = sample.events[0];
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{
+        PMCCFILTR_EL0, PMCCNTR_EL0, PMCEID0_EL0, PMCEID1_EL0, PMCNTENCLR_EL0, PMCNTENSET_EL0,
+        PMCR_EL0, PMOVSCLR_EL0, PMSELR_EL0, PMXEVCNTR_EL0, PMXEVTYPER_EL0,
+    },
+};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// A PMU event number, as programmed into `PMEVTYPER<n>_EL0.EVTCOUNT`.
+///
+/// The associated constants are the common architectural and microarchitectural events. Events
+/// in the IMPLEMENTATION DEFINED range can be constructed directly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Event(pub u16);
+
+impl Event {
+    pub const SW_INCR: Event = Event(0x00);
+    pub const L1I_CACHE_REFILL: Event = Event(0x01);
+    pub const L1I_TLB_REFILL: Event = Event(0x02);
+    pub const L1D_CACHE_REFILL: Event = Event(0x03);
+    pub const L1D_CACHE: Event = Event(0x04);
+    pub const L1D_TLB_REFILL: Event = Event(0x05);
+    pub const LD_RETIRED: Event = Event(0x06);
+    pub const ST_RETIRED: Event = Event(0x07);
+    pub const INST_RETIRED: Event = Event(0x08);
+    pub const EXC_TAKEN: Event = Event(0x09);
+    pub const EXC_RETURN: Event = Event(0x0A);
+    pub const CID_WRITE_RETIRED: Event = Event(0x0B);
+    pub const PC_WRITE_RETIRED: Event = Event(0x0C);
+    pub const BR_IMMED_RETIRED: Event = Event(0x0D);
+    pub const BR_RETURN_RETIRED: Event = Event(0x0E);
+    pub const UNALIGNED_LDST_RETIRED: Event = Event(0x0F);
+    pub const BR_MIS_PRED: Event = Event(0x10);
+    pub const CPU_CYCLES: Event = Event(0x11);
+    pub const BR_PRED: Event = Event(0x12);
+    pub const MEM_ACCESS: Event = Event(0x13);
+    pub const L1I_CACHE: Event = Event(0x14);
+    pub const L1D_CACHE_WB: Event = Event(0x15);
+    pub const L2D_CACHE: Event = Event(0x16);
+    pub const L2D_CACHE_REFILL: Event = Event(0x17);
+    pub const L2D_CACHE_WB: Event = Event(0x18);
+    pub const BUS_ACCESS: Event = Event(0x19);
+    pub const MEMORY_ERROR: Event = Event(0x1A);
+    pub const INST_SPEC: Event = Event(0x1B);
+    pub const TTBR_WRITE_RETIRED: Event = Event(0x1C);
+    pub const BUS_CYCLES: Event = Event(0x1D);
+    pub const CHAIN: Event = Event(0x1E);
+    pub const L1D_CACHE_ALLOCATE: Event = Event(0x1F);
+    pub const L2D_CACHE_ALLOCATE: Event = Event(0x20);
+    pub const BR_RETIRED: Event = Event(0x21);
+    pub const BR_MIS_PRED_RETIRED: Event = Event(0x22);
+    pub const STALL_FRONTEND: Event = Event(0x23);
+    pub const STALL_BACKEND: Event = Event(0x24);
+    pub const L1D_TLB: Event = Event(0x25);
+    pub const L1I_TLB: Event = Event(0x26);
+    pub const L2I_CACHE: Event = Event(0x27);
+    pub const L2I_CACHE_REFILL: Event = Event(0x28);
+    pub const L3D_CACHE_ALLOCATE: Event = Event(0x29);
+    pub const L3D_CACHE_REFILL: Event = Event(0x2A);
+    pub const L3D_CACHE: Event = Event(0x2B);
+    pub const L3D_CACHE_WB: Event = Event(0x2C);
+    pub const L2D_TLB_REFILL: Event = Event(0x2D);
+    pub const L2I_TLB_REFILL: Event = Event(0x2E);
+    pub const L2D_TLB: Event = Event(0x2F);
+    pub const L2I_TLB: Event = Event(0x30);
+}
+
+/// Exception levels in which a counter counts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    pub el0: bool,
+    pub el1: bool,
+    pub el2: bool,
+}
+
+impl Filter {
+    /// Count in EL0 and EL1, which is the reset behavior of the counters.
+    pub const EL0_EL1: Filter = Filter {
+        el0: true,
+        el1: true,
+        el2: false,
+    };
+
+    /// Count in all Non-secure Exception levels.
+    pub const ALL: Filter = Filter {
+        el0: true,
+        el1: true,
+        el2: true,
+    };
+
+    /// Returns the `P`, `U` and `NSH` filter bits in the layout shared by `PMCCFILTR_EL0` and
+    /// `PMEVTYPER<n>_EL0`.
+    fn bits(self) -> u64 {
+        let p = !self.el1 as u64;
+        let u = !self.el0 as u64;
+        let nsh = self.el2 as u64;
+
+        (p << 31) | (u << 30) | (nsh << 27)
+    }
+}
+
+/// Returns the number of implemented event counters, not including the cycle counter.
+#[inline]
+pub fn num_event_counters() -> u8 {
+    PMCR_EL0.read(PMCR_EL0::N) as u8
+}
+
+/// Returns whether `event` is a common event that is implemented, as reported by
+/// `PMCEID0_EL0`/`PMCEID1_EL0`.
+///
+/// Always returns `false` for events outside the common event ranges.
+#[inline]
+pub fn is_common_event_implemented(event: Event) -> bool {
+    let (reg, bit) = match event.0 {
+        n @ 0x0000..=0x001F => (PMCEID0_EL0.get(), n),
+        n @ 0x0020..=0x003F => (PMCEID1_EL0.get(), n - 0x0020),
+        n @ 0x4000..=0x401F => (PMCEID0_EL0.get(), n - 0x4000 + 32),
+        n @ 0x4020..=0x403F => (PMCEID1_EL0.get(), n - 0x4020 + 32),
+        _ => return false,
+    };
+
+    reg & (1 << bit) != 0
+}
+
+/// Globally enables the PMU, with the cycle counter set to overflow at 64 bits.
+///
+/// Individual counters additionally need to be enabled.
+#[inline]
+pub fn enable() {
+    PMCR_EL0.modify(PMCR_EL0::E::Enable + PMCR_EL0::LC::Overflow64);
+    barrier::isb(barrier::SY);
+}
+
+/// Globally disables the PMU.
+#[inline]
+pub fn disable() {
+    PMCR_EL0.modify(PMCR_EL0::E::Disable);
+    barrier::isb(barrier::SY);
+}
+
+/// Resets the cycle counter and all event counters to zero.
+#[inline]
+pub fn reset_counters() {
+    PMCR_EL0.modify(PMCR_EL0::C::SET + PMCR_EL0::P::SET);
+    barrier::isb(barrier::SY);
+}
+
+/// The cycle counter, `PMCCNTR_EL0`.
+#[derive(Copy, Clone, Debug)]
+pub struct CycleCounter;
+
+impl CycleCounter {
+    /// Sets the Exception levels in which the cycle counter counts.
+    #[inline]
+    pub fn set_filter(&self, filter: Filter) {
+        PMCCFILTR_EL0.set(filter.bits());
+    }
+
+    /// Enables the cycle counter.
+    #[inline]
+    pub fn enable(&self) {
+        PMCNTENSET_EL0.write(PMCNTENSET_EL0::C::SET);
+    }
+
+    /// Disables the cycle counter.
+    #[inline]
+    pub fn disable(&self) {
+        PMCNTENCLR_EL0.write(PMCNTENCLR_EL0::C::SET);
+    }
+
+    /// Returns the current cycle count.
+    #[inline]
+    pub fn read(&self) -> u64 {
+        PMCCNTR_EL0.get()
+    }
+
+    /// Sets the cycle count to `value`.
+    #[inline]
+    pub fn write(&self, value: u64) {
+        PMCCNTR_EL0.set(value);
+    }
+
+    /// Returns whether the cycle counter overflowed, and clears the overflow flag.
+    #[inline]
+    pub fn take_overflow(&self) -> bool {
+        let overflowed = PMOVSCLR_EL0.is_set(PMOVSCLR_EL0::C);
+        PMOVSCLR_EL0.write(PMOVSCLR_EL0::C::SET);
+
+        overflowed
+    }
+}
+
+/// An event counter, `PMEVCNTR<n>_EL0`.
+#[derive(Copy, Clone, Debug)]
+pub struct EventCounter {
+    index: u8,
+}
+
+impl EventCounter {
+    /// Returns the event counter with number `index`, or `None` if it is not implemented.
+    #[inline]
+    pub fn new(index: u8) -> Option<Self> {
+        if index < num_event_counters() {
+            Some(EventCounter { index })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of this event counter.
+    #[inline]
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    #[inline(always)]
+    fn select(&self) {
+        PMSELR_EL0.write(PMSELR_EL0::SEL.val(self.index as u64));
+        barrier::isb(barrier::SY);
+    }
+
+    /// Programs the counter to count `event` in the Exception levels selected by `filter`.
+    #[inline]
+    pub fn configure(&self, event: Event, filter: Filter) {
+        self.select();
+        PMXEVTYPER_EL0.set(filter.bits() | event.0 as u64);
+    }
+
+    /// Enables the counter.
+    #[inline]
+    pub fn enable(&self) {
+        PMCNTENSET_EL0.set(1 << self.index);
+    }
+
+    /// Disables the counter.
+    #[inline]
+    pub fn disable(&self) {
+        PMCNTENCLR_EL0.set(1 << self.index);
+    }
+
+    /// Returns the current count.
+    #[inline]
+    pub fn read(&self) -> u64 {
+        self.select();
+        PMXEVCNTR_EL0.get()
+    }
+
+    /// Sets the count to `value`.
+    #[inline]
+    pub fn write(&self, value: u64) {
+        self.select();
+        PMXEVCNTR_EL0.set(value);
+    }
+
+    /// Returns whether the counter overflowed, and clears the overflow flag.
+    #[inline]
+    pub fn take_overflow(&self) -> bool {
+        let mask = 1 << self.index;
+        let overflowed = PMOVSCLR_EL0.get() & mask != 0;
+        PMOVSCLR_EL0.set(mask);
+
+        overflowed
+    }
+}
+
+/// The counter values collected by [`measure`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Sample<const N: usize> {
+    /// Number of cycles spent in the measured closure.
+    pub cycles: u64,
+
+    /// Number of occurrences of each requested event, in the order the events were requested.
+    pub events: [u64; N],
+}
+
+/// Runs `f` and counts cycles and the given `events` while it executes.
+///
+/// Event counters `0..N` and the cycle counter are reprogrammed and reset, and the PMU is enabled
+/// for EL0 and EL1. The counters are stopped when `f` returns.
+///
+/// # Panics
+///
+/// Panics if fewer than `N` event counters are implemented.
+#[inline]
+pub fn measure<R, F, const N: usize>(events: [Event; N], f: F) -> (R, Sample<N>)
+where
+    F: FnOnce() -> R,
+{
+    let mut counters = [EventCounter { index: 0 }; N];
+    for (i, (counter, event)) in counters.iter_mut().zip(events.iter()).enumerate() {
+        *counter = EventCounter::new(i as u8).expect("not enough PMU event counters implemented");
+        counter.configure(*event, Filter::EL0_EL1);
+    }
+
+    let cycle_counter = CycleCounter;
+    cycle_counter.set_filter(Filter::EL0_EL1);
+
+    enable();
+    reset_counters();
+
+    let mask = counters
+        .iter()
+        .fold(0, |mask, counter| mask | (1 << counter.index));
+    PMCNTENSET_EL0.write(PMCNTENSET_EL0::C::SET + PMCNTENSET_EL0::P.val(mask));
+    barrier::isb(barrier::SY);
+
+    let ret = f();
+
+    barrier::isb(barrier::SY);
+    PMCNTENCLR_EL0.write(PMCNTENCLR_EL0::C::SET + PMCNTENCLR_EL0::P.val(mask));
+    barrier::isb(barrier::SY);
+
+    let mut sample = Sample {
+        cycles: cycle_counter.read(),
+        events: [0; N],
+    };
+    for (value, counter) in sample.events.iter_mut().zip(counters.iter()) {
+        *value = counter.read();
+    }
+
+    (ret, sample)
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Scoped PSTATE controls.
+//!
+//! Each function sets a PSTATE bit for the duration of a closure and afterwards restores the bit's
+//! previous value, so calls can be nested.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::pstate;
+//!
+//! let user_ptr = 0x4_1000 as *const u64;
+//! let value = pstate::with_pan_disabled(|| unsafe { core::ptr::read_volatile(user_ptr) });
+//!
+//! pstate::with_dit(|| {
+//!     // Constant-time comparison of secrets.
+//! });
+//! ```
+
+use crate::registers::{DIT, PAN, TCO};
+use tock_registers::interfaces::Readable;
+
+/// Run `f` with Privileged Access Never disabled, i.e. with access to EL0 memory permitted.
+#[inline]
+pub fn with_pan_disabled<R>(f: impl FnOnce() -> R) -> R {
+    let was_set = PAN.is_set(PAN::PAN);
+
+    PAN.write_imm(false);
+    let ret = f();
+    if was_set {
+        PAN.write_imm(true);
+    }
+
+    ret
+}
+
+/// Run `f` with Data Independent Timing enabled.
+#[inline]
+pub fn with_dit<R>(f: impl FnOnce() -> R) -> R {
+    let was_set = DIT.is_set(DIT::DIT);
+
+    DIT.write_imm(true);
+    let ret = f();
+    if !was_set {
+        DIT.write_imm(false);
+    }
+
+    ret
+}
+
+/// Run `f` with Tag Check Override set, i.e. with its loads and stores being Unchecked.
+#[inline]
+pub fn with_tag_checks_disabled<R>(f: impl FnOnce() -> R) -> R {
+    let was_set = TCO.is_set(TCO::TCO);
+
+    TCO.write_imm(true);
+    let ret = f();
+    if !was_set {
+        TCO.write_imm(false);
+    }
+
+    ret
+}
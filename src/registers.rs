@@ -1,135 +1,634 @@
 //! Processor core registers
 
 #![allow(unused_attributes)]
-#![rustfmt::skip]
 
 #[macro_use]
 mod macros;
 
+pub mod aarch32;
+
 mod actlr_el1;
 mod actlr_el2;
 mod actlr_el3;
+mod afsr0_el1;
+mod afsr0_el2;
+mod afsr0_el3;
+mod afsr0_el12;
+mod afsr1_el1;
+mod afsr1_el2;
+mod afsr1_el3;
+mod afsr1_el12;
+mod allint;
+mod amair_el1;
+mod amair_el2;
+mod amair_el12;
+mod apdakeyhi_el1;
+mod apdakeylo_el1;
+mod apdbkeyhi_el1;
+mod apdbkeylo_el1;
+mod apgakeyhi_el1;
+mod apgakeylo_el1;
+mod apiakeyhi_el1;
+mod apiakeylo_el1;
+mod apibkeyhi_el1;
+mod apibkeylo_el1;
 mod ccsidr_el1;
 mod clidr_el1;
 mod cntfrq_el0;
 mod cnthctl_el2;
+mod cntkctl_el1;
+mod cntkctl_el12;
 mod cntp_ctl_el0;
+mod cntp_ctl_el02;
 mod cntp_cval_el0;
+mod cntp_cval_el02;
 mod cntp_tval_el0;
+mod cntp_tval_el02;
 mod cntpct_el0;
 mod cntv_ctl_el0;
+mod cntv_ctl_el02;
 mod cntv_cval_el0;
+mod cntv_cval_el02;
 mod cntv_tval_el0;
+mod cntv_tval_el02;
 mod cntvct_el0;
 mod cntvoff_el2;
+mod contextidr_el1;
+mod contextidr_el2;
+mod contextidr_el12;
+mod cpacr_el12;
+mod cptr_el2;
+mod cptr_el3;
 mod csselr_el1;
 mod cpacr_el1;
 mod dacr32_el2;
 mod currentel;
 mod daif;
+mod dbgbcr0_el1;
+mod dbgbcr1_el1;
+mod dbgbcr2_el1;
+mod dbgbcr3_el1;
+mod dbgbcr4_el1;
+mod dbgbcr5_el1;
+mod dbgbcr6_el1;
+mod dbgbcr7_el1;
+mod dbgbcr8_el1;
+mod dbgbcr9_el1;
+mod dbgbcr10_el1;
+mod dbgbcr11_el1;
+mod dbgbcr12_el1;
+mod dbgbcr13_el1;
+mod dbgbcr14_el1;
+mod dbgbcr15_el1;
+mod dbgbvr0_el1;
+mod dbgbvr1_el1;
+mod dbgbvr2_el1;
+mod dbgbvr3_el1;
+mod dbgbvr4_el1;
+mod dbgbvr5_el1;
+mod dbgbvr6_el1;
+mod dbgbvr7_el1;
+mod dbgbvr8_el1;
+mod dbgbvr9_el1;
+mod dbgbvr10_el1;
+mod dbgbvr11_el1;
+mod dbgbvr12_el1;
+mod dbgbvr13_el1;
+mod dbgbvr14_el1;
+mod dbgbvr15_el1;
+mod dbgwcr0_el1;
+mod dbgwcr1_el1;
+mod dbgwcr2_el1;
+mod dbgwcr3_el1;
+mod dbgwcr4_el1;
+mod dbgwcr5_el1;
+mod dbgwcr6_el1;
+mod dbgwcr7_el1;
+mod dbgwcr8_el1;
+mod dbgwcr9_el1;
+mod dbgwcr10_el1;
+mod dbgwcr11_el1;
+mod dbgwcr12_el1;
+mod dbgwcr13_el1;
+mod dbgwcr14_el1;
+mod dbgwcr15_el1;
+mod dbgwvr0_el1;
+mod dbgwvr1_el1;
+mod dbgwvr2_el1;
+mod dbgwvr3_el1;
+mod dbgwvr4_el1;
+mod dbgwvr5_el1;
+mod dbgwvr6_el1;
+mod dbgwvr7_el1;
+mod dbgwvr8_el1;
+mod dbgwvr9_el1;
+mod dbgwvr10_el1;
+mod dbgwvr11_el1;
+mod dbgwvr12_el1;
+mod dbgwvr13_el1;
+mod dbgwvr14_el1;
+mod dbgwvr15_el1;
+mod dit;
 mod elr_el1;
 mod elr_el2;
 mod elr_el3;
+mod elr_el12;
 mod esr_el1;
 mod esr_el2;
+mod esr_el3;
+mod esr_el12;
 mod far_el1;
 mod far_el2;
+mod far_el3;
+mod far_el12;
 mod fp;
+mod fpcr;
+mod fpexc32_el2;
+mod fpsr;
+mod gcr_el1;
+mod gmid_el1;
+mod hacr_el2;
 mod hcr_el2;
+mod hfgitr_el2;
+mod hfgrtr_el2;
+mod hfgwtr_el2;
+mod hpfar_el2;
+mod hstr_el2;
+mod id_aa64dfr0_el1;
 mod id_aa64mmfr0_el1;
 mod id_aa64isar0_el1;
+mod id_aa64isar1_el1;
+mod id_aa64isar2_el1;
 mod id_aa64mmfr1_el1;
 mod id_aa64mmfr2_el1;
+mod id_aa64pfr0_el1;
+mod id_aa64pfr1_el1;
+mod ifsr32_el2;
 mod lr;
 mod mair_el1;
 mod mair_el2;
+mod mair_el3;
+mod mair_el12;
+mod mdccsr_el0;
+mod mdcr_el2;
+mod mdcr_el3;
+mod mdscr_el1;
 mod midr_el1;
 mod mpidr_el1;
+mod nzcv;
+mod osdlr_el1;
 mod oslar_el1;
+mod oslsr_el1;
+mod pan;
 mod par_el1;
+mod pmccfiltr_el0;
+mod pmccntr_el0;
+mod pmceid0_el0;
+mod pmceid1_el0;
+mod pmcntenclr_el0;
+mod pmcntenset_el0;
+mod pmcr_el0;
+mod pmevcntr0_el0;
+mod pmevcntr1_el0;
+mod pmevcntr2_el0;
+mod pmevcntr3_el0;
+mod pmevcntr4_el0;
+mod pmevcntr5_el0;
+mod pmevcntr6_el0;
+mod pmevcntr7_el0;
+mod pmevcntr8_el0;
+mod pmevcntr9_el0;
+mod pmevcntr10_el0;
+mod pmevcntr11_el0;
+mod pmevcntr12_el0;
+mod pmevcntr13_el0;
+mod pmevcntr14_el0;
+mod pmevcntr15_el0;
+mod pmevcntr16_el0;
+mod pmevcntr17_el0;
+mod pmevcntr18_el0;
+mod pmevcntr19_el0;
+mod pmevcntr20_el0;
+mod pmevcntr21_el0;
+mod pmevcntr22_el0;
+mod pmevcntr23_el0;
+mod pmevcntr24_el0;
+mod pmevcntr25_el0;
+mod pmevcntr26_el0;
+mod pmevcntr27_el0;
+mod pmevcntr28_el0;
+mod pmevcntr29_el0;
+mod pmevcntr30_el0;
+mod pmevtyper0_el0;
+mod pmevtyper1_el0;
+mod pmevtyper2_el0;
+mod pmevtyper3_el0;
+mod pmevtyper4_el0;
+mod pmevtyper5_el0;
+mod pmevtyper6_el0;
+mod pmevtyper7_el0;
+mod pmevtyper8_el0;
+mod pmevtyper9_el0;
+mod pmevtyper10_el0;
+mod pmevtyper11_el0;
+mod pmevtyper12_el0;
+mod pmevtyper13_el0;
+mod pmevtyper14_el0;
+mod pmevtyper15_el0;
+mod pmevtyper16_el0;
+mod pmevtyper17_el0;
+mod pmevtyper18_el0;
+mod pmevtyper19_el0;
+mod pmevtyper20_el0;
+mod pmevtyper21_el0;
+mod pmevtyper22_el0;
+mod pmevtyper23_el0;
+mod pmevtyper24_el0;
+mod pmevtyper25_el0;
+mod pmevtyper26_el0;
+mod pmevtyper27_el0;
+mod pmevtyper28_el0;
+mod pmevtyper29_el0;
+mod pmevtyper30_el0;
+mod pmintenclr_el1;
+mod pmintenset_el1;
+mod pmovsclr_el0;
+mod pmovsset_el0;
+mod pmselr_el0;
+mod pmuserenr_el0;
+mod pmxevcntr_el0;
+mod pmxevtyper_el0;
+mod rgsr_el1;
+mod rmr_el3;
+mod rvbar_el3;
 mod scr_el3;
 mod sctlr_el1;
 mod sctlr_el2;
+mod sctlr_el3;
+mod sctlr_el12;
+mod smcr_el1;
+mod smcr_el2;
+mod smcr_el3;
+mod smcr_el12;
+mod smidr_el1;
+mod smpri_el1;
 mod sp;
 mod sp_el0;
 mod sp_el1;
 mod spsel;
+mod spsr_abt;
 mod spsr_el1;
 mod spsr_el2;
 mod spsr_el3;
+mod spsr_el12;
+mod spsr_fiq;
+mod spsr_irq;
+mod spsr_und;
+mod ssbs;
+mod svcr;
+mod tco;
 mod tcr_el1;
 mod tcr_el2;
+mod tcr_el3;
+mod tcr_el12;
+mod tfsr_el1;
+mod tfsr_el12;
+mod tfsre0_el1;
 mod tpidr_el0;
 mod tpidr_el1;
+mod tpidr_el2;
+mod tpidr_el3;
 mod tpidrro_el0;
 mod ttbr0_el1;
 mod ttbr0_el2;
+mod ttbr0_el3;
+mod ttbr0_el12;
 mod ttbr1_el1;
+mod ttbr1_el2;
+mod ttbr1_el12;
+mod uao;
 mod vbar_el1;
 mod vbar_el2;
+mod vbar_el3;
+mod vbar_el12;
+mod vmpidr_el2;
+mod vpidr_el2;
 mod vtcr_el2;
 mod vttbr_el2;
+mod zcr_el1;
+mod zcr_el2;
+mod zcr_el3;
+mod zcr_el12;
 
 pub use actlr_el1::ACTLR_EL1;
 pub use actlr_el2::ACTLR_EL2;
 pub use actlr_el3::ACTLR_EL3;
+pub use afsr0_el1::AFSR0_EL1;
+pub use afsr0_el2::AFSR0_EL2;
+pub use afsr0_el3::AFSR0_EL3;
+pub use afsr0_el12::AFSR0_EL12;
+pub use afsr1_el1::AFSR1_EL1;
+pub use afsr1_el2::AFSR1_EL2;
+pub use afsr1_el3::AFSR1_EL3;
+pub use afsr1_el12::AFSR1_EL12;
+pub use allint::ALLINT;
+pub use amair_el1::AMAIR_EL1;
+pub use amair_el2::AMAIR_EL2;
+pub use amair_el12::AMAIR_EL12;
+pub use apdakeyhi_el1::APDAKEYHI_EL1;
+pub use apdakeylo_el1::APDAKEYLO_EL1;
+pub use apdbkeyhi_el1::APDBKEYHI_EL1;
+pub use apdbkeylo_el1::APDBKEYLO_EL1;
+pub use apgakeyhi_el1::APGAKEYHI_EL1;
+pub use apgakeylo_el1::APGAKEYLO_EL1;
+pub use apiakeyhi_el1::APIAKEYHI_EL1;
+pub use apiakeylo_el1::APIAKEYLO_EL1;
+pub use apibkeyhi_el1::APIBKEYHI_EL1;
+pub use apibkeylo_el1::APIBKEYLO_EL1;
 pub use ccsidr_el1::CCSIDR_EL1;
 pub use clidr_el1::CLIDR_EL1;
 pub use cntfrq_el0::CNTFRQ_EL0;
 pub use cnthctl_el2::CNTHCTL_EL2;
+pub use cntkctl_el1::CNTKCTL_EL1;
+pub use cntkctl_el12::CNTKCTL_EL12;
 pub use cntp_ctl_el0::CNTP_CTL_EL0;
+pub use cntp_ctl_el02::CNTP_CTL_EL02;
 pub use cntp_cval_el0::CNTP_CVAL_EL0;
+pub use cntp_cval_el02::CNTP_CVAL_EL02;
 pub use cntp_tval_el0::CNTP_TVAL_EL0;
+pub use cntp_tval_el02::CNTP_TVAL_EL02;
 pub use cntpct_el0::CNTPCT_EL0;
 pub use cntv_ctl_el0::CNTV_CTL_EL0;
+pub use cntv_ctl_el02::CNTV_CTL_EL02;
 pub use cntv_cval_el0::CNTV_CVAL_EL0;
+pub use cntv_cval_el02::CNTV_CVAL_EL02;
 pub use cntv_tval_el0::CNTV_TVAL_EL0;
+pub use cntv_tval_el02::CNTV_TVAL_EL02;
 pub use cntvct_el0::CNTVCT_EL0;
 pub use cntvoff_el2::CNTVOFF_EL2;
+pub use contextidr_el1::CONTEXTIDR_EL1;
+pub use contextidr_el2::CONTEXTIDR_EL2;
+pub use contextidr_el12::CONTEXTIDR_EL12;
+pub use cpacr_el12::CPACR_EL12;
+pub use cptr_el2::CPTR_EL2;
+pub use cptr_el3::CPTR_EL3;
 pub use csselr_el1::CSSELR_EL1;
 pub use cpacr_el1::CPACR_EL1;
 pub use dacr32_el2::DACR32_EL2;
 pub use currentel::CurrentEL;
 pub use daif::DAIF;
+pub use dbgbcr0_el1::DBGBCR0_EL1;
+pub use dbgbcr1_el1::DBGBCR1_EL1;
+pub use dbgbcr2_el1::DBGBCR2_EL1;
+pub use dbgbcr3_el1::DBGBCR3_EL1;
+pub use dbgbcr4_el1::DBGBCR4_EL1;
+pub use dbgbcr5_el1::DBGBCR5_EL1;
+pub use dbgbcr6_el1::DBGBCR6_EL1;
+pub use dbgbcr7_el1::DBGBCR7_EL1;
+pub use dbgbcr8_el1::DBGBCR8_EL1;
+pub use dbgbcr9_el1::DBGBCR9_EL1;
+pub use dbgbcr10_el1::DBGBCR10_EL1;
+pub use dbgbcr11_el1::DBGBCR11_EL1;
+pub use dbgbcr12_el1::DBGBCR12_EL1;
+pub use dbgbcr13_el1::DBGBCR13_EL1;
+pub use dbgbcr14_el1::DBGBCR14_EL1;
+pub use dbgbcr15_el1::DBGBCR15_EL1;
+pub use dbgbvr0_el1::DBGBVR0_EL1;
+pub use dbgbvr1_el1::DBGBVR1_EL1;
+pub use dbgbvr2_el1::DBGBVR2_EL1;
+pub use dbgbvr3_el1::DBGBVR3_EL1;
+pub use dbgbvr4_el1::DBGBVR4_EL1;
+pub use dbgbvr5_el1::DBGBVR5_EL1;
+pub use dbgbvr6_el1::DBGBVR6_EL1;
+pub use dbgbvr7_el1::DBGBVR7_EL1;
+pub use dbgbvr8_el1::DBGBVR8_EL1;
+pub use dbgbvr9_el1::DBGBVR9_EL1;
+pub use dbgbvr10_el1::DBGBVR10_EL1;
+pub use dbgbvr11_el1::DBGBVR11_EL1;
+pub use dbgbvr12_el1::DBGBVR12_EL1;
+pub use dbgbvr13_el1::DBGBVR13_EL1;
+pub use dbgbvr14_el1::DBGBVR14_EL1;
+pub use dbgbvr15_el1::DBGBVR15_EL1;
+pub use dbgwcr0_el1::DBGWCR0_EL1;
+pub use dbgwcr1_el1::DBGWCR1_EL1;
+pub use dbgwcr2_el1::DBGWCR2_EL1;
+pub use dbgwcr3_el1::DBGWCR3_EL1;
+pub use dbgwcr4_el1::DBGWCR4_EL1;
+pub use dbgwcr5_el1::DBGWCR5_EL1;
+pub use dbgwcr6_el1::DBGWCR6_EL1;
+pub use dbgwcr7_el1::DBGWCR7_EL1;
+pub use dbgwcr8_el1::DBGWCR8_EL1;
+pub use dbgwcr9_el1::DBGWCR9_EL1;
+pub use dbgwcr10_el1::DBGWCR10_EL1;
+pub use dbgwcr11_el1::DBGWCR11_EL1;
+pub use dbgwcr12_el1::DBGWCR12_EL1;
+pub use dbgwcr13_el1::DBGWCR13_EL1;
+pub use dbgwcr14_el1::DBGWCR14_EL1;
+pub use dbgwcr15_el1::DBGWCR15_EL1;
+pub use dbgwvr0_el1::DBGWVR0_EL1;
+pub use dbgwvr1_el1::DBGWVR1_EL1;
+pub use dbgwvr2_el1::DBGWVR2_EL1;
+pub use dbgwvr3_el1::DBGWVR3_EL1;
+pub use dbgwvr4_el1::DBGWVR4_EL1;
+pub use dbgwvr5_el1::DBGWVR5_EL1;
+pub use dbgwvr6_el1::DBGWVR6_EL1;
+pub use dbgwvr7_el1::DBGWVR7_EL1;
+pub use dbgwvr8_el1::DBGWVR8_EL1;
+pub use dbgwvr9_el1::DBGWVR9_EL1;
+pub use dbgwvr10_el1::DBGWVR10_EL1;
+pub use dbgwvr11_el1::DBGWVR11_EL1;
+pub use dbgwvr12_el1::DBGWVR12_EL1;
+pub use dbgwvr13_el1::DBGWVR13_EL1;
+pub use dbgwvr14_el1::DBGWVR14_EL1;
+pub use dbgwvr15_el1::DBGWVR15_EL1;
+pub use dit::DIT;
 pub use elr_el1::ELR_EL1;
 pub use elr_el2::ELR_EL2;
 pub use elr_el3::ELR_EL3;
+pub use elr_el12::ELR_EL12;
 pub use esr_el1::ESR_EL1;
 pub use esr_el2::ESR_EL2;
+pub use esr_el3::ESR_EL3;
+pub use esr_el12::ESR_EL12;
 pub use far_el1::FAR_EL1;
 pub use far_el2::FAR_EL2;
+pub use far_el3::FAR_EL3;
+pub use far_el12::FAR_EL12;
 pub use fp::FP;
+pub use fpcr::FPCR;
+pub use fpexc32_el2::FPEXC32_EL2;
+pub use fpsr::FPSR;
+pub use gcr_el1::GCR_EL1;
+pub use gmid_el1::GMID_EL1;
+pub use hacr_el2::HACR_EL2;
 pub use hcr_el2::HCR_EL2;
+pub use hfgitr_el2::HFGITR_EL2;
+pub use hfgrtr_el2::HFGRTR_EL2;
+pub use hfgwtr_el2::HFGWTR_EL2;
+pub use hpfar_el2::HPFAR_EL2;
+pub use hstr_el2::HSTR_EL2;
+pub use id_aa64dfr0_el1::ID_AA64DFR0_EL1;
 pub use id_aa64mmfr0_el1::ID_AA64MMFR0_EL1;
 pub use id_aa64isar0_el1::ID_AA64ISAR0_EL1;
+pub use id_aa64isar1_el1::ID_AA64ISAR1_EL1;
+pub use id_aa64isar2_el1::ID_AA64ISAR2_EL1;
 pub use id_aa64mmfr1_el1::ID_AA64MMFR1_EL1;
 pub use id_aa64mmfr2_el1::ID_AA64MMFR2_EL1;
+pub use id_aa64pfr0_el1::ID_AA64PFR0_EL1;
+pub use id_aa64pfr1_el1::ID_AA64PFR1_EL1;
+pub use ifsr32_el2::IFSR32_EL2;
 pub use lr::LR;
 pub use mair_el1::MAIR_EL1;
 pub use mair_el2::MAIR_EL2;
+pub use mair_el3::MAIR_EL3;
+pub use mair_el12::MAIR_EL12;
+pub use mdccsr_el0::MDCCSR_EL0;
+pub use mdcr_el2::MDCR_EL2;
+pub use mdcr_el3::MDCR_EL3;
+pub use mdscr_el1::MDSCR_EL1;
 pub use midr_el1::MIDR_EL1;
 pub use mpidr_el1::MPIDR_EL1;
+pub use nzcv::NZCV;
+pub use osdlr_el1::OSDLR_EL1;
 pub use oslar_el1::OSLAR_EL1;
+pub use oslsr_el1::OSLSR_EL1;
+pub use pan::PAN;
 pub use par_el1::PAR_EL1;
+pub use pmccfiltr_el0::PMCCFILTR_EL0;
+pub use pmccntr_el0::PMCCNTR_EL0;
+pub use pmceid0_el0::PMCEID0_EL0;
+pub use pmceid1_el0::PMCEID1_EL0;
+pub use pmcntenclr_el0::PMCNTENCLR_EL0;
+pub use pmcntenset_el0::PMCNTENSET_EL0;
+pub use pmcr_el0::PMCR_EL0;
+pub use pmevcntr0_el0::PMEVCNTR0_EL0;
+pub use pmevcntr1_el0::PMEVCNTR1_EL0;
+pub use pmevcntr2_el0::PMEVCNTR2_EL0;
+pub use pmevcntr3_el0::PMEVCNTR3_EL0;
+pub use pmevcntr4_el0::PMEVCNTR4_EL0;
+pub use pmevcntr5_el0::PMEVCNTR5_EL0;
+pub use pmevcntr6_el0::PMEVCNTR6_EL0;
+pub use pmevcntr7_el0::PMEVCNTR7_EL0;
+pub use pmevcntr8_el0::PMEVCNTR8_EL0;
+pub use pmevcntr9_el0::PMEVCNTR9_EL0;
+pub use pmevcntr10_el0::PMEVCNTR10_EL0;
+pub use pmevcntr11_el0::PMEVCNTR11_EL0;
+pub use pmevcntr12_el0::PMEVCNTR12_EL0;
+pub use pmevcntr13_el0::PMEVCNTR13_EL0;
+pub use pmevcntr14_el0::PMEVCNTR14_EL0;
+pub use pmevcntr15_el0::PMEVCNTR15_EL0;
+pub use pmevcntr16_el0::PMEVCNTR16_EL0;
+pub use pmevcntr17_el0::PMEVCNTR17_EL0;
+pub use pmevcntr18_el0::PMEVCNTR18_EL0;
+pub use pmevcntr19_el0::PMEVCNTR19_EL0;
+pub use pmevcntr20_el0::PMEVCNTR20_EL0;
+pub use pmevcntr21_el0::PMEVCNTR21_EL0;
+pub use pmevcntr22_el0::PMEVCNTR22_EL0;
+pub use pmevcntr23_el0::PMEVCNTR23_EL0;
+pub use pmevcntr24_el0::PMEVCNTR24_EL0;
+pub use pmevcntr25_el0::PMEVCNTR25_EL0;
+pub use pmevcntr26_el0::PMEVCNTR26_EL0;
+pub use pmevcntr27_el0::PMEVCNTR27_EL0;
+pub use pmevcntr28_el0::PMEVCNTR28_EL0;
+pub use pmevcntr29_el0::PMEVCNTR29_EL0;
+pub use pmevcntr30_el0::PMEVCNTR30_EL0;
+pub use pmevtyper0_el0::PMEVTYPER0_EL0;
+pub use pmevtyper1_el0::PMEVTYPER1_EL0;
+pub use pmevtyper2_el0::PMEVTYPER2_EL0;
+pub use pmevtyper3_el0::PMEVTYPER3_EL0;
+pub use pmevtyper4_el0::PMEVTYPER4_EL0;
+pub use pmevtyper5_el0::PMEVTYPER5_EL0;
+pub use pmevtyper6_el0::PMEVTYPER6_EL0;
+pub use pmevtyper7_el0::PMEVTYPER7_EL0;
+pub use pmevtyper8_el0::PMEVTYPER8_EL0;
+pub use pmevtyper9_el0::PMEVTYPER9_EL0;
+pub use pmevtyper10_el0::PMEVTYPER10_EL0;
+pub use pmevtyper11_el0::PMEVTYPER11_EL0;
+pub use pmevtyper12_el0::PMEVTYPER12_EL0;
+pub use pmevtyper13_el0::PMEVTYPER13_EL0;
+pub use pmevtyper14_el0::PMEVTYPER14_EL0;
+pub use pmevtyper15_el0::PMEVTYPER15_EL0;
+pub use pmevtyper16_el0::PMEVTYPER16_EL0;
+pub use pmevtyper17_el0::PMEVTYPER17_EL0;
+pub use pmevtyper18_el0::PMEVTYPER18_EL0;
+pub use pmevtyper19_el0::PMEVTYPER19_EL0;
+pub use pmevtyper20_el0::PMEVTYPER20_EL0;
+pub use pmevtyper21_el0::PMEVTYPER21_EL0;
+pub use pmevtyper22_el0::PMEVTYPER22_EL0;
+pub use pmevtyper23_el0::PMEVTYPER23_EL0;
+pub use pmevtyper24_el0::PMEVTYPER24_EL0;
+pub use pmevtyper25_el0::PMEVTYPER25_EL0;
+pub use pmevtyper26_el0::PMEVTYPER26_EL0;
+pub use pmevtyper27_el0::PMEVTYPER27_EL0;
+pub use pmevtyper28_el0::PMEVTYPER28_EL0;
+pub use pmevtyper29_el0::PMEVTYPER29_EL0;
+pub use pmevtyper30_el0::PMEVTYPER30_EL0;
+pub use pmintenclr_el1::PMINTENCLR_EL1;
+pub use pmintenset_el1::PMINTENSET_EL1;
+pub use pmovsclr_el0::PMOVSCLR_EL0;
+pub use pmovsset_el0::PMOVSSET_EL0;
+pub use pmselr_el0::PMSELR_EL0;
+pub use pmuserenr_el0::PMUSERENR_EL0;
+pub use pmxevcntr_el0::PMXEVCNTR_EL0;
+pub use pmxevtyper_el0::PMXEVTYPER_EL0;
+pub use rgsr_el1::RGSR_EL1;
+pub use rmr_el3::RMR_EL3;
+pub use rvbar_el3::RVBAR_EL3;
 pub use scr_el3::SCR_EL3;
 pub use sctlr_el1::SCTLR_EL1;
 pub use sctlr_el2::SCTLR_EL2;
+pub use sctlr_el3::SCTLR_EL3;
+pub use sctlr_el12::SCTLR_EL12;
+pub use smcr_el1::SMCR_EL1;
+pub use smcr_el2::SMCR_EL2;
+pub use smcr_el3::SMCR_EL3;
+pub use smcr_el12::SMCR_EL12;
+pub use smidr_el1::SMIDR_EL1;
+pub use smpri_el1::SMPRI_EL1;
 pub use sp::SP;
 pub use sp_el0::SP_EL0;
 pub use sp_el1::SP_EL1;
 pub use spsel::SPSel;
+pub use spsr_abt::SPSR_abt;
 pub use spsr_el1::SPSR_EL1;
 pub use spsr_el2::SPSR_EL2;
 pub use spsr_el3::SPSR_EL3;
+pub use spsr_el12::SPSR_EL12;
+pub use spsr_fiq::SPSR_fiq;
+pub use spsr_irq::SPSR_irq;
+pub use spsr_und::SPSR_und;
+pub use ssbs::SSBS;
+pub use svcr::SVCR;
+pub use tco::TCO;
 pub use tcr_el1::TCR_EL1;
 pub use tcr_el2::TCR_EL2;
+pub use tcr_el3::TCR_EL3;
+pub use tcr_el12::TCR_EL12;
+pub use tfsr_el1::TFSR_EL1;
+pub use tfsr_el12::TFSR_EL12;
+pub use tfsre0_el1::TFSRE0_EL1;
 pub use tpidr_el0::TPIDR_EL0;
 pub use tpidr_el1::TPIDR_EL1;
+pub use tpidr_el2::TPIDR_EL2;
+pub use tpidr_el3::TPIDR_EL3;
 pub use tpidrro_el0::TPIDRRO_EL0;
 pub use ttbr0_el1::TTBR0_EL1;
 pub use ttbr0_el2::TTBR0_EL2;
+pub use ttbr0_el3::TTBR0_EL3;
+pub use ttbr0_el12::TTBR0_EL12;
 pub use ttbr1_el1::TTBR1_EL1;
+pub use ttbr1_el2::TTBR1_EL2;
+pub use ttbr1_el12::TTBR1_EL12;
+pub use uao::UAO;
 pub use vbar_el1::VBAR_EL1;
 pub use vbar_el2::VBAR_EL2;
+pub use vbar_el3::VBAR_EL3;
+pub use vbar_el12::VBAR_EL12;
+pub use vmpidr_el2::VMPIDR_EL2;
+pub use vpidr_el2::VPIDR_EL2;
 pub use vtcr_el2::VTCR_EL2;
 pub use vttbr_el2::VTTBR_EL2;
+pub use zcr_el1::ZCR_EL1;
+pub use zcr_el2::ZCR_EL2;
+pub use zcr_el3::ZCR_EL3;
+pub use zcr_el12::ZCR_EL12;
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch32 system registers.
+//!
+//! The registers are accessed with `MRC`/`MCR` and `MRRC`/`MCRR` to the CP15 coprocessor space, and
+//! are only implemented for the `arm` target architecture, e.g. `armv7a-none-eabi` or
+//! `armv8r-none-eabihf`.
+//!
+//! Registers that are architecturally mapped to an AArch64 register with the same layout share its
+//! field definitions, and are then accessed as 64-bit values. For example, [`DACR`] is read and
+//! written with the fields of [`DACR32_EL2`](mod@super::DACR32_EL2).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::registers::{aarch32::*, CNTP_CTL_EL0};
+//! use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+//!
+//! VBAR.set(0x8000);
+//! SCTLR.modify(SCTLR::M::Enable + SCTLR::C::Cacheable + SCTLR::I::Cacheable);
+//!
+//! CNTP_TVAL.set(CNTFRQ.get() / 100);
+//! CNTP_CTL.write(CNTP_CTL_EL0::ENABLE::SET + CNTP_CTL_EL0::IMASK::CLEAR);
+//!
+//! let cpu = MPIDR.read(MPIDR::Aff0);
+//! # let _ = cpu;
+//! ```
+
+mod cntfrq;
+mod cntp_ctl;
+mod cntp_cval;
+mod cntp_tval;
+mod cntpct;
+mod dacr;
+mod dfar;
+mod dfsr;
+mod ifar;
+mod ifsr;
+mod mpidr;
+mod sctlr;
+mod ttbcr;
+mod ttbr0;
+mod ttbr0_32;
+mod ttbr1;
+mod ttbr1_32;
+mod vbar;
+
+pub use cntfrq::CNTFRQ;
+pub use cntp_ctl::CNTP_CTL;
+pub use cntp_cval::CNTP_CVAL;
+pub use cntp_tval::CNTP_TVAL;
+pub use cntpct::CNTPCT;
+pub use dacr::DACR;
+pub use dfar::DFAR;
+pub use dfsr::DFSR;
+pub use ifar::IFAR;
+pub use ifsr::IFSR;
+pub use mpidr::MPIDR;
+pub use sctlr::SCTLR;
+pub use ttbcr::TTBCR;
+pub use ttbr0::TTBR0;
+pub use ttbr0_32::TTBR0_32;
+pub use ttbr1::TTBR1;
+pub use ttbr1_32::TTBR1_32;
+pub use vbar::VBAR;
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Frequency register - AArch32
+//!
+//! This register is provided so that software can discover the frequency of the system counter.
+//! It must be programmed with this value as part of system initialization. The value of the
+//! register is not interpreted by hardware.
+//!
+//! Architecturally mapped to [`CNTFRQ_EL0`](crate::registers::CNTFRQ_EL0).
+
+use tock_registers::interfaces::Readable;
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_read_raw!(u32, 0, c14, c0, 0);
+}
+
+pub const CNTFRQ: Reg = Reg {};
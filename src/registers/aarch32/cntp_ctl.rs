@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Timer Control register - AArch32
+//!
+//! Control register for the PL1 physical timer.
+//!
+//! Architecturally mapped to [`CNTP_CTL_EL0`](mod@crate::registers::CNTP_CTL_EL0), whose field
+//! definitions it shares. Accessed as a 64-bit value, of which bits \[63:32\] read as zero and are
+//! ignored on writes.
+
+use crate::registers::CNTP_CTL_EL0;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CNTP_CTL_EL0::Register;
+
+    cp15_read_raw!(u64, 0, c14, c2, 1);
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CNTP_CTL_EL0::Register;
+
+    cp15_write_raw!(u64, 0, c14, c2, 1);
+}
+
+pub const CNTP_CTL: Reg = Reg {};
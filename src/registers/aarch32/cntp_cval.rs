@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Timer CompareValue register - AArch32
+//!
+//! Holds the compare value for the PL1 physical timer.
+//!
+//! Architecturally mapped to [`CNTP_CVAL_EL0`](crate::registers::CNTP_CVAL_EL0).
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    cp15_read_raw64!(2, c14);
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    cp15_write_raw64!(2, c14);
+}
+
+pub const CNTP_CVAL: Reg = Reg {};
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Timer TimerValue register - AArch32
+//!
+//! Holds the timer value for the PL1 physical timer.
+//!
+//! Architecturally mapped to bits \[31:0\] of [`CNTP_TVAL_EL0`](crate::registers::CNTP_TVAL_EL0).
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_read_raw!(u32, 0, c14, c2, 0);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_write_raw!(u32, 0, c14, c2, 0);
+}
+
+pub const CNTP_TVAL: Reg = Reg {};
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Count register - AArch32
+//!
+//! Holds the 64-bit physical count value.
+//!
+//! Architecturally mapped to [`CNTPCT_EL0`](crate::registers::CNTPCT_EL0).
+
+use tock_registers::interfaces::Readable;
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    cp15_read_raw64!(0, c14);
+}
+
+pub const CNTPCT: Reg = Reg {};
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Domain Access Control Register - AArch32
+//!
+//! Defines the access permission for each of the sixteen memory domains of the short-descriptor
+//! translation table format.
+//!
+//! Architecturally mapped to [`DACR32_EL2`](mod@crate::registers::DACR32_EL2), whose field
+//! definitions it shares. Accessed as a 64-bit value, of which bits \[63:32\] read as zero and are
+//! ignored on writes.
+
+use crate::registers::DACR32_EL2;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DACR32_EL2::Register;
+
+    cp15_read_raw!(u64, 0, c3, c0, 0);
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DACR32_EL2::Register;
+
+    cp15_write_raw!(u64, 0, c3, c0, 0);
+}
+
+pub const DACR: Reg = Reg {};
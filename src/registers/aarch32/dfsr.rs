@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Data Fault Status Register - AArch32
+//!
+//! Holds status information about the last data fault.
+//!
+//! Architecturally mapped to bits \[31:0\] of [`ESR_EL1`](const@crate::registers::ESR_EL1), but
+//! with a different layout.
+//!
+//! The layout of the register depends on the translation table format in use, as reported by
+//! the LPAE bit. The short-descriptor format splits the fault status between FS_HI and FS_LO.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u32,
+    pub DFSR [
+        /// FAR not Valid, for a synchronous External abort other than one on a translation table
+        /// walk. When set, DFAR does not hold the faulting address.
+        FnV OFFSET(16) NUMBITS(1) [],
+
+        /// Cache maintenance fault. For synchronous faults, indicates whether the fault came from
+        /// a cache maintenance or address translation instruction.
+        CM OFFSET(13) NUMBITS(1) [],
+
+        /// External abort type. IMPLEMENTATION DEFINED classification of External aborts.
+        ExT OFFSET(12) NUMBITS(1) [],
+
+        /// Write not Read. Indicates whether the abort was caused by a write or a read.
+        WnR OFFSET(11) NUMBITS(1) [
+            Read = 0,
+            Write = 1
+        ],
+
+        /// Bit \[4\] of the fault status. Short-descriptor format only.
+        FS_HI OFFSET(10) NUMBITS(1) [],
+
+        /// Translation table format in use when the exception was taken.
+        LPAE OFFSET(9) NUMBITS(1) [
+            ShortDescriptor = 0,
+            LongDescriptor = 1
+        ],
+
+        /// The domain of the fault address. Short-descriptor format only, and UNKNOWN for some
+        /// faults.
+        Domain OFFSET(4) NUMBITS(4) [],
+
+        /// Fault status. Long-descriptor format only.
+        STATUS OFFSET(0) NUMBITS(6) [],
+
+        /// Bits \[3:0\] of the fault status. Short-descriptor format only.
+        FS_LO OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = DFSR::Register;
+
+    cp15_read_raw!(u32, 0, c5, c0, 0);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = DFSR::Register;
+
+    cp15_write_raw!(u32, 0, c5, c0, 0);
+}
+
+impl Reg {
+    /// Returns the fault status, combining FS_HI and FS_LO for the short-descriptor format.
+    #[inline(always)]
+    pub fn get_fault_status(&self) -> u32 {
+        let fsr = self.extract();
+
+        if fsr.is_set(DFSR::LPAE) {
+            fsr.read(DFSR::STATUS)
+        } else {
+            (fsr.read(DFSR::FS_HI) << 4) | fsr.read(DFSR::FS_LO)
+        }
+    }
+}
+
+pub const DFSR: Reg = Reg {};
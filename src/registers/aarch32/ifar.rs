@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Instruction Fault Address Register - AArch32
+//!
+//! Holds the virtual address of the faulting address that caused a synchronous Prefetch Abort
+//! exception.
+//!
+//! Architecturally mapped to bits \[63:32\] of [`FAR_EL1`](crate::registers::FAR_EL1).
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_read_raw!(u32, 0, c6, c0, 2);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_write_raw!(u32, 0, c6, c0, 2);
+}
+
+pub const IFAR: Reg = Reg {};
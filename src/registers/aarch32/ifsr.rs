@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Instruction Fault Status Register - AArch32
+//!
+//! Holds status information about the last instruction fault.
+//!
+//! Architecturally mapped to [`IFSR32_EL2`](mod@crate::registers::IFSR32_EL2), whose field
+//! definitions it shares. Accessed as a 64-bit value, of which bits \[63:32\] read as zero and are
+//! ignored on writes.
+
+use crate::registers::IFSR32_EL2;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = IFSR32_EL2::Register;
+
+    cp15_read_raw!(u64, 0, c5, c0, 1);
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = IFSR32_EL2::Register;
+
+    cp15_write_raw!(u64, 0, c5, c0, 1);
+}
+
+impl Reg {
+    /// Returns the fault status, combining FS_HI and FS_LO for the short-descriptor format.
+    #[inline(always)]
+    pub fn get_fault_status(&self) -> u64 {
+        let fsr = self.extract();
+
+        if fsr.is_set(IFSR32_EL2::LPAE) {
+            fsr.read(IFSR32_EL2::STATUS)
+        } else {
+            (fsr.read(IFSR32_EL2::FS_HI) << 4) | fsr.read(IFSR32_EL2::FS_LO)
+        }
+    }
+}
+
+pub const IFSR: Reg = Reg {};
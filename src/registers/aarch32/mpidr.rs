@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Multiprocessor Affinity Register - AArch32
+//!
+//! In a multiprocessor system, provides an additional PE identification mechanism for scheduling
+//! purposes.
+//!
+//! Architecturally mapped to bits \[31:0\] of [`MPIDR_EL1`](crate::registers::MPIDR_EL1).
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u32,
+    pub MPIDR [
+        /// Indicates whether this register uses the multiprocessing extensions format. RES 1 in
+        /// ARMv8.
+        M OFFSET(31) NUMBITS(1) [],
+
+        /// Indicates a Uniprocessor system, as distinct from PE 0 in a multiprocessor system.
+        U OFFSET(30) NUMBITS(1) [
+            Multiprocessor = 0,
+            Uniprocessor = 1
+        ],
+
+        /// Indicates whether the lowest level of affinity consists of logical PEs that are
+        /// implemented using a multithreading type approach.
+        MT OFFSET(24) NUMBITS(1) [],
+
+        /// Affinity level 2.
+        Aff2 OFFSET(16) NUMBITS(8) [],
+
+        /// Affinity level 1.
+        Aff1 OFFSET(8) NUMBITS(8) [],
+
+        /// Affinity level 0. The level that varies most frequently between PEs.
+        Aff0 OFFSET(0) NUMBITS(8) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = MPIDR::Register;
+
+    cp15_read_raw!(u32, 0, c0, c0, 5);
+}
+
+pub const MPIDR: Reg = Reg {};
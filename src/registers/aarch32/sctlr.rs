@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! System Control Register - AArch32
+//!
+//! Provides top level control of the system, including its memory system, at PL1 and PL0.
+//!
+//! Architecturally mapped to bits \[31:0\] of [`SCTLR_EL1`](const@crate::registers::SCTLR_EL1), but
+//! with a different layout.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u32,
+    pub SCTLR [
+        /// Default PSTATE.SSBS value on exception entry (FEAT_SSBS only).
+        DSSBS OFFSET(31) NUMBITS(1) [],
+
+        /// T32 Exception Enable. Selects the instruction set state that exceptions are taken in.
+        ///
+        /// 0 Exceptions, including reset, are taken to A32 state.
+        /// 1 Exceptions, including reset, are taken to T32 state.
+        TE OFFSET(30) NUMBITS(1) [
+            A32 = 0,
+            T32 = 1
+        ],
+
+        /// Access Flag Enable. When set, AP\[0\] of the short-descriptor translation table format
+        /// is an Access flag, and only the simplified access permissions model is supported.
+        AFE OFFSET(29) NUMBITS(1) [],
+
+        /// TEX remap enable. When set, the TEX\[0\], C and B bits of the short-descriptor
+        /// translation table format select a region in PRRR and NMRR.
+        TRE OFFSET(28) NUMBITS(1) [],
+
+        /// Endianness of data accesses at PL1, and of stage 1 translation table walks in the
+        /// PL1&0 translation regime.
+        EE OFFSET(25) NUMBITS(1) [
+            LittleEndian = 0,
+            BigEndian = 1
+        ],
+
+        /// Set Privileged Access Never on taking an exception to PL1 (FEAT_PAN only).
+        ///
+        /// 0 PSTATE.PAN is set on taking an exception to PL1.
+        /// 1 PSTATE.PAN is left unchanged on taking an exception to PL1.
+        SPAN OFFSET(23) NUMBITS(1) [],
+
+        /// Unprivileged write permission implies PL1 Execute-never.
+        UWXN OFFSET(20) NUMBITS(1) [],
+
+        /// Write permission implies Execute-never.
+        WXN OFFSET(19) NUMBITS(1) [],
+
+        /// Traps PL0 execution of WFE instructions to Undefined mode when clear.
+        nTWE OFFSET(18) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Traps PL0 execution of WFI instructions to Undefined mode when clear.
+        nTWI OFFSET(16) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Vectors bit. Selects the base address of the exception vectors.
+        ///
+        /// 0 Low exception vectors, at the address held in VBAR.
+        /// 1 High exception vectors (Hivecs), at 0xFFFF0000.
+        V OFFSET(13) NUMBITS(1) [
+            Low = 0,
+            High = 1
+        ],
+
+        /// Instruction access Cacheability control, for accesses at PL1 and PL0.
+        I OFFSET(12) NUMBITS(1) [
+            NonCacheable = 0,
+            Cacheable = 1
+        ],
+
+        /// Branch prediction enable. RES 1 in ARMv8.
+        Z OFFSET(11) NUMBITS(1) [],
+
+        /// SETEND instruction disable at PL1 and PL0.
+        SED OFFSET(8) NUMBITS(1) [],
+
+        /// IT Disable. Disables some uses of IT instructions at PL1 and PL0.
+        ITD OFFSET(7) NUMBITS(1) [],
+
+        /// System instruction memory barrier enable. Enables the CP15 DMB, DSB and ISB operations
+        /// at PL1 and PL0.
+        CP15BEN OFFSET(5) NUMBITS(1) [],
+
+        /// Load Multiple and Store Multiple Atomicity and Ordering Enable (FEAT_LSMAOC only).
+        LSMAOE OFFSET(4) NUMBITS(1) [],
+
+        /// No Trap Load Multiple and Store Multiple to Device-nGRE/Device-nGnRE/Device-nGnRnE
+        /// memory (FEAT_LSMAOC only).
+        nTLSMD OFFSET(3) NUMBITS(1) [],
+
+        /// Cacheability control, for data accesses at PL1 and PL0, and for stage 1 translation
+        /// table walks in the PL1&0 translation regime.
+        C OFFSET(2) NUMBITS(1) [
+            NonCacheable = 0,
+            Cacheable = 1
+        ],
+
+        /// Alignment check enable.
+        A OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// MMU enable for the PL1&0 stage 1 address translation.
+        M OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = SCTLR::Register;
+
+    cp15_read_raw!(u32, 0, c1, c0, 0);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = SCTLR::Register;
+
+    cp15_write_raw!(u32, 0, c1, c0, 0);
+}
+
+pub const SCTLR: Reg = Reg {};
@@ -0,0 +1,150 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation Table Base Control Register - AArch32
+//!
+//! Determines which of the Translation Table Base Registers defines the base address for a
+//! translation table walk required for the stage 1 translation of a memory access from PL1 or
+//! PL0, and controls the translation table format.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u32,
+    pub TTBCR [
+        /// Extended Address Enable.
+        ///
+        /// 0 Use the 32-bit translation system, with the short-descriptor translation table
+        ///   format.
+        /// 1 Use the 40-bit translation system, with the long-descriptor translation table format.
+        EAE OFFSET(31) NUMBITS(1) [
+            ShortDescriptor = 0,
+            LongDescriptor = 1
+        ],
+
+        /// Shareability attribute for memory associated with translation table walks using TTBR1.
+        /// Long-descriptor format only.
+        SH1 OFFSET(28) NUMBITS(2) [
+            None = 0b00,
+            Outer = 0b10,
+            Inner = 0b11
+        ],
+
+        /// Outer cacheability attribute for memory associated with translation table walks using
+        /// TTBR1. Long-descriptor format only.
+        ORGN1 OFFSET(26) NUMBITS(2) [
+            NonCacheable = 0b00,
+            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
+            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
+            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
+        ],
+
+        /// Inner cacheability attribute for memory associated with translation table walks using
+        /// TTBR1. Long-descriptor format only.
+        IRGN1 OFFSET(24) NUMBITS(2) [
+            NonCacheable = 0b00,
+            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
+            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
+            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
+        ],
+
+        /// Translation table walk disable for translations using TTBR1. Long-descriptor format
+        /// only.
+        EPD1 OFFSET(23) NUMBITS(1) [
+            EnableTTBR1Walks = 0,
+            DisableTTBR1Walks = 1
+        ],
+
+        /// Selects whether TTBR0 or TTBR1 defines the ASID. Long-descriptor format only.
+        A1 OFFSET(22) NUMBITS(1) [
+            TTBR0 = 0,
+            TTBR1 = 1
+        ],
+
+        /// The size offset of the memory region addressed by TTBR1. The region size is
+        /// 2^(32-T1SZ) bytes. Long-descriptor format only.
+        T1SZ OFFSET(16) NUMBITS(3) [],
+
+        /// Shareability attribute for memory associated with translation table walks using TTBR0.
+        /// Long-descriptor format only.
+        SH0 OFFSET(12) NUMBITS(2) [
+            None = 0b00,
+            Outer = 0b10,
+            Inner = 0b11
+        ],
+
+        /// Outer cacheability attribute for memory associated with translation table walks using
+        /// TTBR0. Long-descriptor format only.
+        ORGN0 OFFSET(10) NUMBITS(2) [
+            NonCacheable = 0b00,
+            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
+            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
+            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
+        ],
+
+        /// Inner cacheability attribute for memory associated with translation table walks using
+        /// TTBR0. Long-descriptor format only.
+        IRGN0 OFFSET(8) NUMBITS(2) [
+            NonCacheable = 0b00,
+            WriteBack_ReadAlloc_WriteAlloc_Cacheable = 0b01,
+            WriteThrough_ReadAlloc_NoWriteAlloc_Cacheable = 0b10,
+            WriteBack_ReadAlloc_NoWriteAlloc_Cacheable = 0b11
+        ],
+
+        /// Translation table walk disable for translations using TTBR0. Long-descriptor format
+        /// only.
+        EPD0 OFFSET(7) NUMBITS(1) [
+            EnableTTBR0Walks = 0,
+            DisableTTBR0Walks = 1
+        ],
+
+        /// TTBCR2 Enable (FEAT_AA32HPD only). Long-descriptor format only.
+        T2E OFFSET(6) NUMBITS(1) [],
+
+        /// Translation table walk disable for translations using TTBR1. Short-descriptor format
+        /// only.
+        PD1 OFFSET(5) NUMBITS(1) [
+            EnableTTBR1Walks = 0,
+            DisableTTBR1Walks = 1
+        ],
+
+        /// Translation table walk disable for translations using TTBR0. Short-descriptor format
+        /// only.
+        PD0 OFFSET(4) NUMBITS(1) [
+            EnableTTBR0Walks = 0,
+            DisableTTBR0Walks = 1
+        ],
+
+        /// The size offset of the memory region addressed by TTBR0. The region size is
+        /// 2^(32-T0SZ) bytes. Long-descriptor format only.
+        T0SZ OFFSET(0) NUMBITS(3) [],
+
+        /// Width of the base address held in TTBR0. Addresses with any of bits \[31:32-N\] set
+        /// are translated using TTBR1. Short-descriptor format only.
+        N OFFSET(0) NUMBITS(3) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = TTBCR::Register;
+
+    cp15_read_raw!(u32, 0, c2, c0, 2);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = TTBCR::Register;
+
+    cp15_write_raw!(u32, 0, c2, c0, 2);
+}
+
+pub const TTBCR: Reg = Reg {};
@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation Table Base Register 1 - AArch32
+//!
+//! Holds the base address of the translation table for the initial lookup for stage 1 of the
+//! translation of an address from the upper VA range in the PL1&0 translation regime, and
+//! other information for this translation regime.
+//!
+//! Accessed as a 64-bit register, which has the long-descriptor format used when TTBCR.EAE is set.
+//! The 64-bit accesses require the Large Physical Address Extension. Use
+//! [`TTBR1_32`](const@super::TTBR1_32) for the short-descriptor format. Architecturally mapped
+//! to [`TTBR1_EL1`](mod@crate::registers::TTBR1_EL1), whose field definitions it shares. Only
+//! the lower 8 bits of ASID are implemented.
+
+use crate::registers::TTBR1_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TTBR1_EL1::Register;
+
+    cp15_read_raw64!(1, c2);
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TTBR1_EL1::Register;
+
+    cp15_write_raw64!(1, c2);
+}
+
+impl Reg {
+    #[inline(always)]
+    pub fn get_baddr(&self) -> u64 {
+        self.read(TTBR1_EL1::BADDR) << 1
+    }
+
+    #[inline(always)]
+    pub fn set_baddr(&self, addr: u64) {
+        self.write(TTBR1_EL1::BADDR.val(addr >> 1));
+    }
+}
+
+pub const TTBR1: Reg = Reg {};
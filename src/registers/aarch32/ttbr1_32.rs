@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation Table Base Register 1 - AArch32, short-descriptor format
+//!
+//! The 32-bit view of [`TTBR1`](const@super::TTBR1) used when TTBCR.EAE is clear. Unlike the 64-bit
+//! view, it is also available on PEs without the Large Physical Address Extension, on which
+//! `MRRC`/`MCRR` accesses to it are UNDEFINED.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u32,
+    pub TTBR1_32 [
+        /// Translation table base 1 address, bits \[31:14\] of the translation table base.
+        TTB1 OFFSET(14) NUMBITS(18) [],
+
+        /// Inner region bit 0, the low bit of the inner cacheability attribute for translation
+        /// table walks. Read together with IRGN_1 as IRGN_1:IRGN_0:
+        ///
+        /// 00 Normal memory, Inner Non-cacheable.
+        /// 01 Normal memory, Inner Write-Back Write-Allocate Cacheable.
+        /// 10 Normal memory, Inner Write-Through Cacheable.
+        /// 11 Normal memory, Inner Write-Back no Write-Allocate Cacheable.
+        IRGN_0 OFFSET(6) NUMBITS(1) [],
+
+        /// Not Outer Shareable bit, for translation table walks to Shareable memory.
+        NOS OFFSET(5) NUMBITS(1) [
+            OuterShareable = 0,
+            InnerShareable = 1
+        ],
+
+        /// Region bits, the outer cacheability attribute for translation table walks.
+        RGN OFFSET(3) NUMBITS(2) [
+            NonCacheable = 0b00,
+            WriteBack_WriteAlloc_Cacheable = 0b01,
+            WriteThrough_Cacheable = 0b10,
+            WriteBack_NoWriteAlloc_Cacheable = 0b11
+        ],
+
+        /// Implementation defined.
+        IMP OFFSET(2) NUMBITS(1) [],
+
+        /// Shareable bit, for translation table walks.
+        S OFFSET(1) NUMBITS(1) [
+            NonShareable = 0,
+            Shareable = 1
+        ],
+
+        /// Inner region bit 1, the high bit of the inner cacheability attribute for translation
+        /// table walks, see IRGN_0. Without the Multiprocessing Extensions, this is the C bit,
+        /// which makes the walks Inner Cacheable when set.
+        IRGN_1 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = TTBR1_32::Register;
+
+    cp15_read_raw!(u32, 0, c2, c0, 1);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = TTBR1_32::Register;
+
+    cp15_write_raw!(u32, 0, c2, c0, 1);
+}
+
+impl Reg {
+    #[inline(always)]
+    pub fn get_baddr(&self) -> u32 {
+        self.read(TTBR1_32::TTB1) << 14
+    }
+
+    #[inline(always)]
+    pub fn set_baddr(&self, addr: u32) {
+        self.write(TTBR1_32::TTB1.val(addr >> 14));
+    }
+}
+
+pub const TTBR1_32: Reg = Reg {};
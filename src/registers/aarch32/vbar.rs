@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Vector Base Address Register - AArch32
+//!
+//! Holds the vector base address for any exception that is taken to PL1 when SCTLR.V is clear.
+//!
+//! Architecturally mapped to bits \[31:0\] of [`VBAR_EL1`](crate::registers::VBAR_EL1).
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_read_raw!(u32, 0, c12, c0, 0);
+}
+
+impl Writeable for Reg {
+    type T = u32;
+    type R = ();
+
+    cp15_write_raw!(u32, 0, c12, c0, 0);
+}
+
+pub const VBAR: Reg = Reg {};
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! All Interrupt Mask Bit
+//!
+//! Allows access to the all interrupt mask bit.
+//!
+//! Accessed through its encoding `S3_0_C4_C3_0`, as assemblers only accept the name with FEAT_NMI
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub ALLINT [
+        /// All interrupt mask.
+        ///
+        /// 0 This control does not cause any interrupts to be masked.
+        /// 1 All IRQ or FIQ interrupts, including those with superpriority, are masked.
+        ALLINT OFFSET(13) NUMBITS(1) [
+            Unmasked = 0,
+            Masked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ALLINT::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c4_c3_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ALLINT::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c4_c3_0", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.ALLINT using the `MSR ALLINT, #<imm>` form, which does not need a
+    /// general-purpose register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            // `msr allint, #<imm>`, encoded directly: no `.arch_extension` enables it, and `.arch`
+            // would also change the architecture the rest of the function is assembled for.
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".inst 0xd501411f", options(nostack))
+                } else {
+                    core::arch::asm!(".inst 0xd501401f", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const ALLINT: Reg = Reg {};
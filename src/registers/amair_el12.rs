@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Auxiliary Memory Attribute Indirection Register - EL12
+//!
+//! When HCR_EL2.E2H is 1, accesses AMAIR_EL1 from EL2, where the AMAIR_EL1 encoding is redirected
+//! to the corresponding EL2 register. Uses the bitfields of AMAIR_EL1.
+//!
+//! Accessed through its encoding `S3_5_C10_C3_0`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "s3_5_c10_c3_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "s3_5_c10_c3_0", "x");
+}
+
+pub const AMAIR_EL12: Reg = Reg {};
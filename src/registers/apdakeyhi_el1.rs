@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Pointer Authentication Key A for Data High - EL1
+//!
+//! Holds bits\[127:64\] of key A used for authentication of data pointer values.
+//!
+//! Accessed through its encoding `S3_0_C2_C2_1`, as assemblers only accept the name with FEAT_PAuth
+//! enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "s3_0_c2_c2_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "s3_0_c2_c2_1", "x");
+}
+
+pub const APDAKEYHI_EL1: Reg = Reg {};
@@ -10,7 +10,7 @@
 //! Provides information about the architecture of the currently selected cache.
 
 use tock_registers::{
-    interfaces::{Readable, Writeable},
+    interfaces::Readable,
     register_bitfields,
 };
 
@@ -71,16 +71,6 @@ impl Reg {
         }
     }
 
-    /// Sets the [`CCSIDR_EL1`] `NumSets` field, selecting the correct
-    /// bit field by checking if the running CPU supports `CCIDX`.
-    #[inline(always)]
-    pub fn set_num_sets(&self, value: u64) {
-        match has_feature_ccidx() {
-            true => self.write(CCSIDR_EL1::NumSetsWithCCIDX.val(value)),
-            false => self.write(CCSIDR_EL1::NumSetsWithoutCCIDX.val(value)),
-        }
-    }
-
     /// Reads the [`CCSIDR_EL1`] `Associativity` field, selecting the correct
     /// bit field by checking if the running CPU supports `CCIDX`.
     #[inline(always)]
@@ -90,16 +80,6 @@ impl Reg {
             false => self.read(CCSIDR_EL1::AssociativityWithoutCCIDX),
         }
     }
-
-    /// Sets the [`CCSIDR_EL1`] `Associativity` field, selecting the correct
-    /// bit field by checking if the running CPU supports `CCIDX`.
-    #[inline(always)]
-    pub fn set_associativity(&self, value: u64) {
-        match has_feature_ccidx() {
-            true => self.write(CCSIDR_EL1::AssociativityWithCCIDX.val(value)),
-            false => self.write(CCSIDR_EL1::AssociativityWithoutCCIDX.val(value)),
-        }
-    }
 }
 
 impl Readable for Reg {
@@ -109,11 +89,4 @@ impl Readable for Reg {
     sys_coproc_read_raw!(u64, "CCSIDR_EL1", "x");
 }
 
-impl Writeable for Reg {
-    type T = u64;
-    type R = CCSIDR_EL1::Register;
-
-    sys_coproc_write_raw!(u64, "CCSIDR_EL1", "x");
-}
-
 pub const CCSIDR_EL1: Reg = Reg;
@@ -13,7 +13,7 @@
 //! of Unification (LoU) for the cache hierarchy.
 
 use tock_registers::{
-    interfaces::{Readable, Writeable},
+    interfaces::Readable,
     register_bitfields,
 };
 
@@ -404,11 +404,4 @@ impl Readable for Reg {
     sys_coproc_read_raw!(u64, "CLIDR_EL1", "x");
 }
 
-impl Writeable for Reg {
-    type T = u64;
-    type R = CLIDR_EL1::Register;
-
-    sys_coproc_write_raw!(u64, "CLIDR_EL1", "x");
-}
-
 pub const CLIDR_EL1: Reg = Reg;
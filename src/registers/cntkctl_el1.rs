@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Kernel Control register - EL1
+//!
+//! Controls the generation of an event stream from the virtual counter, and access from EL0 to
+//! the physical counter, virtual counter, EL1 physical timers, and the virtual timer.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub CNTKCTL_EL1 [
+        /// Traps EL0 accesses to the physical timer registers to EL1 when clear.
+        EL0PTEN OFFSET(9) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the virtual timer registers to EL1 when clear.
+        EL0VTEN OFFSET(8) NUMBITS(1) [],
+
+        /// Selects which bit of the virtual counter is the trigger for the event stream.
+        EVNTI OFFSET(4) NUMBITS(4) [],
+
+        /// Controls which transition of the EVNTI trigger bit generates an event.
+        EVNTDIR OFFSET(3) NUMBITS(1) [
+            ZeroToOne = 0,
+            OneToZero = 1
+        ],
+
+        /// Enables the generation of an event stream from the virtual counter.
+        EVNTEN OFFSET(2) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the frequency register and virtual counter register to EL1 when
+        /// clear.
+        EL0VCTEN OFFSET(1) NUMBITS(1) [],
+
+        /// Traps EL0 accesses to the frequency register and physical counter register to EL1
+        /// when clear.
+        EL0PCTEN OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CNTKCTL_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "CNTKCTL_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CNTKCTL_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "CNTKCTL_EL1", "x");
+}
+
+pub const CNTKCTL_EL1: Reg = Reg {};
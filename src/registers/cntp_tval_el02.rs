@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Physical Timer TimerValue register - EL02
+//!
+//! When HCR_EL2.E2H is 1, accesses CNTP_TVAL_EL0 from EL2, where the CNTP_TVAL_EL0 encoding is
+//! redirected to the corresponding EL2 register. Uses the bitfields of CNTP_TVAL_EL0.
+//!
+//! Accessed through its encoding `S3_5_C14_C2_0`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "s3_5_c14_c2_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "s3_5_c14_c2_0", "x");
+}
+
+pub const CNTP_TVAL_EL02: Reg = Reg {};
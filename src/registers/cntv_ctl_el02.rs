@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Counter-timer Virtual Timer Control register - EL02
+//!
+//! When HCR_EL2.E2H is 1, accesses CNTV_CTL_EL0 from EL2, where the CNTV_CTL_EL0 encoding is
+//! redirected to the corresponding EL2 register. Uses the bitfields of CNTV_CTL_EL0.
+//!
+//! Accessed through its encoding `S3_5_C14_C3_1`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use super::CNTV_CTL_EL0;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CNTV_CTL_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "s3_5_c14_c3_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CNTV_CTL_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "s3_5_c14_c3_1", "x");
+}
+
+pub const CNTV_CTL_EL02: Reg = Reg {};
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Context ID Register - EL1
+//!
+//! Identifies the current Process Identifier.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub CONTEXTIDR_EL1 [
+        /// Process Identifier.
+        PROCID OFFSET(0) NUMBITS(32) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CONTEXTIDR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "CONTEXTIDR_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CONTEXTIDR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "CONTEXTIDR_EL1", "x");
+}
+
+pub const CONTEXTIDR_EL1: Reg = Reg {};
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Context ID Register - EL2
+//!
+//! Identifies the current Process Identifier when HCR_EL2.E2H is 1.
+//!
+//! Accessed through its encoding `S3_4_C13_C0_1`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub CONTEXTIDR_EL2 [
+        /// Process Identifier.
+        PROCID OFFSET(0) NUMBITS(32) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CONTEXTIDR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "s3_4_c13_c0_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CONTEXTIDR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "s3_4_c13_c0_1", "x");
+}
+
+pub const CONTEXTIDR_EL2: Reg = Reg {};
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Architectural Feature Trap Register - EL2
+//!
+//! Controls trapping to EL2 of accesses to CPACR, CPACR_EL1, trace, Activity Monitor, SME,
+//! Streaming SVE, SVE, and Advanced SIMD and floating-point functionality.
+//!
+//! The layout depends on HCR_EL2.E2H. Fields only valid with HCR_EL2.E2H == 1 are marked as such.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub CPTR_EL2 [
+        /// Traps EL1 accesses to CPACR_EL1 to EL2.
+        TCPAC OFFSET(31) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// Traps EL0, EL1 and EL2 accesses to Activity Monitor registers to EL2.
+        TAM OFFSET(30) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// HCR_EL2.E2H == 1: Traps System register accesses to the trace registers to EL2.
+        TTA_E2H OFFSET(28) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// HCR_EL2.E2H == 1: Traps execution of SME instructions and accesses to SVCR, SMCR_EL1
+        /// and SMCR_EL2 to EL2.
+        SMEN OFFSET(24) NUMBITS(2) [
+            TrapEl0El2 = 0b00,
+            TrapEl0 = 0b01,
+            TrapNothing = 0b11
+        ],
+
+        /// HCR_EL2.E2H == 1: Traps execution of instructions which access the Advanced SIMD and
+        /// floating-point functionality to EL2.
+        FPEN OFFSET(20) NUMBITS(2) [
+            TrapEl0El2 = 0b00,
+            TrapEl0 = 0b01,
+            TrapNothing = 0b11
+        ],
+
+        /// HCR_EL2.E2H == 0: Traps System register accesses to the trace registers to EL2.
+        TTA OFFSET(20) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// HCR_EL2.E2H == 1: Traps execution of SVE instructions and accesses to ZCR_EL1 and
+        /// ZCR_EL2 to EL2.
+        ZEN OFFSET(16) NUMBITS(2) [
+            TrapEl0El2 = 0b00,
+            TrapEl0 = 0b01,
+            TrapNothing = 0b11
+        ],
+
+        /// HCR_EL2.E2H == 0: Traps execution of SME instructions and accesses to SVCR, SMCR_EL1
+        /// and SMCR_EL2 to EL2.
+        TSM OFFSET(12) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// HCR_EL2.E2H == 0: Traps execution of instructions which access the Advanced SIMD and
+        /// floating-point functionality to EL2.
+        TFP OFFSET(10) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// HCR_EL2.E2H == 0: Traps execution of SVE instructions and accesses to ZCR_EL1 and
+        /// ZCR_EL2 to EL2.
+        TZ OFFSET(8) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CPTR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "CPTR_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CPTR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "CPTR_EL2", "x");
+}
+
+pub const CPTR_EL2: Reg = Reg {};
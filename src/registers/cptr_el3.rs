@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Architectural Feature Trap Register - EL3
+//!
+//! Controls trapping to EL3 of accesses to CPACR_EL1, CPTR_EL2, trace, Activity Monitor, SME,
+//! Streaming SVE, SVE, and Advanced SIMD and floating-point functionality.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub CPTR_EL3 [
+        /// Traps EL2 accesses to CPTR_EL2 and HCPTR, and EL2 and EL1 accesses to CPACR_EL1 and
+        /// CPACR, to EL3.
+        TCPAC OFFSET(31) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// Traps EL0, EL1 and EL2 accesses to Activity Monitor registers to EL3.
+        TAM OFFSET(30) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// Traps System register accesses to all implemented trace registers to EL3.
+        TTA OFFSET(20) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// Traps execution of SME instructions and accesses to SVCR, SMCR_EL1, SMCR_EL2 and
+        /// SMCR_EL3 to EL3.
+        ESM OFFSET(12) NUMBITS(1) [
+            Trap = 0,
+            NoTrap = 1
+        ],
+
+        /// Traps execution of instructions which access the Advanced SIMD and floating-point
+        /// functionality to EL3.
+        TFP OFFSET(10) NUMBITS(1) [
+            NoTrap = 0,
+            Trap = 1
+        ],
+
+        /// Traps execution of SVE instructions and accesses to ZCR_EL1, ZCR_EL2 and ZCR_EL3 to
+        /// EL3.
+        EZ OFFSET(8) NUMBITS(1) [
+            Trap = 0,
+            NoTrap = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = CPTR_EL3::Register;
+
+    sys_coproc_read_raw!(u64, "CPTR_EL3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = CPTR_EL3::Register;
+
+    sys_coproc_write_raw!(u64, "CPTR_EL3", "x");
+}
+
+pub const CPTR_EL3: Reg = Reg {};
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Breakpoint Control Register 0 - EL1
+//!
+//! Holds control information for a breakpoint. Forms breakpoint 0 together with value register
+//! `DBGBVR0_EL1`.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub DBGBCR0_EL1 [
+        /// Breakpoint Type. This field is used to set the type of this breakpoint.
+        ///
+        /// 0000 Unlinked instruction address match.
+        /// 0001 Linked instruction address match.
+        /// 0010 Unlinked Context ID match.
+        /// 0011 Linked Context ID match.
+        /// 0100 Unlinked instruction address mismatch.
+        /// 0101 Linked instruction address mismatch.
+        /// 1000 Unlinked VMID match.
+        /// 1001 Linked VMID match.
+        /// 1010 Unlinked VMID and Context ID match.
+        /// 1011 Linked VMID and Context ID match.
+        ///
+        /// All other values are reserved.
+        BT OFFSET(20) NUMBITS(4) [
+            UnlinkedAddressMatch = 0b0000,
+            LinkedAddressMatch = 0b0001,
+            UnlinkedContextIdMatch = 0b0010,
+            LinkedContextIdMatch = 0b0011,
+            UnlinkedAddressMismatch = 0b0100,
+            LinkedAddressMismatch = 0b0101,
+            UnlinkedVmidMatch = 0b1000,
+            LinkedVmidMatch = 0b1001,
+            UnlinkedVmidContextIdMatch = 0b1010,
+            LinkedVmidContextIdMatch = 0b1011
+        ],
+
+        /// Linked breakpoint number. For Linked Address Matching breakpoints, this specifies the
+        /// index of the Context-matching breakpoint linked to.
+        LBN OFFSET(16) NUMBITS(4) [],
+
+        /// Security state control. Determines the Security states under which a debug event for
+        /// this breakpoint is generated. This field must be interpreted along with the HMC and PMC
+        /// fields.
+        SSC OFFSET(14) NUMBITS(2) [],
+
+        /// Higher mode control. Determines the debug perspective for deciding when a debug event
+        /// for this breakpoint is generated. This field must be interpreted along with the SSC and
+        /// PMC fields.
+        HMC OFFSET(13) NUMBITS(1) [],
+
+        /// Byte address select. Defines which half-words an address-matching breakpoint matches,
+        /// regardless of the instruction set and Execution state. For AArch64 instructions, this
+        /// field must be programmed to 0b1111.
+        BAS OFFSET(5) NUMBITS(4) [
+            AArch64 = 0b1111
+        ],
+
+        /// Privilege mode control. Determines the Exception level or levels at which a
+        /// Breakpoint debug event for this breakpoint is generated. This field must be
+        /// interpreted along with the SSC and HMC fields.
+        PMC OFFSET(1) NUMBITS(2) [],
+
+        /// Enable breakpoint.
+        ///
+        /// 0 Breakpoint disabled.
+        /// 1 Breakpoint enabled.
+        E OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DBGBCR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "DBGBCR0_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DBGBCR0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "DBGBCR0_EL1", "x");
+}
+
+pub const DBGBCR0_EL1: Reg = Reg {};
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Breakpoint Control Register 14 - EL1
+//!
+//! Holds control information for a breakpoint. Forms breakpoint 14 together with value register
+//! `DBGBVR14_EL1`.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+#[allow(non_snake_case)]
+pub mod DBGBCR14_EL1 {
+    //! Same layout as [`DBGBCR0_EL1`](super::super::DBGBCR0_EL1).
+
+    pub use super::super::dbgbcr0_el1::DBGBCR0_EL1::*;
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DBGBCR14_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "DBGBCR14_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DBGBCR14_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "DBGBCR14_EL1", "x");
+}
+
+pub const DBGBCR14_EL1: Reg = Reg {};
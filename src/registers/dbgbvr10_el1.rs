@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Breakpoint Value Register 10 - EL1
+//!
+//! Holds a virtual address, or a VMID and/or a context ID, for use in breakpoint matching. Forms
+//! breakpoint 10 together with control register `DBGBCR10_EL1`.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "DBGBVR10_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "DBGBVR10_EL1", "x");
+}
+
+pub const DBGBVR10_EL1: Reg = Reg {};
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Watchpoint Control Register 0 - EL1
+//!
+//! Holds control information for a watchpoint. Forms watchpoint 0 together with value register
+//! `DBGWVR0_EL1`.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub DBGWCR0_EL1 [
+        /// Address mask. Only objects up to 2GB can be watched using a single mask.
+        ///
+        /// 00000 No mask.
+        /// 00011 0x00000007 mask for address.
+        /// 00100 0x0000000F mask for address.
+        /// ...
+        /// 11111 0x7FFFFFFF mask for address.
+        ///
+        /// The values 0b00001 and 0b00010 are reserved. If MASK is nonzero, BAS must be
+        /// programmed to 0b11111111.
+        MASK OFFSET(24) NUMBITS(5) [],
+
+        /// Watchpoint type.
+        ///
+        /// 0 Unlinked data address match.
+        /// 1 Linked data address match.
+        WT OFFSET(20) NUMBITS(1) [
+            Unlinked = 0,
+            Linked = 1
+        ],
+
+        /// Linked breakpoint number. For Linked Address Matching breakpoints, this specifies the
+        /// index of the Context-matching breakpoint linked to.
+        LBN OFFSET(16) NUMBITS(4) [],
+
+        /// Security state control. Determines the Security states under which a debug event for
+        /// this watchpoint is generated. This field must be interpreted along with the HMC and PAC
+        /// fields.
+        SSC OFFSET(14) NUMBITS(2) [],
+
+        /// Higher mode control. Determines the debug perspective for deciding when a debug event
+        /// for this watchpoint is generated. This field must be interpreted along with the SSC and
+        /// PAC fields.
+        HMC OFFSET(13) NUMBITS(1) [],
+
+        /// Byte address select. Each bit of this field selects whether a byte from within the
+        /// word or double-word addressed by `DBGWVR<n>_EL1` is being watched. Bit n corresponds
+        /// to byte address `DBGWVR<n>_EL1 + n`.
+        BAS OFFSET(5) NUMBITS(8) [],
+
+        /// Load/store control. This field enables watchpoint matching on the type of access
+        /// being made.
+        ///
+        /// 01 Match instructions that load from a watchpointed address.
+        /// 10 Match instructions that store to a watchpointed address.
+        /// 11 Match instructions that load from or store to a watchpointed address.
+        ///
+        /// All other values are reserved.
+        LSC OFFSET(3) NUMBITS(2) [
+            Load = 0b01,
+            Store = 0b10,
+            LoadStore = 0b11
+        ],
+
+        /// Privilege of access control. Determines the Exception level or levels at which a
+        /// Watchpoint debug event for this watchpoint is generated. This field must be
+        /// interpreted along with the SSC and HMC fields.
+        PAC OFFSET(1) NUMBITS(2) [],
+
+        /// Enable watchpoint.
+        ///
+        /// 0 Watchpoint disabled.
+        /// 1 Watchpoint enabled.
+        E OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DBGWCR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "DBGWCR0_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DBGWCR0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "DBGWCR0_EL1", "x");
+}
+
+pub const DBGWCR0_EL1: Reg = Reg {};
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Debug Watchpoint Control Register 7 - EL1
+//!
+//! Holds control information for a watchpoint. Forms watchpoint 7 together with value register
+//! `DBGWVR7_EL1`.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+#[allow(non_snake_case)]
+pub mod DBGWCR7_EL1 {
+    //! Same layout as [`DBGWCR0_EL1`](super::super::DBGWCR0_EL1).
+
+    pub use super::super::dbgwcr0_el1::DBGWCR0_EL1::*;
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DBGWCR7_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "DBGWCR7_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DBGWCR7_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "DBGWCR7_EL1", "x");
+}
+
+pub const DBGWCR7_EL1: Reg = Reg {};
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Data Independent Timing
+//!
+//! Allows access to the Data Independent Timing bit.
+//!
+//! Accessed through its encoding `S3_3_C4_C2_5`, as assemblers only accept the name with FEAT_DIT
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub DIT [
+        /// Data Independent Timing.
+        ///
+        /// 0 The architecture makes no statement about the timing properties of any instructions.
+        /// 1 The architecture requires that the timing of data-processing instructions, and of
+        ///   loads and stores, is independent of the values of the data operated on.
+        DIT OFFSET(24) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = DIT::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c2_5", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = DIT::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c2_5", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.DIT using the `MSR DIT, #<imm>` form, which does not need a general-purpose
+    /// register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            // `msr dit, #<imm>`, encoded directly: not every supported LLVM version knows the `dit`
+            // extension.
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".inst 0xd503415f", options(nostack))
+                } else {
+                    core::arch::asm!(".inst 0xd503405f", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const DIT: Reg = Reg {};
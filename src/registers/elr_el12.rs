@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Exception Link Register - EL12
+//!
+//! When HCR_EL2.E2H is 1, accesses ELR_EL1 from EL2, where the ELR_EL1 encoding is redirected to
+//! the corresponding EL2 register. Uses the bitfields of ELR_EL1.
+//!
+//! Accessed through its encoding `S3_5_C4_C0_1`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "s3_5_c4_c0_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "s3_5_c4_c0_1", "x");
+}
+
+pub const ELR_EL12: Reg = Reg {};
@@ -10,7 +10,10 @@
 //!
 //! Holds syndrome information for an exception taken to EL1.
 
-use tock_registers::{interfaces::Readable, register_bitfields};
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
 
 register_bitfields! {u64,
     pub ESR_EL1 [
@@ -79,4 +82,11 @@ impl Readable for Reg {
     sys_coproc_read_raw!(u64, "ESR_EL1", "x");
 }
 
+impl Writeable for Reg {
+    type T = u64;
+    type R = ESR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "ESR_EL1", "x");
+}
+
 pub const ESR_EL1: Reg = Reg {};
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Floating-point Control Register
+//!
+//! Controls floating-point behavior.
+//!
+//! Accessed through its encoding `S3_3_C4_C4_0`, as assemblers only accept the name with the FP
+//! extension enabled, which soft-float targets lack.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub FPCR [
+        /// Alternative half-precision control bit.
+        AHP OFFSET(26) NUMBITS(1) [
+            IEEE = 0,
+            Alternative = 1
+        ],
+
+        /// Default NaN use for NaN propagation.
+        DN OFFSET(25) NUMBITS(1) [
+            Propagate = 0,
+            DefaultNaN = 1
+        ],
+
+        /// Flushing denormalized numbers to zero control bit.
+        FZ OFFSET(24) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Rounding Mode control field.
+        RMode OFFSET(22) NUMBITS(2) [
+            RoundToNearest = 0b00,
+            RoundTowardsPlusInfinity = 0b01,
+            RoundTowardsMinusInfinity = 0b10,
+            RoundTowardsZero = 0b11
+        ],
+
+        /// AArch32 only. This field has no function in AArch64 state, and non-zero values are
+        /// ignored during execution in AArch64 state.
+        Stride OFFSET(20) NUMBITS(2) [],
+
+        /// Flushing denormalized numbers to zero control bit on half-precision data-processing
+        /// instructions (FEAT_FP16 only).
+        FZ16 OFFSET(19) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// AArch32 only. This field has no function in AArch64 state, and non-zero values are
+        /// ignored during execution in AArch64 state.
+        Len OFFSET(16) NUMBITS(3) [],
+
+        /// Input Denormal floating-point exception trap enable.
+        IDE OFFSET(15) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Inexact floating-point exception trap enable.
+        IXE OFFSET(12) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Underflow floating-point exception trap enable.
+        UFE OFFSET(11) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Overflow floating-point exception trap enable.
+        OFE OFFSET(10) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Divide by Zero floating-point exception trap enable.
+        DZE OFFSET(9) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Invalid Operation floating-point exception trap enable.
+        IOE OFFSET(8) NUMBITS(1) [
+            Untrapped = 0,
+            Trapped = 1
+        ],
+
+        /// Controls how the output elements other than the lowest element of the vector are
+        /// determined for Advanced SIMD scalar instructions (FEAT_AFP only).
+        NEP OFFSET(2) NUMBITS(1) [
+            Zeroed = 0,
+            Preserved = 1
+        ],
+
+        /// Alternate Handling. Controls alternate handling of floating-point numbers (FEAT_AFP
+        /// only).
+        AH OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Flush Inputs to Zero. Controls whether single-precision, double-precision and
+        /// BFloat16 input operands that are denormalized numbers are flushed to zero (FEAT_AFP
+        /// only).
+        FIZ OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = FPCR::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c4_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = FPCR::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c4_0", "x");
+}
+
+pub const FPCR: Reg = Reg {};
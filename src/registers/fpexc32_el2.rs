@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Floating-Point Exception Control register - EL2
+//!
+//! Allows access to the AArch32 FPEXC register from AArch64 state only. Its value has no effect
+//! on execution in AArch64 state.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub FPEXC32_EL2 [
+        /// Exception bit. Indicates that the PE is in the asynchronous exception state of an
+        /// implementation that supports trapping of floating-point exceptions.
+        EX OFFSET(31) NUMBITS(1) [],
+
+        /// Enables access to the Advanced SIMD and floating-point functionality from all
+        /// Exception levels using AArch32, except for FPSID, MVFR0, MVFR1, MVFR2 and FPEXC.
+        ///
+        /// This bit has no effect on accesses from AArch64 state.
+        EN OFFSET(30) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Defined synchronous exception on floating-point execution. Set when an enabled
+        /// floating-point exception is taken synchronously.
+        DEX OFFSET(29) NUMBITS(1) [],
+
+        /// FPINST2 instruction valid bit. IMPLEMENTATION DEFINED.
+        FP2V OFFSET(28) NUMBITS(1) [],
+
+        /// VECITR valid bit. IMPLEMENTATION DEFINED.
+        VV OFFSET(27) NUMBITS(1) [],
+
+        /// Trapped Fault Valid bit. Indicates whether the IDF, IXF, UFF, OFF, DZF and IOF bits
+        /// record the exceptions of a trapped floating-point instruction.
+        TFV OFFSET(26) NUMBITS(1) [],
+
+        /// Vector iteration count. IMPLEMENTATION DEFINED.
+        VECITR OFFSET(8) NUMBITS(3) [],
+
+        /// Input Denormal trapped exception bit.
+        IDF OFFSET(7) NUMBITS(1) [],
+
+        /// Inexact trapped exception bit.
+        IXF OFFSET(4) NUMBITS(1) [],
+
+        /// Underflow trapped exception bit.
+        UFF OFFSET(3) NUMBITS(1) [],
+
+        /// Overflow trapped exception bit.
+        OFF OFFSET(2) NUMBITS(1) [],
+
+        /// Divide by Zero trapped exception bit.
+        DZF OFFSET(1) NUMBITS(1) [],
+
+        /// Invalid Operation trapped exception bit.
+        IOF OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = FPEXC32_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "FPEXC32_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = FPEXC32_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "FPEXC32_EL2", "x");
+}
+
+pub const FPEXC32_EL2: Reg = Reg {};
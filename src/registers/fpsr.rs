@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Floating-point Status Register
+//!
+//! Provides floating-point system status information.
+//!
+//! Accessed through its encoding `S3_3_C4_C4_1`, as assemblers only accept the name with the FP
+//! extension enabled, which soft-float targets lack.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub FPSR [
+        /// AArch32 only. Negative condition flag for AArch32 floating-point comparison
+        /// operations.
+        N OFFSET(31) NUMBITS(1) [],
+
+        /// AArch32 only. Zero condition flag for AArch32 floating-point comparison operations.
+        Z OFFSET(30) NUMBITS(1) [],
+
+        /// AArch32 only. Carry condition flag for AArch32 floating-point comparison operations.
+        C OFFSET(29) NUMBITS(1) [],
+
+        /// AArch32 only. Overflow condition flag for AArch32 floating-point comparison
+        /// operations.
+        V OFFSET(28) NUMBITS(1) [],
+
+        /// Cumulative saturation bit, Advanced SIMD only. This bit is set to 1 to indicate that
+        /// an Advanced SIMD integer operation has saturated since 0 was last written to this bit.
+        QC OFFSET(27) NUMBITS(1) [],
+
+        /// Input Denormal cumulative floating-point exception bit.
+        IDC OFFSET(7) NUMBITS(1) [],
+
+        /// Inexact cumulative floating-point exception bit.
+        IXC OFFSET(4) NUMBITS(1) [],
+
+        /// Underflow cumulative floating-point exception bit.
+        UFC OFFSET(3) NUMBITS(1) [],
+
+        /// Overflow cumulative floating-point exception bit.
+        OFC OFFSET(2) NUMBITS(1) [],
+
+        /// Divide by Zero cumulative floating-point exception bit.
+        DZC OFFSET(1) NUMBITS(1) [],
+
+        /// Invalid Operation cumulative floating-point exception bit.
+        IOC OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = FPSR::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c4_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = FPSR::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c4_1", "x");
+}
+
+pub const FPSR: Reg = Reg {};
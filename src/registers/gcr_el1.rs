@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Control Register - EL1
+//!
+//! Tag Control Register, configures the generation of Allocation Tags by the IRG, ADDG and SUBG
+//! instructions.
+//!
+//! Accessed through its encoding `S3_0_C1_C0_6`, as assemblers only accept the name with FEAT_MTE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub GCR_EL1 [
+        /// Random Allocation Tag Selection.
+        ///
+        /// 0 Generation of random Allocation Tags by the IRG instruction uses the
+        ///   RGSR_EL1.{TAG, SEED} fields.
+        /// 1 Generation of random Allocation Tags by the IRG instruction is IMPLEMENTATION
+        ///   DEFINED, and RGSR_EL1 is not updated.
+        RRND OFFSET(16) NUMBITS(1) [
+            Architected = 0,
+            ImplementationDefined = 1
+        ],
+
+        /// Allocation Tag values excluded from selection by ADDG, SUBG and IRG.
+        ///
+        /// Bit n set excludes the Allocation Tag value n.
+        Exclude OFFSET(0) NUMBITS(16) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = GCR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c1_c0_6", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = GCR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c1_c0_6", "x");
+}
+
+pub const GCR_EL1: Reg = Reg {};
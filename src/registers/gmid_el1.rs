@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Multiple tag transfer ID Register - EL1
+//!
+//! Indicates the block size that is accessed by the LDGM and STGM instructions.
+//!
+//! Accessed through its encoding `S3_1_C0_C0_4`, as assemblers only accept the name with FEAT_MTE
+//! enabled.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub GMID_EL1 [
+        /// Log2 of the block size in words. The minimum supported size is 16B (value == 2) and the
+        /// maximum is 256B (value == 6).
+        BS OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = GMID_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_1_c0_c0_4", "x");
+}
+
+pub const GMID_EL1: Reg = Reg {};
@@ -19,6 +19,63 @@ use tock_registers::{
 
 register_bitfields! {u64,
     pub HCR_EL2 [
+        /// When FEAT_TWED is implemented, the delay for trapping WFE instructions is
+        /// 2^(TWEDEL + 8) cycles.
+        TWEDEL OFFSET(60) NUMBITS(4) [],
+
+        /// When FEAT_TWED is implemented, enables the configurable delay of WFE traps.
+        TWEDEn OFFSET(59) NUMBITS(1) [],
+
+        /// When FEAT_CSV2_2 is implemented, traps EL1 and EL0 accesses to the SCXTNUM_ELx
+        /// registers and GMID_EL1 to EL2.
+        TID5 OFFSET(58) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented, Default Cacheability Tagging. When HCR_EL2.DC is set, the
+        /// default stage 1 memory type of the EL1&0 regime is Tagged.
+        DCT OFFSET(57) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented, allows access to Allocation Tags at EL1 and EL0.
+        ATA OFFSET(56) NUMBITS(1) [
+            Trap = 0,
+            Allow = 1
+        ],
+
+        /// When FEAT_EVT is implemented, traps EL1 execution of Outer Shareable TLB maintenance
+        /// instructions to EL2.
+        TTLBOS OFFSET(55) NUMBITS(1) [],
+
+        /// When FEAT_EVT is implemented, traps EL1 execution of Inner Shareable TLB maintenance
+        /// instructions to EL2.
+        TTLBIS OFFSET(54) NUMBITS(1) [],
+
+        /// When FEAT_CSV2_2 is implemented, enables access to the SCXTNUM_EL1 and SCXTNUM_EL0
+        /// registers.
+        EnSCXT OFFSET(53) NUMBITS(1) [],
+
+        /// When FEAT_EVT is implemented, traps EL1 and EL0 execution of cache maintenance
+        /// instructions to the Point of Unification to EL2.
+        TOCU OFFSET(52) NUMBITS(1) [],
+
+        /// When FEAT_AMUv1p1 is implemented, enables the virtual offsets of the Activity Monitors
+        /// event counters.
+        AMVOFFEN OFFSET(51) NUMBITS(1) [],
+
+        /// When FEAT_EVT is implemented, traps EL1 execution of `IC IALLUIS` and `IC IALLU` to
+        /// EL2.
+        TICAB OFFSET(50) NUMBITS(1) [],
+
+        /// When FEAT_EVT is implemented, traps EL1 and EL0 accesses to CCSIDR_EL1, CCSIDR2_EL1,
+        /// CLIDR_EL1 and CSSELR_EL1 to EL2.
+        TID4 OFFSET(49) NUMBITS(1) [],
+
+        /// When FEAT_RME is implemented, routes Granule Protection Faults from EL1 and EL0 to
+        /// EL2.
+        GPF OFFSET(48) NUMBITS(1) [],
+
+        /// When FEAT_RASv1p1 is implemented, enables access to the error record fault injection
+        /// registers from EL1.
+        FIEN OFFSET(47) NUMBITS(1) [],
+
         /// When FEAT_S2FWB is implemented Forced Write-back changes the combined cachability of stage1
         /// and stage2 attributes
         FWB OFFSET(46) NUMBITS(1) [
@@ -28,6 +85,22 @@ register_bitfields! {u64,
            Enabled = 1,
         ],
 
+        /// When FEAT_NV2 is implemented, enables the transformation of EL1 System register
+        /// accesses into memory accesses relative to VNCR_EL2.
+        NV2 OFFSET(45) NUMBITS(1) [],
+
+        /// When FEAT_NV is implemented, traps EL1 execution of `AT S1E0R`, `AT S1E0W`, `AT S1E1R`,
+        /// `AT S1E1W`, `AT S1E1RP` and `AT S1E1WP` to EL2.
+        AT OFFSET(44) NUMBITS(1) [],
+
+        /// When FEAT_NV is implemented, Nested Virtualization. Together with NV, traps EL1 accesses
+        /// to the EL1 System registers that a guest hypervisor uses as EL2 registers.
+        NV1 OFFSET(43) NUMBITS(1) [],
+
+        /// When FEAT_NV is implemented, Nested Virtualization. Traps EL1 execution of EL2 System
+        /// register accesses and instructions to EL2, and makes CurrentEL report EL2.
+        NV OFFSET(42) NUMBITS(1) [],
+
         /// Controls the use of instructions related to Pointer Authentication:
         ///
         ///   - In EL0, when HCR_EL2.TGE==0 or HCR_EL2.E2H==0, and the associated SCTLR_EL1.En<N><M>==1.
@@ -57,6 +130,14 @@ register_bitfields! {u64,
             DisableTrapPointerAuthKeyRegsToEl2 = 1,
         ],
 
+        /// When FEAT_TME is implemented, enables the Transactional Memory Extension instructions at
+        /// EL1 and EL0.
+        TME OFFSET(39) NUMBITS(1) [],
+
+        /// Mismatched Inner/Outer Cacheable Non-Coherency Enable, for the EL1&0 translation
+        /// regimes.
+        MIOCNCE OFFSET(38) NUMBITS(1) [],
+
         /// Route synchronous External abort exceptions to EL2.
         ///   if 0: This control does not cause exceptions to be routed from EL0 and EL1 to EL2.
         ///   if 1: Route synchronous External abort exceptions from EL0 and EL1 to EL2, when EL2 is
@@ -66,6 +147,14 @@ register_bitfields! {u64,
             EnableTrapSyncExtAbortsToEl2 = 1,
         ],
 
+        /// When FEAT_RAS is implemented, traps EL1 accesses to the RAS error record registers to
+        /// EL2.
+        TERR OFFSET(36) NUMBITS(1) [],
+
+        /// When FEAT_LOR is implemented, traps EL1 accesses to the LORegion registers to
+        /// EL2.
+        TLOR OFFSET(35) NUMBITS(1) [],
+
         /// EL2 Host. Enables a configuration where a Host Operating System is running in EL2, and
         /// the Host Operating System's applications are running in EL0.
         E2H   OFFSET(34) NUMBITS(1) [
@@ -73,6 +162,14 @@ register_bitfields! {u64,
             EnableOsAtEl2 = 1
         ],
 
+        /// Stage 2 Instruction access cacheability disable. When set, forces all stage 2
+        /// translations for instruction accesses to Normal memory to be Non-cacheable.
+        ID OFFSET(33) NUMBITS(1) [],
+
+        /// Stage 2 Data access cacheability disable. When set, forces all stage 2 translations for
+        /// data accesses and translation table walks to Normal memory to be Non-cacheable.
+        CD OFFSET(32) NUMBITS(1) [],
+
         /// Execution state control for lower Exception levels:
         ///
         /// 0 Lower levels are all AArch32.
@@ -94,6 +191,17 @@ register_bitfields! {u64,
             EL1IsAarch64 = 1
         ],
 
+        /// Trap Reads of Virtual Memory controls. Traps EL1 reads of the virtual memory control
+        /// registers to EL2.
+        TRVM OFFSET(30) NUMBITS(1) [],
+
+        /// HVC instruction disable. When set, HVC instructions are UNDEFINED at EL2 and EL1.
+        /// Only implemented if EL3 is not implemented.
+        HCD OFFSET(29) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of `DC ZVA` to EL2.
+        TDZ OFFSET(28) NUMBITS(1) [],
+
         /// Trap General Exceptions, from EL0.
         ///
         /// If enabled:
@@ -128,6 +236,55 @@ register_bitfields! {u64,
             EnableTrapGeneralExceptionsToEl2 = 1,
         ],
 
+        /// Trap Virtual Memory controls. Traps EL1 writes to the virtual memory control registers
+        /// to EL2.
+        TVM OFFSET(26) NUMBITS(1) [],
+
+        /// Traps EL1 execution of TLB maintenance instructions to EL2.
+        TTLB OFFSET(25) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of cache maintenance instructions to the Point of
+        /// Unification to EL2.
+        TPU OFFSET(24) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of cache maintenance instructions to the Point of Coherency
+        /// or the Point of Persistence to EL2.
+        TPCP OFFSET(23) NUMBITS(1) [],
+
+        /// Traps EL1 execution of data cache maintenance instructions by set/way to EL2.
+        TSW OFFSET(22) NUMBITS(1) [],
+
+        /// Traps EL1 accesses to the Auxiliary Control Register ACTLR_EL1 to EL2.
+        TACR OFFSET(21) NUMBITS(1) [],
+
+        /// Traps EL1 and EL0 accesses to the System register encodings reserved for
+        /// IMPLEMENTATION DEFINED functionality to EL2.
+        TIDCP OFFSET(20) NUMBITS(1) [],
+
+        /// Traps EL1 execution of SMC instructions to EL2.
+        TSC OFFSET(19) NUMBITS(1) [],
+
+        /// Traps EL1 and EL0 reads of the ID group 3 registers to EL2.
+        TID3 OFFSET(18) NUMBITS(1) [],
+
+        /// Traps EL1 and EL0 accesses to the ID group 2 registers, such as CTR_EL0 and
+        /// CCSIDR_EL1, to EL2.
+        TID2 OFFSET(17) NUMBITS(1) [],
+
+        /// Traps EL1 reads of the ID group 1 registers, REVIDR_EL1 and AIDR_EL1, to
+        /// EL2.
+        TID1 OFFSET(16) NUMBITS(1) [],
+
+        /// Traps EL1 and EL0 accesses to the ID group 0 registers, which only exist in AArch32, to
+        /// EL2.
+        TID0 OFFSET(15) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of WFE instructions to EL2.
+        TWE OFFSET(14) NUMBITS(1) [],
+
+        /// Traps EL0 and EL1 execution of WFI instructions to EL2.
+        TWI OFFSET(13) NUMBITS(1) [],
+
         /// Default Cacheability.
         ///
         /// 0 This control has no effect on the Non-secure EL1&0 translation regime.
@@ -158,6 +315,32 @@ register_bitfields! {u64,
         /// field behaves as 0 for all purposes other than a direct read of the value of this field.
         DC   OFFSET(12) NUMBITS(1) [],
 
+        /// Barrier Shareability upgrade. Determines the minimum shareability domain that is applied
+        /// to any barrier instruction executed from EL1 or EL0.
+        BSU OFFSET(10) NUMBITS(2) [
+            NoEffect = 0b00,
+            InnerShareable = 0b01,
+            OuterShareable = 0b10,
+            FullSystem = 0b11
+        ],
+
+        /// Force broadcast. Causes TLB maintenance, branch predictor invalidation and instruction
+        /// cache invalidation instructions executed at EL1 to be broadcast within the Inner Shareable
+        /// domain.
+        FB OFFSET(9) NUMBITS(1) [],
+
+        /// Virtual SError interrupt. Setting this bit signals a virtual SError interrupt to EL1,
+        /// when HCR_EL2.AMO is set.
+        VSE OFFSET(8) NUMBITS(1) [],
+
+        /// Virtual IRQ interrupt. Setting this bit signals a virtual IRQ interrupt to EL1, when
+        /// HCR_EL2.IMO is set.
+        VI OFFSET(7) NUMBITS(1) [],
+
+        /// Virtual FIQ interrupt. Setting this bit signals a virtual FIQ interrupt to EL1, when
+        /// HCR_EL2.FMO is set.
+        VF OFFSET(6) NUMBITS(1) [],
+
         /// Physical SError interrupt routing.
         ///   - If bit is 1 when executing at any Exception level, and EL2 is enabled in the current
         ///     Security state:
@@ -224,6 +407,10 @@ register_bitfields! {u64,
             EnableVirtualFIQ = 1,
         ],
 
+        /// Protected Table Walk. When set, a stage 1 translation table walk of the EL1&0 regime
+        /// that accesses Device memory at stage 2 generates a stage 2 Permission fault.
+        PTW OFFSET(2) NUMBITS(1) [],
+
         /// Set/Way Invalidation Override. Causes Non-secure EL1 execution of the data cache
         /// invalidate by set/way instructions to perform a data cache clean and invalidate by
         /// set/way:
@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Hypervisor Fine-Grained Instruction Trap Register - EL2
+//!
+//! Provides controls for traps of the execution of System instructions at EL1 and EL0 to EL2.
+//!
+//! Requires FEAT_FGT. Bits prefixed with `n` have negative polarity, i.e. clearing them enables the
+//! trap.
+//!
+//! Accessed through its encoding `S3_4_C1_C1_6`, as assemblers only accept the name with FEAT_FGT
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub HFGITR_EL2 [
+        /// Clear to trap EL1 and EL0 execution of BRBIALL to EL2.
+        nBRBIALL OFFSET(56) NUMBITS(1) [],
+
+        /// Clear to trap EL1 and EL0 execution of BRBINJ to EL2.
+        nBRBINJ OFFSET(55) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCVAC to EL2.
+        DCCVAC OFFSET(54) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of SVC_EL1 to EL2.
+        SVC_EL1 OFFSET(53) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of SVC_EL0 to EL2.
+        SVC_EL0 OFFSET(52) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ERET to EL2.
+        ERET OFFSET(51) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of CPPRCTX to EL2.
+        CPPRCTX OFFSET(50) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DVPRCTX to EL2.
+        DVPRCTX OFFSET(49) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of CFPRCTX to EL2.
+        CFPRCTX OFFSET(48) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAALE1 to EL2.
+        TLBIVAALE1 OFFSET(47) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVALE1 to EL2.
+        TLBIVALE1 OFFSET(46) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAAE1 to EL2.
+        TLBIVAAE1 OFFSET(45) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIASIDE1 to EL2.
+        TLBIASIDE1 OFFSET(44) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAE1 to EL2.
+        TLBIVAE1 OFFSET(43) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVMALLE1 to EL2.
+        TLBIVMALLE1 OFFSET(42) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAALE1 to EL2.
+        TLBIRVAALE1 OFFSET(41) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVALE1 to EL2.
+        TLBIRVALE1 OFFSET(40) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAAE1 to EL2.
+        TLBIRVAAE1 OFFSET(39) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAE1 to EL2.
+        TLBIRVAE1 OFFSET(38) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAALE1IS to EL2.
+        TLBIRVAALE1IS OFFSET(37) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVALE1IS to EL2.
+        TLBIRVALE1IS OFFSET(36) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAAE1IS to EL2.
+        TLBIRVAAE1IS OFFSET(35) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAE1IS to EL2.
+        TLBIRVAE1IS OFFSET(34) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAALE1IS to EL2.
+        TLBIVAALE1IS OFFSET(33) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVALE1IS to EL2.
+        TLBIVALE1IS OFFSET(32) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAAE1IS to EL2.
+        TLBIVAAE1IS OFFSET(31) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIASIDE1IS to EL2.
+        TLBIASIDE1IS OFFSET(30) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAE1IS to EL2.
+        TLBIVAE1IS OFFSET(29) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVMALLE1IS to EL2.
+        TLBIVMALLE1IS OFFSET(28) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAALE1OS to EL2.
+        TLBIRVAALE1OS OFFSET(27) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVALE1OS to EL2.
+        TLBIRVALE1OS OFFSET(26) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAAE1OS to EL2.
+        TLBIRVAAE1OS OFFSET(25) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIRVAE1OS to EL2.
+        TLBIRVAE1OS OFFSET(24) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAALE1OS to EL2.
+        TLBIVAALE1OS OFFSET(23) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVALE1OS to EL2.
+        TLBIVALE1OS OFFSET(22) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAAE1OS to EL2.
+        TLBIVAAE1OS OFFSET(21) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIASIDE1OS to EL2.
+        TLBIASIDE1OS OFFSET(20) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVAE1OS to EL2.
+        TLBIVAE1OS OFFSET(19) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of TLBIVMALLE1OS to EL2.
+        TLBIVMALLE1OS OFFSET(18) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E1WP to EL2.
+        ATS1E1WP OFFSET(17) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E1RP to EL2.
+        ATS1E1RP OFFSET(16) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E0W to EL2.
+        ATS1E0W OFFSET(15) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E0R to EL2.
+        ATS1E0R OFFSET(14) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E1W to EL2.
+        ATS1E1W OFFSET(13) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ATS1E1R to EL2.
+        ATS1E1R OFFSET(12) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCZVA to EL2.
+        DCZVA OFFSET(11) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCIVAC to EL2.
+        DCCIVAC OFFSET(10) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCVADP to EL2.
+        DCCVADP OFFSET(9) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCVAP to EL2.
+        DCCVAP OFFSET(8) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCVAU to EL2.
+        DCCVAU OFFSET(7) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCISW to EL2.
+        DCCISW OFFSET(6) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCCSW to EL2.
+        DCCSW OFFSET(5) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCISW to EL2.
+        DCISW OFFSET(4) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of DCIVAC to EL2.
+        DCIVAC OFFSET(3) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ICIVAU to EL2.
+        ICIVAU OFFSET(2) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ICIALLU to EL2.
+        ICIALLU OFFSET(1) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 execution of ICIALLUIS to EL2.
+        ICIALLUIS OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = HFGITR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "s3_4_c1_c1_6", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = HFGITR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "s3_4_c1_c1_6", "x");
+}
+
+pub const HFGITR_EL2: Reg = Reg {};
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Hypervisor Fine-Grained Read Trap Register - EL2
+//!
+//! Provides controls for traps of MRS reads of System registers at EL1 and EL0 to EL2.
+//!
+//! Requires FEAT_FGT. Bits prefixed with `n` have negative polarity, i.e. clearing them enables the
+//! trap. The `*Key` bits cover both the `Lo` and `Hi` key registers, the `n` in other names stands
+//! for all register instances.
+//!
+//! Accessed through its encoding `S3_4_C1_C1_4`, as assemblers only accept the name with FEAT_FGT
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub HFGRTR_EL2 [
+        /// Clear to trap EL1 and EL0 reads of TPIDR2_EL0 to EL2.
+        nTPIDR2_EL0 OFFSET(55) NUMBITS(1) [],
+
+        /// Clear to trap EL1 and EL0 reads of SMPRI_EL1 to EL2.
+        nSMPRI_EL1 OFFSET(54) NUMBITS(1) [],
+
+        /// Clear to trap EL1 and EL0 reads of ACCDATA_EL1 to EL2.
+        nACCDATA_EL1 OFFSET(50) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXADDR_EL1 to EL2.
+        ERXADDR_EL1 OFFSET(49) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXPFGCDN_EL1 to EL2.
+        ERXPFGCDN_EL1 OFFSET(48) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXPFGCTL_EL1 to EL2.
+        ERXPFGCTL_EL1 OFFSET(47) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXPFGF_EL1 to EL2.
+        ERXPFGF_EL1 OFFSET(46) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXMISCn_EL1 to EL2.
+        ERXMISCn_EL1 OFFSET(45) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXSTATUS_EL1 to EL2.
+        ERXSTATUS_EL1 OFFSET(44) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXCTLR_EL1 to EL2.
+        ERXCTLR_EL1 OFFSET(43) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERXFR_EL1 to EL2.
+        ERXFR_EL1 OFFSET(42) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERRSELR_EL1 to EL2.
+        ERRSELR_EL1 OFFSET(41) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ERRIDR_EL1 to EL2.
+        ERRIDR_EL1 OFFSET(40) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ICC_IGRPENn_EL1 to EL2.
+        ICC_IGRPENn_EL1 OFFSET(39) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of VBAR_EL1 to EL2.
+        VBAR_EL1 OFFSET(38) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TTBR1_EL1 to EL2.
+        TTBR1_EL1 OFFSET(37) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TTBR0_EL1 to EL2.
+        TTBR0_EL1 OFFSET(36) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TPIDR_EL0 to EL2.
+        TPIDR_EL0 OFFSET(35) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TPIDRRO_EL0 to EL2.
+        TPIDRRO_EL0 OFFSET(34) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TPIDR_EL1 to EL2.
+        TPIDR_EL1 OFFSET(33) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of TCR_EL1 to EL2.
+        TCR_EL1 OFFSET(32) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of SCXTNUM_EL0 to EL2.
+        SCXTNUM_EL0 OFFSET(31) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of SCXTNUM_EL1 to EL2.
+        SCXTNUM_EL1 OFFSET(30) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of SCTLR_EL1 to EL2.
+        SCTLR_EL1 OFFSET(29) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of REVIDR_EL1 to EL2.
+        REVIDR_EL1 OFFSET(28) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of PAR_EL1 to EL2.
+        PAR_EL1 OFFSET(27) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of MPIDR_EL1 to EL2.
+        MPIDR_EL1 OFFSET(26) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of MIDR_EL1 to EL2.
+        MIDR_EL1 OFFSET(25) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of MAIR_EL1 to EL2.
+        MAIR_EL1 OFFSET(24) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of LORSA_EL1 to EL2.
+        LORSA_EL1 OFFSET(23) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of LORN_EL1 to EL2.
+        LORN_EL1 OFFSET(22) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of LORID_EL1 to EL2.
+        LORID_EL1 OFFSET(21) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of LOREA_EL1 to EL2.
+        LOREA_EL1 OFFSET(20) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of LORC_EL1 to EL2.
+        LORC_EL1 OFFSET(19) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ISR_EL1 to EL2.
+        ISR_EL1 OFFSET(18) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of FAR_EL1 to EL2.
+        FAR_EL1 OFFSET(17) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of ESR_EL1 to EL2.
+        ESR_EL1 OFFSET(16) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of DCZID_EL0 to EL2.
+        DCZID_EL0 OFFSET(15) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CTR_EL0 to EL2.
+        CTR_EL0 OFFSET(14) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CSSELR_EL1 to EL2.
+        CSSELR_EL1 OFFSET(13) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CPACR_EL1 to EL2.
+        CPACR_EL1 OFFSET(12) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CONTEXTIDR_EL1 to EL2.
+        CONTEXTIDR_EL1 OFFSET(11) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CLIDR_EL1 to EL2.
+        CLIDR_EL1 OFFSET(10) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of CCSIDR_EL1 to EL2.
+        CCSIDR_EL1 OFFSET(9) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of APIBKey to EL2.
+        APIBKey OFFSET(8) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of APIAKey to EL2.
+        APIAKey OFFSET(7) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of APGAKey to EL2.
+        APGAKey OFFSET(6) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of APDBKey to EL2.
+        APDBKey OFFSET(5) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of APDAKey to EL2.
+        APDAKey OFFSET(4) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of AMAIR_EL1 to EL2.
+        AMAIR_EL1 OFFSET(3) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of AIDR_EL1 to EL2.
+        AIDR_EL1 OFFSET(2) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of AFSR1_EL1 to EL2.
+        AFSR1_EL1 OFFSET(1) NUMBITS(1) [],
+
+        /// Set to trap EL1 and EL0 reads of AFSR0_EL1 to EL2.
+        AFSR0_EL1 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = HFGRTR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "s3_4_c1_c1_4", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = HFGRTR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "s3_4_c1_c1_4", "x");
+}
+
+pub const HFGRTR_EL2: Reg = Reg {};
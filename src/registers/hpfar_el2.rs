@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Hypervisor IPA Fault Address Register - EL2
+//!
+//! Holds the faulting IPA for some aborts on a stage 2 translation taken to EL2.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub HPFAR_EL2 [
+        /// Faulting IPA address space.
+        NS   OFFSET(63) NUMBITS(1) [],
+
+        /// Faulting Intermediate Physical Address. Holds bits[51:12] of the faulting IPA.
+        FIPA OFFSET(4) NUMBITS(44) []
+    ]
+}
+
+pub struct Reg;
+
+impl Reg {
+    /// Returns the page-aligned faulting IPA.
+    #[inline(always)]
+    pub fn get_fault_ipa(&self) -> u64 {
+        self.read(HPFAR_EL2::FIPA) << 12
+    }
+}
+
+impl Readable for Reg {
+    type T = u64;
+    type R = HPFAR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "HPFAR_EL2", "x");
+}
+
+pub const HPFAR_EL2: Reg = Reg {};
@@ -0,0 +1,94 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Hypervisor System Trap Register - EL2
+//!
+//! Controls trapping to EL2 of EL1 or lower AArch32 accesses to the System register in the
+//! coproc == 0b1111 encoding space, by the CRn value used to access the register.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub HSTR_EL2 [
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c15 to EL2.
+        T15 OFFSET(15) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c13 to EL2.
+        T13 OFFSET(13) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c12 to EL2.
+        T12 OFFSET(12) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c11 to EL2.
+        T11 OFFSET(11) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c10 to EL2.
+        T10 OFFSET(10) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c9 to EL2.
+        T9 OFFSET(9) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c8 to EL2.
+        T8 OFFSET(8) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c7 to EL2.
+        T7 OFFSET(7) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c6 to EL2.
+        T6 OFFSET(6) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c5 to EL2.
+        T5 OFFSET(5) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c3 to EL2.
+        T3 OFFSET(3) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c2 to EL2.
+        T2 OFFSET(2) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c1 to EL2.
+        T1 OFFSET(1) NUMBITS(1) [],
+
+        /// Trap EL1 and EL0 AArch32 accesses to the System registers in the coproc == 0b1111
+        /// encoding space with CRn == c0 to EL2.
+        T0 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = HSTR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "HSTR_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = HSTR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "HSTR_EL2", "x");
+}
+
+pub const HSTR_EL2: Reg = Reg {};
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Debug Feature Register 0 - EL1
+//!
+//! Provides top level information about the debug system in AArch64 state.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64DFR0_EL1 [
+        /// Indicates support for the Multi-threaded PMU extension.
+        ///
+        /// 0000 FEAT_MTPMU not implemented.
+        /// 0001 FEAT_MTPMU and FEAT_PMUv3 implemented.
+        /// 1111 FEAT_MTPMU not implemented. `PMEVTYPER<n>_EL0.MT` are RES0.
+        MTPMU OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001,
+            NotImplementedRes0 = 0b1111
+        ],
+
+        /// Trace Filter. Indicates support for the Trace Filter Control.
+        TraceFilt OFFSET(40) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// OS Double Lock implemented.
+        ///
+        /// 0000 OS Double Lock implemented. `OSDLR_EL1` is RW.
+        /// 1111 OS Double Lock not implemented. `OSDLR_EL1` is RAZ/WI.
+        DoubleLock OFFSET(36) NUMBITS(4) [
+            Implemented = 0b0000,
+            NotImplemented = 0b1111
+        ],
+
+        /// Statistical Profiling Extension version.
+        PMSVer OFFSET(32) NUMBITS(4) [],
+
+        /// Number of breakpoints that are context-aware, minus 1. These are the highest numbered
+        /// breakpoints.
+        CTX_CMPs OFFSET(28) NUMBITS(4) [],
+
+        /// Number of watchpoints, minus 1. The value of 0b0000 is reserved.
+        WRPs OFFSET(20) NUMBITS(4) [],
+
+        /// Number of breakpoints, minus 1. The value of 0b0000 is reserved.
+        BRPs OFFSET(12) NUMBITS(4) [],
+
+        /// Performance Monitors Extension version.
+        ///
+        /// 0000 Performance Monitors Extension not implemented.
+        /// 0001 PMUv3 implemented.
+        /// 0100 PMUv3 for ARMv8.1 implemented.
+        /// 0101 PMUv3 for ARMv8.4 implemented.
+        /// 0110 PMUv3 for ARMv8.5 implemented.
+        /// 0111 PMUv3 for ARMv8.7 implemented.
+        /// 1111 IMPLEMENTATION DEFINED form of performance monitors supported.
+        PMUVer OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PMUv3 = 0b0001,
+            PMUv3p1 = 0b0100,
+            PMUv3p4 = 0b0101,
+            PMUv3p5 = 0b0110,
+            PMUv3p7 = 0b0111,
+            ImplementationDefined = 0b1111
+        ],
+
+        /// Trace support. Indicates whether System register interface to a PE trace unit is
+        /// implemented.
+        TraceVer OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Debug architecture version. Indicates presence of Armv8 debug architecture.
+        DebugVer OFFSET(0) NUMBITS(4) [
+            Armv8 = 0b0110,
+            Armv8_VHE = 0b0111,
+            Armv8p2 = 0b1000,
+            Armv8p4 = 0b1001,
+            Armv8p8 = 0b1010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64DFR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64DFR0_EL1", "x");
+}
+
+pub const ID_AA64DFR0_EL1: Reg = Reg {};
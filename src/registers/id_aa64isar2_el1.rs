@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Instruction Set Attribute Register 2 - EL1
+//!
+//! Provides information about the features and instructions implemented in AArch64 state.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64ISAR2_EL1 [
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for address
+        /// authentication in AArch64 state.
+        ///
+        /// 0000 Address Authentication using the QARMA3 algorithm is not implemented.
+        /// 0001 Address Authentication using the QARMA3 algorithm is implemented, with the
+        ///      HaveEnhancedPAC() and HaveEnhancedPAC2() functions returning FALSE.
+        /// 0010 As 0001, with HaveEnhancedPAC() returning TRUE.
+        /// 0011 As 0001, with HaveEnhancedPAC2() returning TRUE.
+        /// 0100 As 0011, with FEAT_FPAC implemented.
+        /// 0101 As 0100, with FEAT_FPACCOMBINE implemented.
+        ///
+        /// All other values are reserved.
+        APA3 OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PAuth = 0b0001,
+            EPAC = 0b0010,
+            PAuth2 = 0b0011,
+            FPAC = 0b0100,
+            FPACCOMBINE = 0b0101
+        ],
+
+        /// Indicates whether the QARMA3 algorithm is implemented in the PE for generic code
+        /// authentication in AArch64 state.
+        ///
+        /// 0000 Generic Authentication using the QARMA3 algorithm is not implemented.
+        /// 0001 Generic Authentication using the QARMA3 algorithm is implemented. This includes
+        ///      the PACGA instruction.
+        ///
+        /// All other values are reserved.
+        GPA3 OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Indicates support for WFET and WFIT instructions in AArch64 state.
+        WFxT OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64ISAR2_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c0_c6_2", "x");
+}
+
+pub const ID_AA64ISAR2_EL1: Reg = Reg {};
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Processor Feature Register 0 - EL1
+//!
+//! Provides additional information about implemented PE features in AArch64 state.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64PFR0_EL1 [
+        /// Speculative use of faulting data.
+        CSV3 OFFSET(60) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Speculative use of out of context branch targets.
+        CSV2 OFFSET(56) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001,
+            SCXTNUM = 0b0010,
+            SCXTNUM_CSV2_3 = 0b0011
+        ],
+
+        /// Data Independent Timing. Indicates support for PSTATE.DIT.
+        DIT OFFSET(48) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Activity Monitors Extension support.
+        AMU OFFSET(44) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AMUv1 = 0b0001,
+            AMUv1p1 = 0b0010
+        ],
+
+        /// Secure EL2 support.
+        SEL2 OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Scalable Vector Extension support.
+        SVE OFFSET(32) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// RAS Extension version.
+        RAS OFFSET(28) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            RAS = 0b0001,
+            RASv1p1 = 0b0010
+        ],
+
+        /// System register GIC CPU interface support.
+        GIC OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            GICv3 = 0b0001,
+            GICv4p1 = 0b0011
+        ],
+
+        /// Advanced SIMD support.
+        AdvSIMD OFFSET(20) NUMBITS(4) [
+            Implemented = 0b0000,
+            ImplementedHalfPrecision = 0b0001,
+            NotImplemented = 0b1111
+        ],
+
+        /// Floating-point support.
+        FP OFFSET(16) NUMBITS(4) [
+            Implemented = 0b0000,
+            ImplementedHalfPrecision = 0b0001,
+            NotImplemented = 0b1111
+        ],
+
+        /// EL3 Exception level handling.
+        EL3 OFFSET(12) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AArch64 = 0b0001,
+            AArch64AndAArch32 = 0b0010
+        ],
+
+        /// EL2 Exception level handling.
+        EL2 OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            AArch64 = 0b0001,
+            AArch64AndAArch32 = 0b0010
+        ],
+
+        /// EL1 Exception level handling.
+        EL1 OFFSET(4) NUMBITS(4) [
+            AArch64 = 0b0001,
+            AArch64AndAArch32 = 0b0010
+        ],
+
+        /// EL0 Exception level handling.
+        EL0 OFFSET(0) NUMBITS(4) [
+            AArch64 = 0b0001,
+            AArch64AndAArch32 = 0b0010
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64PFR0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64PFR0_EL1", "x");
+}
+
+pub const ID_AA64PFR0_EL1: Reg = Reg {};
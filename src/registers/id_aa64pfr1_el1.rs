@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! AArch64 Processor Feature Register 1 - EL1
+//!
+//! Provides additional information about implemented PE features in AArch64 state.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub ID_AA64PFR1_EL1 [
+        /// Non-maskable Interrupt. Indicates support for Non-maskable interrupts.
+        NMI OFFSET(36) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ],
+
+        /// Scalable Matrix Extension. Indicates support for SME.
+        SME OFFSET(24) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            SME = 0b0001,
+            SME2 = 0b0010
+        ],
+
+        /// Support for the Memory Tagging Extension.
+        ///
+        /// 0000 Memory Tagging Extension is not implemented.
+        /// 0001 Instruction-only Memory Tagging Extension is implemented.
+        /// 0010 Full Memory Tagging Extension is implemented.
+        /// 0011 Memory Tagging Extension is implemented with support for asymmetric Tag Check
+        ///      Fault handling.
+        ///
+        /// All other values are reserved.
+        MTE OFFSET(8) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            InstructionsOnly = 0b0001,
+            Full = 0b0010,
+            Asymmetric = 0b0011
+        ],
+
+        /// Speculative Store Bypassing controls in AArch64 state.
+        ///
+        /// 0000 AArch64 provides no mechanism to control the use of Speculative Store Bypassing.
+        /// 0001 AArch64 provides the PSTATE.SSBS mechanism to mark regions that are Speculative
+        ///      Store Bypass Safe.
+        /// 0010 As 0001, and adds the MSR and MRS instructions to directly read and write the
+        ///      PSTATE.SSBS field.
+        ///
+        /// All other values are reserved.
+        SSBS OFFSET(4) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            PSTATE = 0b0001,
+            MSR_MRS = 0b0010
+        ],
+
+        /// Branch Target Identification mechanism support in AArch64 state.
+        BT OFFSET(0) NUMBITS(4) [
+            NotImplemented = 0b0000,
+            Implemented = 0b0001
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ID_AA64PFR1_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "ID_AA64PFR1_EL1", "x");
+}
+
+pub const ID_AA64PFR1_EL1: Reg = Reg {};
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Instruction Fault Status Register - EL2
+//!
+//! Allows access to the AArch32 IFSR register from AArch64 state only. Its value has no effect on
+//! execution in AArch64 state.
+//!
+//! The layout of the register depends on the translation table format in use, as reported by the
+//! LPAE bit. The short-descriptor format splits the fault status between FS_HI and FS_LO.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub IFSR32_EL2 [
+        /// FAR not Valid, for a synchronous External abort other than one on a translation table
+        /// walk. When set, IFAR does not hold the faulting address.
+        FnV OFFSET(16) NUMBITS(1) [],
+
+        /// External abort type. IMPLEMENTATION DEFINED classification of External aborts.
+        ExT OFFSET(12) NUMBITS(1) [],
+
+        /// Bit \[4\] of the fault status. Short-descriptor format only.
+        FS_HI OFFSET(10) NUMBITS(1) [],
+
+        /// Translation table format in use when the exception was taken.
+        LPAE OFFSET(9) NUMBITS(1) [
+            ShortDescriptor = 0,
+            LongDescriptor = 1
+        ],
+
+        /// Fault status. Long-descriptor format only.
+        STATUS OFFSET(0) NUMBITS(6) [],
+
+        /// Bits \[3:0\] of the fault status. Short-descriptor format only.
+        FS_LO OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = IFSR32_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "IFSR32_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = IFSR32_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "IFSR32_EL2", "x");
+}
+
+pub const IFSR32_EL2: Reg = Reg {};
@@ -74,3 +74,95 @@ macro_rules! write_raw {
         __write_raw!($width, "mov", $asm_reg_name, $asm_width);
     };
 }
+
+/// Raw read from 32-bit AArch32 system registers in the CP15 coprocessor space, zero-extended to
+/// `$width`.
+macro_rules! cp15_read_raw {
+    ($width:ty, $opc1:literal, $crn:ident, $crm:ident, $opc2:literal) => {
+        /// Reads the raw bits of the CPU register.
+        #[inline]
+        fn get(&self) -> $width {
+            match () {
+                #[cfg(target_arch = "arm")]
+                () => {
+                    let reg: u32;
+                    unsafe {
+                        core::arch::asm!(concat!("mrc p15, ", $opc1, ", {reg}, ", stringify!($crn), ", ", stringify!($crm), ", ", $opc2), reg = out(reg) reg, options(nomem, nostack));
+                    }
+                    reg as $width
+                }
+
+                #[cfg(not(target_arch = "arm"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Raw write to 32-bit AArch32 system registers in the CP15 coprocessor space, truncated from
+/// `$width`.
+macro_rules! cp15_write_raw {
+    ($width:ty, $opc1:literal, $crn:ident, $crm:ident, $opc2:literal) => {
+        /// Writes raw bits to the CPU register.
+        #[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
+        #[inline]
+        fn set(&self, value: $width) {
+            match () {
+                #[cfg(target_arch = "arm")]
+                () => {
+                    unsafe {
+                        core::arch::asm!(concat!("mcr p15, ", $opc1, ", {reg}, ", stringify!($crn), ", ", stringify!($crm), ", ", $opc2), reg = in(reg) value as u32, options(nomem, nostack))
+                    }
+                }
+
+                #[cfg(not(target_arch = "arm"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Raw read from 64-bit AArch32 system registers in the CP15 coprocessor space.
+macro_rules! cp15_read_raw64 {
+    ($opc1:literal, $crm:ident) => {
+        /// Reads the raw bits of the CPU register.
+        #[inline]
+        fn get(&self) -> u64 {
+            match () {
+                #[cfg(target_arch = "arm")]
+                () => {
+                    let (lo, hi): (u32, u32);
+                    unsafe {
+                        core::arch::asm!(concat!("mrrc p15, ", $opc1, ", {lo}, {hi}, ", stringify!($crm)), lo = out(reg) lo, hi = out(reg) hi, options(nomem, nostack));
+                    }
+                    ((hi as u64) << 32) | lo as u64
+                }
+
+                #[cfg(not(target_arch = "arm"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Raw write to 64-bit AArch32 system registers in the CP15 coprocessor space.
+macro_rules! cp15_write_raw64 {
+    ($opc1:literal, $crm:ident) => {
+        /// Writes raw bits to the CPU register.
+        #[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
+        #[inline]
+        fn set(&self, value: u64) {
+            match () {
+                #[cfg(target_arch = "arm")]
+                () => {
+                    unsafe {
+                        core::arch::asm!(concat!("mcrr p15, ", $opc1, ", {lo}, {hi}, ", stringify!($crm)), lo = in(reg) value as u32, hi = in(reg) (value >> 32) as u32, options(nomem, nostack))
+                    }
+                }
+
+                #[cfg(not(target_arch = "arm"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
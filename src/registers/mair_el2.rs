@@ -48,6 +48,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr7_Normal_Tagged OFFSET(56) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr7_Normal_Inner OFFSET(56) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -99,6 +104,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr6_Normal_Tagged OFFSET(48) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr6_Normal_Inner OFFSET(48) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -150,6 +160,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr5_Normal_Tagged OFFSET(40) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr5_Normal_Inner OFFSET(40) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -201,6 +216,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr4_Normal_Tagged OFFSET(32) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr4_Normal_Inner OFFSET(32) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -252,6 +272,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr3_Normal_Tagged OFFSET(24) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr3_Normal_Inner OFFSET(24) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -303,6 +328,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr2_Normal_Tagged OFFSET(16) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr2_Normal_Inner OFFSET(16) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -354,6 +384,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr1_Normal_Tagged OFFSET(8) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr1_Normal_Inner OFFSET(8) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -405,6 +440,11 @@ register_bitfields! {u64,
             nonGathering_Reordering_EarlyWriteAck = 0b0000_1000,
             Gathering_Reordering_EarlyWriteAck = 0b0000_1100
         ],
+        Attr0_Normal_Tagged OFFSET(0) NUMBITS(8) [
+            /// Tagged Normal Memory. Inner+Outer Write-back non-transient memory, Inner+Outer
+            /// Read-Allocate, Inner+Outer Write-Allocate (FEAT_MTE2 only).
+            WriteBack_NonTransient_ReadWriteAlloc = 0b1111_0000
+        ],
         Attr0_Normal_Inner OFFSET(0) NUMBITS(4) [
             WriteThrough_Transient = 0x0000,
             WriteThrough_Transient_WriteAlloc = 0x0001,
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Monitor DCC Status Register - EL0
+//!
+//! Main control register for the Debug Communications Channel (DCC).
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub MDCCSR_EL0 [
+        /// DTRRX full. Read-only view of the equivalent bit in the `EDSCR`.
+        RXfull OFFSET(30) NUMBITS(1) [
+            NotFull = 0,
+            Full = 1
+        ],
+
+        /// DTRTX full. Read-only view of the equivalent bit in the `EDSCR`.
+        TXfull OFFSET(29) NUMBITS(1) [
+            NotFull = 0,
+            Full = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = MDCCSR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "MDCCSR_EL0", "x");
+}
+
+pub const MDCCSR_EL0: Reg = Reg {};
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Monitor Debug Configuration Register - EL2
+//!
+//! Provides EL2 configuration options for self-hosted debug and the Performance Monitors
+//! Extension.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub MDCR_EL2 [
+        /// When FEAT_PMUv3p7 is implemented, Hyp Performance Monitors Freeze-on-overflow. Stops
+        /// event counters reserved for EL2 on overflow.
+        HPMFZO OFFSET(29) NUMBITS(1) [],
+
+        /// When FEAT_MTPMU is implemented, Multi-threaded PMU Enable.
+        MTPME OFFSET(28) NUMBITS(1) [],
+
+        /// When FEAT_FGT is implemented, trap DCC register accesses at EL1 and EL0 to EL2.
+        TDCC OFFSET(27) NUMBITS(1) [],
+
+        /// When FEAT_PMUv3p5 is implemented, Hypervisor Long event counter enable. Makes the
+        /// event counters reserved for EL2 64 bits wide.
+        HLP OFFSET(26) NUMBITS(1) [],
+
+        /// When FEAT_TRBE is implemented, EL2 Trace Buffer. Controls the owning translation regime
+        /// and accesses to the Trace Buffer control registers.
+        E2TB OFFSET(24) NUMBITS(2) [],
+
+        /// When FEAT_PMUv3p5 is implemented, Hypervisor Cycle Counter Disable. Prohibits the cycle
+        /// counter from counting at EL2.
+        HCCD OFFSET(23) NUMBITS(1) [],
+
+        /// When FEAT_TRF is implemented, trap accesses to TRFCR_EL1 at EL1 to EL2.
+        TTRF OFFSET(19) NUMBITS(1) [],
+
+        /// When FEAT_PMUv3p1 is implemented, Hypervisor Performance Monitors Disable. Prohibits
+        /// event counting at EL2.
+        HPMD OFFSET(17) NUMBITS(1) [],
+
+        /// When FEAT_SPE is implemented, trap Profiling Buffer and Statistical Profiling control
+        /// register accesses at EL1 to EL2.
+        TPMS OFFSET(14) NUMBITS(1) [],
+
+        /// When FEAT_SPE is implemented, EL2 Profiling Buffer. Controls the owning translation
+        /// regime and accesses to the Statistical Profiling and Profiling Buffer control registers.
+        E2PB OFFSET(12) NUMBITS(2) [],
+
+        /// Trap Debug ROM Address register access. Traps EL1 and EL0 accesses to MDRAR_EL1 to EL2.
+        TDRA OFFSET(11) NUMBITS(1) [],
+
+        /// Trap debug OS-related register access. Traps EL1 and EL0 accesses to the OS lock and
+        /// power-down registers to EL2.
+        TDOSA OFFSET(10) NUMBITS(1) [],
+
+        /// Trap Debug Access. Traps EL1 and EL0 accesses to the debug System registers to EL2.
+        TDA OFFSET(9) NUMBITS(1) [],
+
+        /// Trap Debug Exceptions. Routes debug exceptions from EL1 and EL0 to EL2.
+        TDE OFFSET(8) NUMBITS(1) [],
+
+        /// EL2 Performance Monitors Enable. Enables the event counters reserved for EL2.
+        HPME OFFSET(7) NUMBITS(1) [],
+
+        /// Trap Performance Monitors accesses. Traps EL1 and EL0 accesses to the Performance
+        /// Monitors registers to EL2.
+        TPM OFFSET(6) NUMBITS(1) [],
+
+        /// Trap PMCR_EL0 accesses. Traps EL1 and EL0 accesses to PMCR_EL0 to EL2.
+        TPMCR OFFSET(5) NUMBITS(1) [],
+
+        /// Defines the number of event counters that are accessible from EL1 and from EL0 if
+        /// permitted. The remaining counters are reserved for EL2.
+        HPMN OFFSET(0) NUMBITS(5) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = MDCR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "MDCR_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = MDCR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "MDCR_EL2", "x");
+}
+
+pub const MDCR_EL2: Reg = Reg {};
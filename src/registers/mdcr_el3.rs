@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Monitor Debug Configuration Register - EL3
+//!
+//! Provides EL3 configuration options for self-hosted debug and the Performance Monitors
+//! Extension.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub MDCR_EL3 [
+        /// When FEAT_PMUv3p7 is implemented, enables Non-secure EL2 and EL3 to use the
+        /// Statistical Profiling Extension from the Realm and Secure states.
+        EnPMSN OFFSET(36) NUMBITS(1) [],
+
+        /// When FEAT_PMUv3p7 is implemented, Monitor Performance Monitors Extension. Prohibits
+        /// event counting at EL3 by event counters that are not reserved for EL2.
+        MPMX OFFSET(35) NUMBITS(1) [],
+
+        /// When FEAT_PMUv3p7 is implemented, Monitor Cycle Counter Disable. Prohibits the cycle
+        /// counter from counting at EL3.
+        MCCD OFFSET(34) NUMBITS(1) [],
+
+        /// When FEAT_MTPMU is implemented, Multi-threaded PMU Enable.
+        MTPME OFFSET(28) NUMBITS(1) [],
+
+        /// When FEAT_FGT is implemented, trap DCC register accesses at EL2, EL1 and EL0 to EL3.
+        TDCC OFFSET(27) NUMBITS(1) [],
+
+        /// When FEAT_TRBE is implemented, Non-secure Trace Buffer. Controls the owning
+        /// translation regime and accesses to the Trace Buffer control registers.
+        NSTB OFFSET(24) NUMBITS(2) [],
+
+        /// When FEAT_PMUv3p5 is implemented, Secure Cycle Counter Disable. Prohibits the cycle
+        /// counter from counting in Secure state.
+        SCCD OFFSET(23) NUMBITS(1) [],
+
+        /// When FEAT_Debugv8p4 is implemented, trap Non-secure accesses to the Performance
+        /// Monitors registers through the external debug interface.
+        EPMAD OFFSET(21) NUMBITS(1) [],
+
+        /// When FEAT_Debugv8p4 is implemented, trap Non-secure accesses to the breakpoint and
+        /// watchpoint registers through the external debug interface.
+        EDAD OFFSET(20) NUMBITS(1) [],
+
+        /// When FEAT_TRF is implemented, trap accesses to the trace filter control registers
+        /// at EL2 and EL1 to EL3.
+        TTRF OFFSET(19) NUMBITS(1) [],
+
+        /// When FEAT_TRF is implemented, Secure Trace enable.
+        STE OFFSET(18) NUMBITS(1) [],
+
+        /// Secure Performance Monitors Enable. Allows event counting in Secure state.
+        SPME OFFSET(17) NUMBITS(1) [],
+
+        /// AArch64 Secure Self-hosted invasive debug Disable.
+        ///
+        /// 0 Debug exceptions in Secure state, other than Breakpoint Instruction exceptions,
+        ///   are enabled.
+        /// 1 Debug exceptions in Secure state, other than Breakpoint Instruction exceptions,
+        ///   are disabled.
+        SDD OFFSET(16) NUMBITS(1) [],
+
+        /// AArch32 Secure self-hosted privileged debug.
+        SPD32 OFFSET(14) NUMBITS(2) [
+            Legacy = 0b00,
+            DisableSecurePrivilegedDebug = 0b10,
+            EnableSecurePrivilegedDebug = 0b11
+        ],
+
+        /// When FEAT_SPE is implemented, Non-secure Profiling Buffer. Controls the owning
+        /// translation regime and accesses to the Statistical Profiling and Profiling Buffer
+        /// control registers.
+        NSPB OFFSET(12) NUMBITS(2) [],
+
+        /// Trap debug OS-related register accesses at EL2, EL1 and EL0 to EL3.
+        TDOSA OFFSET(10) NUMBITS(1) [],
+
+        /// Trap accesses to debug System registers at EL2, EL1 and EL0 to EL3.
+        TDA OFFSET(9) NUMBITS(1) [],
+
+        /// Trap accesses to Performance Monitor registers at EL2, EL1 and EL0 to EL3.
+        TPM OFFSET(6) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = MDCR_EL3::Register;
+
+    sys_coproc_read_raw!(u64, "MDCR_EL3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = MDCR_EL3::Register;
+
+    sys_coproc_write_raw!(u64, "MDCR_EL3", "x");
+}
+
+pub const MDCR_EL3: Reg = Reg {};
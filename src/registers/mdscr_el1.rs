@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Monitor Debug System Control Register - EL1
+//!
+//! Main control register for the debug implementation.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub MDSCR_EL1 [
+        /// Saved value of `EDSCR.RXfull`. The value of this bit is normally UNKNOWN.
+        RXfull OFFSET(30) NUMBITS(1) [],
+
+        /// Saved value of `EDSCR.TXfull`. The value of this bit is normally UNKNOWN.
+        TXfull OFFSET(29) NUMBITS(1) [],
+
+        /// Saved value of `EDSCR.RXO`. The value of this bit is normally UNKNOWN.
+        RXO OFFSET(27) NUMBITS(1) [],
+
+        /// Saved value of `EDSCR.TXU`. The value of this bit is normally UNKNOWN.
+        TXU OFFSET(26) NUMBITS(1) [],
+
+        /// Saved value of `EDSCR.INTdis`. The value of this field is normally UNKNOWN.
+        INTdis OFFSET(22) NUMBITS(2) [],
+
+        /// Saved value of `EDSCR.TDA`. The value of this bit is normally UNKNOWN.
+        TDA OFFSET(21) NUMBITS(1) [],
+
+        /// Monitor debug events. Enable Breakpoint, Watchpoint, and Vector Catch exceptions.
+        ///
+        /// 0 Breakpoint, Watchpoint, and Vector Catch exceptions disabled.
+        /// 1 Breakpoint, Watchpoint, and Vector Catch exceptions enabled.
+        MDE OFFSET(15) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Used for save/restore of `EDSCR.HDE`.
+        HDE OFFSET(14) NUMBITS(1) [],
+
+        /// Local (kernel) debug enable. If EL_D is using AArch64, enable debug exceptions within
+        /// EL_D.
+        ///
+        /// 0 Debug exceptions, other than Breakpoint Instruction exceptions, disabled within
+        ///   EL_D.
+        /// 1 All debug exceptions enabled within EL_D.
+        KDE OFFSET(13) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Traps EL0 accesses to the Debug Communication Channel (DCC) registers to EL1.
+        ///
+        /// 0 This control does not cause any instructions to be trapped.
+        /// 1 EL0 using AArch64: EL0 accesses to the `MDCCSR_EL0`, `DBGDTR_EL0`, `DBGDTRTX_EL0`,
+        ///   and `DBGDTRRX_EL0` registers are trapped to EL1.
+        TDCC OFFSET(12) NUMBITS(1) [
+            DontTrap = 0,
+            Trap = 1
+        ],
+
+        /// Used for save/restore of `EDSCR.ERR`.
+        ERR OFFSET(6) NUMBITS(1) [],
+
+        /// Software step control bit. If EL_D is using AArch64, enable Software step.
+        ///
+        /// 0 Software step disabled.
+        /// 1 Software step enabled.
+        SS OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = MDSCR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "MDSCR_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = MDSCR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "MDSCR_EL1", "x");
+}
+
+pub const MDSCR_EL1: Reg = Reg {};
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! OS Double Lock Register - EL1
+//!
+//! Used to control the OS Double Lock.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub OSDLR_EL1 [
+        /// OS Double Lock control bit.
+        ///
+        /// 0 OS Double Lock unlocked.
+        /// 1 OS Double Lock locked, if `DBGPRCR_EL1.CORENPDRQ` (Core no powerdown request) bit is
+        ///   set to 0 and the PE is in Non-debug state.
+        DLK OFFSET(0) NUMBITS(1) [
+            Unlocked = 0,
+            Locked = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = OSDLR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "OSDLR_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = OSDLR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "OSDLR_EL1", "x");
+}
+
+pub const OSDLR_EL1: Reg = Reg {};
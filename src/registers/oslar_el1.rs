@@ -14,7 +14,7 @@
 //! register `OSLAR_EL1[31:0]`. The OS Lock can also be locked or unlocked using `DBGOSLAR`.
 
 use tock_registers::{
-    interfaces::{Readable, Writeable},
+    interfaces::Writeable,
     register_bitfields,
 };
 
@@ -31,13 +31,6 @@ register_bitfields! {u64,
 
 pub struct Reg;
 
-impl Readable for Reg {
-    type T = u64;
-    type R = OSLAR_EL1::Register;
-
-    sys_coproc_read_raw!(u64, "OSLAR_EL1", "x");
-}
-
 impl Writeable for Reg {
     type T = u64;
     type R = OSLAR_EL1::Register;
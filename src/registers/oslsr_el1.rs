@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! OS Lock Status Register - EL1
+//!
+//! Provides the status of the OS Lock.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub OSLSR_EL1 [
+        /// OS Lock model implemented. Identifies the form of OS Save and Restore mechanism
+        /// implemented. Bit[3] of this field, OSLM[1], is at bit 3 of the register.
+        ///
+        /// 10 OS Lock implemented. DBGOSSRR not implemented.
+        ///
+        /// All other values are reserved.
+        OSLM1 OFFSET(3) NUMBITS(1) [],
+
+        /// Not 32-bit access. This bit is always RAZ.
+        nTT OFFSET(2) NUMBITS(1) [],
+
+        /// OS Lock Status.
+        ///
+        /// 0 OS Lock unlocked.
+        /// 1 OS Lock locked.
+        OSLK OFFSET(1) NUMBITS(1) [
+            Unlocked = 0,
+            Locked = 1
+        ],
+
+        /// OS Lock model implemented. Bit[0] of this field, OSLM[0], is at bit 0 of the register.
+        OSLM0 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = OSLSR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "OSLSR_EL1", "x");
+}
+
+pub const OSLSR_EL1: Reg = Reg {};
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Privileged Access Never
+//!
+//! Allows access to the Privileged Access Never bit.
+//!
+//! Accessed through its encoding `S3_0_C4_C2_3`, as assemblers only accept the name with FEAT_PAN
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PAN [
+        /// Privileged Access Never.
+        ///
+        /// 0 Privileged data accesses to memory that is accessible at EL0 are permitted.
+        /// 1 Privileged data accesses to memory that is accessible at EL0 generate a Permission
+        ///   fault.
+        PAN OFFSET(22) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PAN::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c4_c2_3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PAN::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c4_c2_3", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.PAN using the `MSR PAN, #<imm>` form, which is the
+    /// recommended way to toggle it around accesses to user memory.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".arch_extension pan", "msr pan, #1", options(nostack))
+                } else {
+                    core::arch::asm!(".arch_extension pan", "msr pan, #0", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const PAN: Reg = Reg {};
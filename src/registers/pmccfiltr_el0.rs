@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Cycle Count Filter Register - EL0
+//!
+//! Determines the modes in which the Cycle Counter, `PMCCNTR_EL0`, increments.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMCCFILTR_EL0 [
+        /// Privileged filtering bit. Controls counting in EL1.
+        ///
+        /// 0 Count events in EL1.
+        /// 1 Do not count events in EL1.
+        P OFFSET(31) NUMBITS(1) [
+            Count = 0,
+            DontCount = 1
+        ],
+
+        /// User filtering bit. Controls counting in EL0.
+        ///
+        /// 0 Count events in EL0.
+        /// 1 Do not count events in EL0.
+        U OFFSET(30) NUMBITS(1) [
+            Count = 0,
+            DontCount = 1
+        ],
+
+        /// Non-secure EL1 (kernel) modes filtering bit. Controls counting in Non-secure EL1.
+        ///
+        /// If EL3 is implemented, then counting in Non-secure EL1 is enabled if NSK is equal to P,
+        /// and is disabled otherwise.
+        NSK OFFSET(29) NUMBITS(1) [],
+
+        /// Non-secure EL0 (Unprivileged) filtering bit. Controls counting in Non-secure EL0.
+        ///
+        /// If EL3 is implemented, then counting in Non-secure EL0 is enabled if NSU is equal to U,
+        /// and is disabled otherwise.
+        NSU OFFSET(28) NUMBITS(1) [],
+
+        /// EL2 (Hypervisor) filtering bit. Controls counting in EL2.
+        ///
+        /// 0 Do not count events in EL2.
+        /// 1 Count events in EL2.
+        NSH OFFSET(27) NUMBITS(1) [
+            DontCount = 0,
+            Count = 1
+        ],
+
+        /// EL3 filtering bit.
+        ///
+        /// If EL3 is implemented, then counting in EL3 is enabled if M is equal to P, and is
+        /// disabled otherwise.
+        M OFFSET(26) NUMBITS(1) [],
+
+        /// Secure EL2 filtering.
+        ///
+        /// If Secure EL2 is implemented, counting in Secure EL2 is enabled if SH is not equal to
+        /// NSH, and is disabled otherwise.
+        ///
+        /// When FEAT_SEL2 is not implemented, this bit is RES0.
+        SH OFFSET(24) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCCFILTR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCCFILTR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCCFILTR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCCFILTR_EL0", "x");
+}
+
+pub const PMCCFILTR_EL0: Reg = Reg {};
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Common Event Identification register 0 - EL0
+//!
+//! Defines which common architectural events and common microarchitectural events are
+//! implemented, or counted, using PMU events in the ranges 0x0000-0x001F and 0x4000-0x401F.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub PMCEID0_EL0 [
+        /// Each bit in this field indicates whether the common event with event number
+        /// 0x4000 + n is implemented, where n is the bit number.
+        ///
+        /// 0 The common event is not implemented, or not counted.
+        /// 1 The common event is implemented.
+        IDhi OFFSET(32) NUMBITS(32) [],
+
+        /// Each bit in this field indicates whether the common event with event number
+        /// 0x0000 + n is implemented, where n is the bit number.
+        ///
+        /// 0 The common event is not implemented, or not counted.
+        /// 1 The common event is implemented.
+        ID OFFSET(0) NUMBITS(32) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCEID0_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCEID0_EL0", "x");
+}
+
+pub const PMCEID0_EL0: Reg = Reg {};
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Control Register - EL0
+//!
+//! Provides details of the Performance Monitors implementation, including the number of counters
+//! implemented, and configures and controls the counters.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMCR_EL0 [
+        /// Implementer code.
+        ///
+        /// This field is RO and deprecated. Software should use `MIDR_EL1` to identify the PE.
+        IMP OFFSET(24) NUMBITS(8) [],
+
+        /// Identification code.
+        ///
+        /// This field is RO and deprecated. Software should use `MIDR_EL1` to identify the PE.
+        IDCODE OFFSET(16) NUMBITS(8) [],
+
+        /// Indicates the number of event counters implemented. This value is in the range of 0b00000
+        /// (only `PMCCNTR_EL0` implemented) to 0b11111 (`PMCCNTR_EL0` and 31 event counters
+        /// implemented).
+        ///
+        /// This field is RO.
+        N OFFSET(11) NUMBITS(5) [],
+
+        /// Freeze-on-overflow. Stop event counters on overflow.
+        ///
+        /// 0 Do not freeze on overflow.
+        /// 1 Event counter `PMEVCNTR<n>_EL0` does not count when `PMOVSCLR_EL0[(n | 1) - 1]`
+        ///   is 1.
+        ///
+        /// When FEAT_PMUv3p7 is not implemented, this bit is RES0.
+        FZO OFFSET(9) NUMBITS(1) [
+            DontFreeze = 0,
+            Freeze = 1
+        ],
+
+        /// Long event counter enable. Determines when unsigned overflow is recorded by an event
+        /// counter overflow bit.
+        ///
+        /// 0 Event counter overflow on increment that causes unsigned overflow of
+        ///   `PMEVCNTR<n>_EL0[31:0]`.
+        /// 1 Event counter overflow on increment that causes unsigned overflow of
+        ///   `PMEVCNTR<n>_EL0[63:0]`.
+        ///
+        /// When FEAT_PMUv3p5 is not implemented, this bit is RES0.
+        LP OFFSET(7) NUMBITS(1) [
+            Overflow32 = 0,
+            Overflow64 = 1
+        ],
+
+        /// Long cycle counter enable. Determines when unsigned overflow is recorded by the cycle
+        /// counter overflow bit.
+        ///
+        /// 0 Cycle counter overflow on increment that causes unsigned overflow of
+        ///   `PMCCNTR_EL0[31:0]`.
+        /// 1 Cycle counter overflow on increment that causes unsigned overflow of
+        ///   `PMCCNTR_EL0[63:0]`.
+        ///
+        /// Arm deprecates use of `PMCR_EL0.LC = 0`.
+        LC OFFSET(6) NUMBITS(1) [
+            Overflow32 = 0,
+            Overflow64 = 1
+        ],
+
+        /// Disable cycle counter when event counting is prohibited.
+        ///
+        /// 0 Cycle counting by `PMCCNTR_EL0` is not affected by this bit.
+        /// 1 When event counting for counters in the range [0..(MDCR_EL2.HPMN-1)] is
+        ///   prohibited, cycle counting by `PMCCNTR_EL0` is disabled.
+        DP OFFSET(5) NUMBITS(1) [],
+
+        /// Enable export of events in an IMPLEMENTATION DEFINED PMU event export bus.
+        ///
+        /// 0 Do not export events.
+        /// 1 Export events where not prohibited.
+        X OFFSET(4) NUMBITS(1) [],
+
+        /// Clock divider.
+        ///
+        /// 0 When enabled, `PMCCNTR_EL0` counts every clock cycle.
+        /// 1 When enabled, `PMCCNTR_EL0` counts once every 64 clock cycles.
+        ///
+        /// Arm deprecates use of `PMCR_EL0.D = 1`.
+        D OFFSET(3) NUMBITS(1) [
+            EveryCycle = 0,
+            Every64Cycles = 1
+        ],
+
+        /// Cycle counter reset. The effects of writing to this bit are:
+        ///
+        /// 0 No action.
+        /// 1 Reset `PMCCNTR_EL0` to zero.
+        ///
+        /// This bit is WO. The effect of writing to this bit is not affected by `PMCR_EL0.E`.
+        C OFFSET(2) NUMBITS(1) [],
+
+        /// Event counter reset. The effects of writing to this bit are:
+        ///
+        /// 0 No action.
+        /// 1 Reset all event counters accessible in the current Exception level, not including
+        ///   `PMCCNTR_EL0`, to zero.
+        ///
+        /// This bit is WO. The effect of writing to this bit is not affected by `PMCR_EL0.E`.
+        P OFFSET(1) NUMBITS(1) [],
+
+        /// Enable.
+        ///
+        /// 0 All counters that are accessible at Non-secure EL1, including `PMCCNTR_EL0`, are
+        ///   disabled.
+        /// 1 All counters are enabled by `PMCNTENSET_EL0`.
+        ///
+        /// When this register has an architecturally-defined reset value, this field resets to 0.
+        ///
+        /// This bit does not affect the operation of event counters that `MDCR_EL2.HPMN`
+        /// reserves for EL2 use.
+        E OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMCR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMCR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMCR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMCR_EL0", "x");
+}
+
+pub const PMCR_EL0: Reg = Reg {};
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Type Register 13 - EL0
+//!
+//! Configures event counter 13, determining the event that increments it and the modes of
+//! execution in which it counts.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+#[allow(non_snake_case)]
+pub mod PMEVTYPER13_EL0 {
+    //! Same layout as [`PMXEVTYPER_EL0`](super::super::PMXEVTYPER_EL0).
+
+    pub use super::super::pmxevtyper_el0::PMXEVTYPER_EL0::*;
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMEVTYPER13_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMEVTYPER13_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMEVTYPER13_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMEVTYPER13_EL0", "x");
+}
+
+pub const PMEVTYPER13_EL0: Reg = Reg {};
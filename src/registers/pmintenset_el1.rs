@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Interrupt Enable Set register - EL1
+//!
+//! Enables the generation of interrupt requests on overflows from the Cycle Count Register,
+//! `PMCCNTR_EL0`, and the event counters `PMEVCNTR<n>_EL0`. Reading the register shows which
+//! overflow interrupt requests are enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMINTENSET_EL1 [
+        /// `PMCCNTR_EL0` overflow interrupt request enable bit. Writing 1 enables the interrupt, writing 0
+        /// has no effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter overflow interrupt request enable bits. Writing 1 to bit n enables the overflow
+        /// interrupt of `PMEVCNTR<n>_EL0`, writing 0 has no effect.
+        ///
+        /// Bit n corresponds to event counter `PMEVCNTR<n>_EL0`. Bits that correspond to
+        /// unimplemented event counters are RAZ/WI.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMINTENSET_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "PMINTENSET_EL1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMINTENSET_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "PMINTENSET_EL1", "x");
+}
+
+pub const PMINTENSET_EL1: Reg = Reg {};
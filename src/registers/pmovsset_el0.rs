@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Overflow Flag Status Set register - EL0
+//!
+//! Sets the state of the overflow bit for the Cycle Count Register, `PMCCNTR_EL0`, and each of the
+//! implemented event counters `PMEVCNTR<n>_EL0`.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMOVSSET_EL0 [
+        /// `PMCCNTR_EL0` overflow bit. Writing 1 sets the cycle counter overflow bit, writing 0 has no
+        /// effect.
+        C OFFSET(31) NUMBITS(1) [],
+
+        /// Event counter overflow set bits. Writing 1 to bit n sets the overflow bit of
+        /// `PMEVCNTR<n>_EL0`, writing 0 has no effect.
+        ///
+        /// Bit n corresponds to event counter `PMEVCNTR<n>_EL0`. Bits that correspond to
+        /// unimplemented event counters are RAZ/WI.
+        P OFFSET(0) NUMBITS(31) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMOVSSET_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMOVSSET_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMOVSSET_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMOVSSET_EL0", "x");
+}
+
+pub const PMOVSSET_EL0: Reg = Reg {};
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Event Counter Selection Register - EL0
+//!
+//! Selects the current event counter `PMEVCNTR<n>_EL0` or the cycle counter `CCNT`.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMSELR_EL0 [
+        /// Event counter select. Selects the counter accessed by subsequent accesses to
+        /// `PMXEVTYPER_EL0` and `PMXEVCNTR_EL0`.
+        ///
+        /// 0b00000..=0b11110 Select event counter n.
+        /// 0b11111           Select the cycle counter. When `PMSELR_EL0.SEL` is 0b11111,
+        ///                   `PMXEVTYPER_EL0` accesses `PMCCFILTR_EL0` and `PMXEVCNTR_EL0` is
+        ///                   CONSTRAINED UNPREDICTABLE.
+        SEL OFFSET(0) NUMBITS(5) [
+            CycleCounter = 0b11111
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMSELR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMSELR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMSELR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMSELR_EL0", "x");
+}
+
+pub const PMSELR_EL0: Reg = Reg {};
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors User Enable Register - EL0
+//!
+//! Enables or disables EL0 access to the Performance Monitors.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub PMUSERENR_EL0 [
+        /// Event counter read trap control.
+        ///
+        /// 0 EL0 using AArch64: EL0 reads of the `PMXEVCNTR_EL0` and `PMEVCNTR<n>_EL0`, and EL0
+        ///   read/write accesses to the `PMSELR_EL0`, are trapped if `PMUSERENR_EL0.EN` is also 0.
+        /// 1 Overrides `PMUSERENR_EL0.EN` and enables EL0 reads of the `PMXEVCNTR_EL0` and
+        ///   `PMEVCNTR<n>_EL0`, and EL0 read/write accesses to the `PMSELR_EL0`.
+        ER OFFSET(3) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Cycle counter read trap control.
+        ///
+        /// 0 EL0 reads of the `PMCCNTR_EL0` are trapped if `PMUSERENR_EL0.EN` is also 0.
+        /// 1 Overrides `PMUSERENR_EL0.EN` and enables EL0 reads of the `PMCCNTR_EL0`.
+        CR OFFSET(2) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Software Increment write trap control.
+        ///
+        /// 0 EL0 writes to the `PMSWINC_EL0` are trapped if `PMUSERENR_EL0.EN` is also 0.
+        /// 1 Overrides `PMUSERENR_EL0.EN` and enables EL0 writes to the `PMSWINC_EL0`.
+        SW OFFSET(1) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Traps EL0 accesses to the Performance Monitors registers to EL1.
+        ///
+        /// 0 EL0 accesses to the Performance Monitors registers are trapped, unless overridden
+        ///   by the ER, CR or SW bits.
+        /// 1 This control does not cause any instructions to be trapped.
+        EN OFFSET(0) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = PMUSERENR_EL0::Register;
+
+    sys_coproc_read_raw!(u64, "PMUSERENR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = PMUSERENR_EL0::Register;
+
+    sys_coproc_write_raw!(u64, "PMUSERENR_EL0", "x");
+}
+
+pub const PMUSERENR_EL0: Reg = Reg {};
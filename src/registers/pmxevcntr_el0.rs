@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Performance Monitors Selected Event Count Register - EL0
+//!
+//! Reads or writes the value of the selected event counter, `PMEVCNTR<n>_EL0`. `PMSELR_EL0.SEL`
+//! determines which event counter is selected.
+
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_read_raw!(u64, "PMXEVCNTR_EL0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ();
+
+    sys_coproc_write_raw!(u64, "PMXEVCNTR_EL0", "x");
+}
+
+pub const PMXEVCNTR_EL0: Reg = Reg {};
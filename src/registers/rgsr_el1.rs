@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Random Allocation Tag Seed Register - EL1
+//!
+//! Holds the state of the pseudo-random generator used by the IRG instruction.
+//!
+//! Accessed through its encoding `S3_0_C1_C0_5`, as assemblers only accept the name with FEAT_MTE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub RGSR_EL1 [
+        /// Seed register.
+        SEED OFFSET(8) NUMBITS(16) [],
+
+        /// The tag generated by the most recent IRG instruction.
+        TAG OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = RGSR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c1_c0_5", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = RGSR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c1_c0_5", "x");
+}
+
+pub const RGSR_EL1: Reg = Reg {};
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Reset Management Register - EL3
+//!
+//! If EL3 is the highest Exception level implemented and is capable of using both AArch32 and
+//! AArch64, controls the Execution state that the PE resets into and can request a Warm reset.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub RMR_EL3 [
+        /// Reset Request. Setting this bit to 1 requests a Warm reset.
+        RR OFFSET(1) NUMBITS(1) [],
+
+        /// The Execution state that the PE resets into when a Warm reset is requested.
+        AA64 OFFSET(0) NUMBITS(1) [
+            AArch32 = 0,
+            AArch64 = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = RMR_EL3::Register;
+
+    sys_coproc_read_raw!(u64, "RMR_EL3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = RMR_EL3::Register;
+
+    sys_coproc_write_raw!(u64, "RMR_EL3", "x");
+}
+
+pub const RMR_EL3: Reg = Reg {};
@@ -19,6 +19,106 @@ use tock_registers::{
 
 register_bitfields! {u64,
     pub SCR_EL3 [
+        /// When FEAT_RME is implemented, Non-secure Realm world bit. Together with NS, selects
+        /// the Security state of EL2 and lower Exception levels.
+        ///
+        /// {NSE, NS} 0b00 Secure, 0b01 Non-secure, 0b11 Realm.
+        NSE OFFSET(62) NUMBITS(1) [],
+
+        /// When FEAT_RME is implemented, Granule Protection Faults are routed to EL3.
+        GPF OFFSET(48) NUMBITS(1) [],
+
+        /// When FEAT_HCX is implemented, enables access to HCRX_EL2 at EL2.
+        HXEn OFFSET(38) NUMBITS(1) [],
+
+        /// When FEAT_TME is implemented, enables the Transactional Memory Extension
+        /// instructions at EL2 and lower.
+        TME OFFSET(34) NUMBITS(1) [],
+
+        /// When FEAT_TWED is implemented, the delay for trapping WFE instructions is
+        /// 2^(TWEDEL + 8) cycles.
+        TWEDEL OFFSET(30) NUMBITS(4) [],
+
+        /// When FEAT_TWED is implemented, enables the configurable delay of WFE traps.
+        TWEDEn OFFSET(29) NUMBITS(1) [],
+
+        /// When FEAT_ECV is implemented, enables the Enhanced Counter Virtualization controls
+        /// of CNTHCTL_EL2 and access to CNTPOFF_EL2.
+        ECVEn OFFSET(28) NUMBITS(1) [],
+
+        /// When FEAT_FGT is implemented, enables access to the fine-grained trap registers at
+        /// EL2.
+        FGTEn OFFSET(27) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented, allows access to Allocation Tags at EL2 and lower.
+        ATA OFFSET(26) NUMBITS(1) [
+            Trap = 0,
+            Allow = 1
+        ],
+
+        /// When FEAT_CSV2_2 is implemented, enables access to the SCXTNUM_ELx registers at EL2
+        /// and lower.
+        EnSCXT OFFSET(25) NUMBITS(1) [],
+
+        /// When FEAT_RASv1p1 is implemented, enables access to the error record fault injection
+        /// registers at EL2 and lower.
+        FIEN OFFSET(21) NUMBITS(1) [],
+
+        /// When FEAT_DoubleFault is implemented, routes SError exceptions masked at EL3 to EL3
+        /// as non-maskable exceptions.
+        NMEA OFFSET(20) NUMBITS(1) [],
+
+        /// When FEAT_DoubleFault is implemented, routes synchronous External aborts and SError
+        /// exceptions taken to EL3 to the SError vector.
+        EASE OFFSET(19) NUMBITS(1) [],
+
+        /// When FEAT_SEL2 is implemented, enables Secure EL2.
+        EEL2 OFFSET(18) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// When FEAT_PAuth is implemented, controls the use of pointer authentication
+        /// instructions at EL2 and lower.
+        ///
+        /// 0 Pointer authentication instructions trap to EL3.
+        /// 1 Pointer authentication instructions do not trap.
+        API OFFSET(17) NUMBITS(1) [
+            Trap = 0,
+            NoTrap = 1
+        ],
+
+        /// When FEAT_PAuth is implemented, traps EL2 and EL1 accesses to the pointer
+        /// authentication key registers to EL3.
+        ///
+        /// 0 Accesses trap to EL3.
+        /// 1 Accesses do not trap.
+        APK OFFSET(16) NUMBITS(1) [
+            Trap = 0,
+            NoTrap = 1
+        ],
+
+        /// When FEAT_RAS is implemented, traps EL2 and EL1 accesses to the RAS error record
+        /// registers to EL3.
+        TERR OFFSET(15) NUMBITS(1) [],
+
+        /// When FEAT_LOR is implemented, traps EL2 and EL1 accesses to the LORegion registers
+        /// to EL3.
+        TLOR OFFSET(14) NUMBITS(1) [],
+
+        /// Traps EL2, EL1 and EL0 execution of WFE instructions to EL3.
+        TWE OFFSET(13) NUMBITS(1) [],
+
+        /// Traps EL2, EL1 and EL0 execution of WFI instructions to EL3.
+        TWI OFFSET(12) NUMBITS(1) [],
+
+        /// Traps Secure EL1 accesses to the Counter-timer Physical Secure timer registers to
+        /// EL3.
+        ///
+        /// 0 Accesses trap to EL3.
+        /// 1 Accesses do not trap.
+        ST OFFSET(11) NUMBITS(1) [],
+
         /// Execution state control for lower Exception levels:
         ///
         /// 0 Lower levels are all AArch32.
@@ -42,6 +142,10 @@ register_bitfields! {u64,
             NextELIsAarch64 = 1
         ],
 
+        /// Secure Instruction Fetch. When set, Secure state instruction fetches from memory
+        /// marked Non-secure are not permitted.
+        SIF OFFSET(9) NUMBITS(1) [],
+
         /// Hypervisor Call Enable
         ///
         /// 0 The HVC instruction is undefined at all exception levels.
@@ -62,6 +166,33 @@ register_bitfields! {u64,
             SmcDisabled = 1
         ],
 
+        /// External Abort and SError interrupt routing.
+        ///
+        /// 0 External Aborts and SError interrupts are not taken to EL3 from lower levels.
+        /// 1 External Aborts and SError interrupts at any Exception level are taken to EL3.
+        EA OFFSET(3) NUMBITS(1) [
+            NotTaken = 0,
+            Taken = 1
+        ],
+
+        /// Physical FIQ routing.
+        ///
+        /// 0 Physical FIQ interrupts are not taken to EL3 from lower levels.
+        /// 1 Physical FIQ interrupts at any Exception level are taken to EL3.
+        FIQ OFFSET(2) NUMBITS(1) [
+            NotTaken = 0,
+            Taken = 1
+        ],
+
+        /// Physical IRQ routing.
+        ///
+        /// 0 Physical IRQ interrupts are not taken to EL3 from lower levels.
+        /// 1 Physical IRQ interrupts at any Exception level are taken to EL3.
+        IRQ OFFSET(1) NUMBITS(1) [
+            NotTaken = 0,
+            Taken = 1
+        ],
+
         /// Non-secure bit.
         /// 0 Indicates that EL0 and EL1 are in Secure state.
         ///
@@ -16,6 +16,112 @@ use tock_registers::{
 
 register_bitfields! {u64,
     pub SCTLR_EL1 [
+        /// Allocation Tag Access in EL1.
+        ///
+        /// 0 Access to Allocation Tags is prevented at EL1.
+        /// 1 This control does not prevent access to Allocation Tags at EL1.
+        ///
+        /// When FEAT_MTE2 is not implemented, this bit is RES 0.
+        ATA OFFSET(43) NUMBITS(1) [
+            Prevent = 0,
+            Allow = 1
+        ],
+
+        /// Allocation Tag Access in EL0.
+        ///
+        /// 0 Access to Allocation Tags is prevented at EL0.
+        /// 1 This control does not prevent access to Allocation Tags at EL0.
+        ///
+        /// When FEAT_MTE2 is not implemented, this bit is RES 0.
+        ATA0 OFFSET(42) NUMBITS(1) [
+            Prevent = 0,
+            Allow = 1
+        ],
+
+        /// Tag Check Fault in EL1. Controls the effect of Tag Check Faults due to loads and stores
+        /// in EL1.
+        ///
+        /// 00 Tag Check Faults have no effect on the PE.
+        /// 01 Tag Check Faults cause a synchronous exception.
+        /// 10 Tag Check Faults are asynchronously accumulated in TFSR_EL1.TF0/TF1.
+        /// 11 Tag Check Faults cause a synchronous exception on reads, and are asynchronously
+        ///    accumulated on writes (FEAT_MTE3 only).
+        ///
+        /// When FEAT_MTE2 is not implemented, this field is RES 0.
+        TCF OFFSET(40) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// Tag Check Fault in EL0. Controls the effect of Tag Check Faults due to loads and stores
+        /// in EL0.
+        ///
+        /// 00 Tag Check Faults have no effect on the PE.
+        /// 01 Tag Check Faults cause a synchronous exception.
+        /// 10 Tag Check Faults are asynchronously accumulated in TFSRE0_EL1.TF0/TF1.
+        /// 11 Tag Check Faults cause a synchronous exception on reads, and are asynchronously
+        ///    accumulated on writes (FEAT_MTE3 only).
+        ///
+        /// When FEAT_MTE2 is not implemented, this field is RES 0.
+        TCF0 OFFSET(38) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// When synchronous exceptions are not being generated by Tag Check Faults, this field
+        /// controls whether on exception entry into EL1, all Tag Check Faults due to instructions
+        /// executed before exception entry, that are reported asynchronously, are synchronized
+        /// into TFSRE0_EL1 and TFSR_EL1 registers.
+        ///
+        /// 0 Tag Check Faults are not synchronized on entry to EL1.
+        /// 1 Tag Check Faults are synchronized on entry to EL1.
+        ///
+        /// When FEAT_MTE2 is not implemented, this bit is RES 0.
+        ITFSB OFFSET(37) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls enabling of pointer authentication (using the APIAKey_EL1 key) of instruction
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APIAKey_EL1 key) of instruction addresses is not enabled.
+        /// 1 Pointer authentication (using the APIAKey_EL1 key) of instruction addresses is enabled.
+        ///
+        /// When FEAT_PAuth is not implemented, this bit is RES 0.
+        EnIA OFFSET(31) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls enabling of pointer authentication (using the APIBKey_EL1 key) of instruction
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APIBKey_EL1 key) of instruction addresses is not enabled.
+        /// 1 Pointer authentication (using the APIBKey_EL1 key) of instruction addresses is enabled.
+        ///
+        /// When FEAT_PAuth is not implemented, this bit is RES 0.
+        EnIB OFFSET(30) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls enabling of pointer authentication (using the APDAKey_EL1 key) of data
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APDAKey_EL1 key) of data addresses is not enabled.
+        /// 1 Pointer authentication (using the APDAKey_EL1 key) of data addresses is enabled.
+        ///
+        /// When FEAT_PAuth is not implemented, this bit is RES 0.
+        EnDA OFFSET(27) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// Traps EL0 execution of cache maintenance instructions to EL1, from AArch64 state only.
         ///
         /// 0 Any attempt to execute a DC CVAU, DC CIVAC, DC CVAC, DC CVAP, or IC IVAU
@@ -178,6 +284,18 @@ register_bitfields! {u64,
             DontTrap = 1,
         ],
 
+        /// Controls enabling of pointer authentication (using the APDBKey_EL1 key) of data
+        /// addresses in the EL1&0 translation regime.
+        ///
+        /// 0 Pointer authentication (using the APDBKey_EL1 key) of data addresses is not enabled.
+        /// 1 Pointer authentication (using the APDBKey_EL1 key) of data addresses is enabled.
+        ///
+        /// When FEAT_PAuth is not implemented, this bit is RES 0.
+        EnDB OFFSET(13) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
         /// Instruction access Cacheability control, for accesses at EL0 and
         /// EL1:
         ///
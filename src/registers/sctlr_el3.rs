@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! System Control Register - EL3
+//!
+//! Provides top level control of the system, including its memory system, at EL3.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub SCTLR_EL3 [
+        /// When FEAT_SSBS is implemented, the default PSTATE.SSBS value on taking an exception
+        /// to EL3.
+        DSSBS OFFSET(44) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented, allow access to Allocation Tags at EL3.
+        ATA OFFSET(43) NUMBITS(1) [
+            Trap = 0,
+            Allow = 1
+        ],
+
+        /// When FEAT_MTE2 is implemented, controls the effect of Tag Check Faults due to loads
+        /// and stores in EL3.
+        TCF OFFSET(40) NUMBITS(2) [
+            None = 0b00,
+            Sync = 0b01,
+            Async = 0b10,
+            Asymmetric = 0b11
+        ],
+
+        /// When FEAT_MTE2 is implemented, controls whether asynchronous Tag Check Faults
+        /// accumulated in TFSR_EL3 are synchronized on taking an exception to EL3.
+        ITFSB OFFSET(37) NUMBITS(1) [],
+
+        /// When FEAT_PAuth is implemented, controls enabling of pointer authentication, using
+        /// the APIAKey_EL1 key, of instruction addresses in the EL3 translation regime.
+        EnIA OFFSET(31) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// When FEAT_PAuth is implemented, controls enabling of pointer authentication, using
+        /// the APIBKey_EL1 key, of instruction addresses in the EL3 translation regime.
+        EnIB OFFSET(30) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// When FEAT_PAuth is implemented, controls enabling of pointer authentication, using
+        /// the APDAKey_EL1 key, of data addresses in the EL3 translation regime.
+        EnDA OFFSET(27) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Exception endianness. The possible values are:
+        ///
+        /// 0  Little endian.
+        /// 1  Big endian.
+        EE OFFSET(25) NUMBITS(1) [
+            Little = 0,
+            Big = 1
+        ],
+
+        /// When FEAT_ExS is implemented control if taking an exception to EL3 is a context
+        /// synchonizing event
+        EIS OFFSET(22) NUMBITS(1) [
+            IsNotSynch = 0,
+            IsSynch = 1
+        ],
+
+        /// When FEAT_IESB is implemented control if an implict ESB is added at each exception
+        /// and before each ERET to/from EL3
+        IESB OFFSET(21) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Force treatment of all memory regions with write permissions as XN.
+        /// The possible values are:
+        ///
+        /// 0  Regions with write permissions are not forced XN.
+        /// 1  Regions with write permissions are forced XN.
+        WXN OFFSET(19) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// When FEAT_PAuth is implemented, controls enabling of pointer authentication, using
+        /// the APDBKey_EL1 key, of data addresses in the EL3 translation regime.
+        EnDB OFFSET(13) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Instruction access Cacheability control, for accesses at EL3.
+        ///
+        /// 0 All instruction access to Normal memory from EL3 are Non-cacheable for all levels
+        ///   of instruction and unified cache.
+        /// 1 This control has no effect on the Cacheability of instruction access to Normal
+        ///   memory from EL3.
+        ///
+        /// On a Warm reset, this field resets to 0.
+        I OFFSET(12) NUMBITS(1) [
+            NonCacheable = 0,
+            Cacheable = 1
+        ],
+
+        /// When FEAT_ExS is implemented control if an exception return from EL3 is a context
+        /// synchonizing event
+        EOS OFFSET(11) NUMBITS(1) [
+            IsNotSynch = 0,
+            IsSynch = 1
+        ],
+
+        /// SP Alignment check enable.
+        ///
+        /// When set to 1, if a load or store instruction executed at EL3 uses the SP
+        /// as the base address and the SP is not aligned to a 16-byte boundary,
+        /// then a SP alignment fault exception is generated.
+        SA OFFSET(3) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Cacheability control, for data accesses.
+        ///
+        /// 0 Data accesses to Normal memory from EL3 and Normal memory accesses to the EL3
+        ///   translation tables are Non-cacheable for all levels of data and unified cache.
+        /// 1 This control has no effect on the Cacheability of these accesses.
+        ///
+        /// On a Warm reset, this field resets to 0.
+        C OFFSET(2) NUMBITS(1) [
+            NonCacheable = 0,
+            Cacheable = 1
+        ],
+
+        /// Alignment check enable. This is the enable bit for Alignment fault checking at EL3.
+        ///
+        /// Load/store exclusive and load-acquire/store-release instructions have an alignment check
+        /// regardless of the value of the A bit.
+        A OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// MMU enable for EL3 stage 1 address translation.
+        ///
+        /// On a Warm reset, this field resets to 0.
+        M OFFSET(0) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SCTLR_EL3::Register;
+
+    sys_coproc_read_raw!(u64, "SCTLR_EL3", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SCTLR_EL3::Register;
+
+    sys_coproc_write_raw!(u64, "SCTLR_EL3", "x");
+}
+
+pub const SCTLR_EL3: Reg = Reg {};
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! SME Control Register - EL2
+//!
+//! Controls aspects of Streaming SVE and SME visible at EL2.
+//!
+//! Accessed through its encoding `S3_4_C1_C2_6`, as assemblers only accept the name with FEAT_SME
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub SMCR_EL2 [
+        /// Controls whether execution of the full A64 instruction set is permitted in Streaming
+        /// SVE mode at EL2 (FEAT_SME_FA64 only).
+        FA64 OFFSET(31) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Controls whether ZT0 can be accessed at EL2 (FEAT_SME2 only).
+        EZT0 OFFSET(30) NUMBITS(1) [
+            Trap = 0,
+            DontTrap = 1
+        ],
+
+        /// Requests an effective Streaming SVE vector length at EL2 of (LEN+1)*128 bits.
+        ///
+        /// The effective vector length is the largest implemented length that is not greater
+        /// than the requested one, and is further constrained by SMCR_EL3.LEN.
+        LEN OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SMCR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "s3_4_c1_c2_6", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SMCR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "s3_4_c1_c2_6", "x");
+}
+
+pub const SMCR_EL2: Reg = Reg {};
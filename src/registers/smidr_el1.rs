@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Streaming Mode Identification Register - EL1
+//!
+//! Provides additional identification mechanisms for scheduling purposes, for a PE that supports
+//! Streaming SVE mode.
+//!
+//! Accessed through its encoding `S3_1_C0_C0_6`, as assemblers only accept the name with FEAT_SME
+//! enabled.
+
+use tock_registers::{interfaces::Readable, register_bitfields};
+
+register_bitfields! {u64,
+    pub SMIDR_EL1 [
+        /// The Implementer code of the SME execution unit shared between PEs.
+        Implementer OFFSET(24) NUMBITS(8) [],
+
+        /// IMPLEMENTATION DEFINED revision number of the SME execution unit.
+        Revision OFFSET(16) NUMBITS(8) [],
+
+        /// Indicates whether Streaming execution priority is supported, see SMPRI_EL1.
+        SMPS OFFSET(15) NUMBITS(1) [
+            NotSupported = 0,
+            Supported = 1
+        ],
+
+        /// Identifies the SME execution unit shared between PEs.
+        Affinity OFFSET(0) NUMBITS(12) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SMIDR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_1_c0_c0_6", "x");
+}
+
+pub const SMIDR_EL1: Reg = Reg {};
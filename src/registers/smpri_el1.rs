@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Streaming Mode Priority Register - EL1
+//!
+//! Configures the streaming execution priority for the SME execution unit shared between PEs.
+//!
+//! Accessed through its encoding `S3_0_C1_C2_4`, as assemblers only accept the name with FEAT_SME
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub SMPRI_EL1 [
+        /// Streaming execution priority. The priority value is IMPLEMENTATION DEFINED.
+        Priority OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SMPRI_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c1_c2_4", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SMPRI_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c1_c2_4", "x");
+}
+
+pub const SMPRI_EL1: Reg = Reg {};
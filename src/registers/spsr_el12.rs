@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Saved Program Status Register - EL12
+//!
+//! When HCR_EL2.E2H is 1, accesses SPSR_EL1 from EL2, where the SPSR_EL1 encoding is redirected to
+//! the corresponding EL2 register. Uses the bitfields of SPSR_EL1.
+//!
+//! Accessed through its encoding `S3_5_C4_C0_0`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use super::SPSR_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SPSR_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_5_c4_c0_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SPSR_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_5_c4_c0_0", "x");
+}
+
+pub const SPSR_EL12: Reg = Reg {};
@@ -51,6 +51,21 @@ register_bitfields! {u64,
         /// example a signed overflow on an addition.
         V OFFSET(28) NUMBITS(1) [],
 
+        /// Cumulative saturation bit. AArch32 only.
+        ///
+        /// Set to the value of PSTATE.Q on taking an exception to EL2 from AArch32 state.
+        Q OFFSET(27) NUMBITS(1) [],
+
+        /// If-Then bits \[1:0\]. AArch32 only.
+        ///
+        /// Together with IT_HI, holds the execution state bits of the T32 IT instruction.
+        IT_LO OFFSET(25) NUMBITS(2) [],
+
+        /// Jazelle bit. AArch32 only.
+        ///
+        /// Used in ARMv7. RES 0 in ARMv8, where T32 and A32 are the only AArch32 instruction sets.
+        J OFFSET(24) NUMBITS(1) [],
+
         /// Software step. Shows the value of PSTATE.SS immediately before the exception was taken.
         SS OFFSET(21) NUMBITS(1) [],
 
@@ -58,6 +73,15 @@ register_bitfields! {u64,
         /// exception was taken.
         IL OFFSET(20) NUMBITS(1) [],
 
+        /// Greater than or Equal flags, for the parallel add and subtract instructions. AArch32
+        /// only.
+        GE OFFSET(16) NUMBITS(4) [],
+
+        /// If-Then bits \[7:2\]. AArch32 only.
+        ///
+        /// Together with IT_LO, holds the execution state bits of the T32 IT instruction.
+        IT_HI OFFSET(10) NUMBITS(6) [],
+
         /// Process state D mask. The possible values of this bit are:
         ///
         /// 0 Watchpoint, Breakpoint, and Software Step exceptions targeted at the current Exception
@@ -73,6 +97,14 @@ register_bitfields! {u64,
             Masked = 1
         ],
 
+        /// Endianness state bit. AArch32 only, overlapping D.
+        ///
+        /// Controls the load and store endianness for data accesses.
+        E OFFSET(9) NUMBITS(1) [
+            LittleEndian = 0,
+            BigEndian = 1
+        ],
+
         /// SError interrupt mask bit. The possible values of this bit are:
         ///
         /// 0 Exception not masked.
@@ -100,32 +132,55 @@ register_bitfields! {u64,
             Masked = 1
         ],
 
-        /// AArch64 state (Exception level and selected SP) that an exception was taken from. The
-        /// possible values are:
+        /// T32 Instruction set state bit. AArch32 only.
         ///
-        /// M[3:0] | State
-        /// --------------
-        /// 0b0000 | EL0t
-        /// 0b0100 | EL1t
-        /// 0b0101 | EL1h
-        /// 0b1000 | EL2t
-        /// 0b1001 | EL2h
-        ///
-        /// Other values are reserved, and returning to an Exception level that is using AArch64
-        /// with a reserved value in this field is treated as an illegal exception return.
+        /// Determines the AArch32 instruction set state that the exception was taken from.
+        T OFFSET(5) NUMBITS(1) [
+            A32 = 0,
+            T32 = 1
+        ],
+
+        /// Execution state and mode that an exception was taken from. The possible values are:
+        ///
+        /// M[4:0]  | State
+        /// ---------------
+        /// 0b00000 | EL0t
+        /// 0b00100 | EL1t
+        /// 0b00101 | EL1h
+        /// 0b01000 | EL2t
+        /// 0b01001 | EL2h
+        /// 0b10000 | AArch32 User
+        /// 0b10001 | AArch32 FIQ
+        /// 0b10010 | AArch32 IRQ
+        /// 0b10011 | AArch32 Supervisor
+        /// 0b10111 | AArch32 Abort
+        /// 0b11011 | AArch32 Undefined
+        /// 0b11111 | AArch32 System
+        ///
+        /// Other values are reserved, and returning with a reserved value in this field is treated
+        /// as an illegal exception return.
         ///
         /// The bits in this field are interpreted as follows:
-        ///   - M[3:2] holds the Exception Level.
-        ///   - M[1] is unused and is RES 0 for all non-reserved values.
-        ///   - M[0] is used to select the SP:
+        ///   - M[4] holds the Execution state, 0 for AArch64 and 1 for AArch32.
+        ///   - For AArch64, M[3:2] holds the Exception Level.
+        ///   - For AArch64, M[1] is unused and is RES 0 for all non-reserved values.
+        ///   - For AArch64, M[0] is used to select the SP:
         ///     - 0 means the SP is always SP0.
         ///     - 1 means the exception SP is determined by the EL.
-        M OFFSET(0) NUMBITS(4) [
-            EL0t = 0b0000,
-            EL1t = 0b0100,
-            EL1h = 0b0101,
-            EL2t = 0b1000,
-            EL2h = 0b1001
+        ///   - For AArch32, M[3:0] holds the mode.
+        M OFFSET(0) NUMBITS(5) [
+            EL0t = 0b00000,
+            EL1t = 0b00100,
+            EL1h = 0b00101,
+            EL2t = 0b01000,
+            EL2h = 0b01001,
+            User = 0b10000,
+            Fiq = 0b10001,
+            Irq = 0b10010,
+            Supervisor = 0b10011,
+            Abort = 0b10111,
+            Undefined = 0b11011,
+            System = 0b11111
         ]
     ]
 }
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Saved Program Status Register - FIQ mode
+//!
+//! Holds the saved process state when an exception is taken to AArch32 FIQ mode. Accessible from
+//! AArch64 state only at EL2 and EL3, to context switch an AArch32 EL1.
+//!
+//! Has the AArch32 format of SPSR_EL2, whose field definitions it shares.
+
+use super::SPSR_EL2;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SPSR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "SPSR_fiq", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SPSR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "SPSR_fiq", "x");
+}
+
+#[allow(non_upper_case_globals)]
+pub const SPSR_fiq: Reg = Reg {};
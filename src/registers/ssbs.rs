@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Speculative Store Bypass Safe
+//!
+//! Allows access to the Speculative Store Bypass Safe bit.
+//!
+//! Accessed through its encoding `S3_3_C4_C2_6`, as assemblers only accept the name with FEAT_SSBS
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub SSBS [
+        /// Speculative Store Bypass Safe.
+        ///
+        /// 0 Hardware is not permitted to use speculative loads that bypass earlier stores to
+        ///   the same address in a way that could be exploited.
+        /// 1 Hardware is permitted to use Speculative Store Bypassing.
+        SSBS OFFSET(12) NUMBITS(1) [
+            Disallowed = 0,
+            Allowed = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SSBS::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c2_6", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SSBS::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c2_6", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.SSBS using the `MSR SSBS, #<imm>` form, which does not need a general-purpose
+    /// register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".arch_extension ssbs", "msr ssbs, #1", options(nostack))
+                } else {
+                    core::arch::asm!(".arch_extension ssbs", "msr ssbs, #0", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const SSBS: Reg = Reg {};
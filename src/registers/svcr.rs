@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Streaming Vector Control Register
+//!
+//! Controls Streaming SVE mode and SME behavior.
+//!
+//! Accessed through its encoding `S3_3_C4_C2_2`, as assemblers only accept the name with FEAT_SME
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub SVCR [
+        /// Enables SME ZA storage.
+        ///
+        /// When this bit changes from 0 to 1 or from 1 to 0, ZA is zeroed.
+        ZA OFFSET(1) NUMBITS(1) [
+            Disable = 0,
+            Enable = 1
+        ],
+
+        /// Enables Streaming SVE mode.
+        ///
+        /// When this bit changes, the SVE Z and P registers and FFR are zeroed.
+        SM OFFSET(0) NUMBITS(1) [
+            NonStreaming = 0,
+            Streaming = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = SVCR::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c2_2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = SVCR::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c2_2", "x");
+}
+
+pub const SVCR: Reg = Reg {};
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Check Override
+//!
+//! Allows access to the Tag Check Override bit.
+//!
+//! Accessed through its encoding `S3_3_C4_C2_7`, as assemblers only accept the name with FEAT_MTE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub TCO [
+        /// Tag Check Override.
+        ///
+        /// 0 Loads and stores are not affected by this control.
+        /// 1 Loads and stores are Unchecked, i.e. they do not generate Tag Check Faults.
+        TCO OFFSET(25) NUMBITS(1) [
+            NotOverridden = 0,
+            Overridden = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TCO::Register;
+
+    sys_coproc_read_raw!(u64, "s3_3_c4_c2_7", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TCO::Register;
+
+    sys_coproc_write_raw!(u64, "s3_3_c4_c2_7", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.TCO using the `MSR TCO, #<imm>` form, which does not need a general-purpose
+    /// register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".arch_extension memtag", "msr tco, #1", options(nostack))
+                } else {
+                    core::arch::asm!(".arch_extension memtag", "msr tco, #0", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const TCO: Reg = Reg {};
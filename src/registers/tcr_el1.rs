@@ -16,6 +16,41 @@ use tock_registers::{
 
 register_bitfields! {u64,
     pub TCR_EL1 [
+        /// When FEAT_LPA2 is implemented, enables the 52-bit output address and virtual address
+        /// formats of the 4KiB and 16KiB granules.
+        ///
+        /// 0 Bits[49:48] of translation descriptors are RES0, and the shareability of the
+        ///   descriptors is taken from their SH field.
+        /// 1 Bits[49:48] of translation descriptors hold bits[51:50] of the output address, and
+        ///   the shareability is taken from TCR_EL1.SH0 and TCR_EL1.SH1.
+        DS OFFSET(59) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented:
+        ///     Controls the generation of Unchecked accesses at EL1, and at EL0 if
+        ///     HCR_EL2.{E2H,TGE} != {1,1}, when address[59:55] = 0b11111.
+        ///     0 This control has no effect on the PE.
+        ///     1 All accesses are Unchecked.
+        /// Otherwise:
+        ///     Reserved, RES0.
+        TCMA1 OFFSET(58) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented:
+        ///     Controls the generation of Unchecked accesses at EL1, and at EL0 if
+        ///     HCR_EL2.{E2H,TGE} != {1,1}, when address[59:55] = 0b00000.
+        ///     0 This control has no effect on the PE.
+        ///     1 All accesses are Unchecked.
+        /// Otherwise:
+        ///     Reserved, RES0.
+        TCMA0 OFFSET(57) NUMBITS(1) [],
+
+        /// When FEAT_E0PD is implemented, faults EL0 accesses to the region translated by
+        /// TTBR1_EL1, with a timing that is independent of whether the address is mapped.
+        E0PD1 OFFSET(56) NUMBITS(1) [],
+
+        /// When FEAT_E0PD is implemented, faults EL0 accesses to the region translated by
+        /// TTBR0_EL1, with a timing that is independent of whether the address is mapped.
+        E0PD0 OFFSET(55) NUMBITS(1) [],
+
         /// When ARMv8.3-PAuth is implemented:
         ///     Controls the use of the top byte of instruction addresses for address matching.
         ///     0 TCR_EL1.TBI1 applies to Instruction and Data accesses.
@@ -36,6 +71,58 @@ register_bitfields! {u64,
         ///     Reserved, RES0.
         TBID0  OFFSET(51) NUMBITS(1) [],
 
+        /// When FEAT_HPDS2 is implemented, allows bit[62] of the last level descriptors of the
+        /// region translated by TTBR1_EL1 to be used by hardware, if TCR_EL1.HPD1 is set.
+        HWU162 OFFSET(50) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[61] of the last level descriptors of the
+        /// region translated by TTBR1_EL1 to be used by hardware, if TCR_EL1.HPD1 is set.
+        HWU161 OFFSET(49) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[60] of the last level descriptors of the
+        /// region translated by TTBR1_EL1 to be used by hardware, if TCR_EL1.HPD1 is set.
+        HWU160 OFFSET(48) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[59] of the last level descriptors of the
+        /// region translated by TTBR1_EL1 to be used by hardware, if TCR_EL1.HPD1 is set.
+        HWU159 OFFSET(47) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[62] of the last level descriptors of the
+        /// region translated by TTBR0_EL1 to be used by hardware, if TCR_EL1.HPD0 is set.
+        HWU062 OFFSET(46) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[61] of the last level descriptors of the
+        /// region translated by TTBR0_EL1 to be used by hardware, if TCR_EL1.HPD0 is set.
+        HWU061 OFFSET(45) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[60] of the last level descriptors of the
+        /// region translated by TTBR0_EL1 to be used by hardware, if TCR_EL1.HPD0 is set.
+        HWU060 OFFSET(44) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[59] of the last level descriptors of the
+        /// region translated by TTBR0_EL1 to be used by hardware, if TCR_EL1.HPD0 is set.
+        HWU059 OFFSET(43) NUMBITS(1) [],
+
+        /// When FEAT_HPDS is implemented, disables the hierarchical permissions in the
+        /// translation tables pointed to by TTBR1_EL1.
+        ///
+        /// 0 Hierarchical permissions are enabled.
+        /// 1 Hierarchical permissions are disabled.
+        HPD1 OFFSET(42) NUMBITS(1) [
+            Enable = 0,
+            Disable = 1
+        ],
+
+        /// When FEAT_HPDS is implemented, disables the hierarchical permissions in the
+        /// translation tables pointed to by TTBR0_EL1.
+        ///
+        /// 0 Hierarchical permissions are enabled.
+        /// 1 Hierarchical permissions are disabled.
+        HPD0 OFFSET(41) NUMBITS(1) [
+            Enable = 0,
+            Disable = 1
+        ],
+
         /// When FEAT_HAFDBS is implemented hardware can update the dirty flags in the stage1
         /// descriptors
         HD OFFSET(40) NUMBITS(1) [
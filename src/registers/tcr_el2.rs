@@ -17,6 +17,46 @@ use tock_registers::{
 
 register_bitfields! {u64,
     pub TCR_EL2 [
+        /// When FEAT_LPA2 is implemented, enables the 52-bit output address and virtual address
+        /// formats of the 4KiB and 16KiB granules.
+        DS OFFSET(32) NUMBITS(1) [],
+
+        /// When FEAT_MTE2 is implemented, controls the generation of Unchecked accesses at EL2
+        /// when address[59:55] = 0b00000.
+        TCMA OFFSET(30) NUMBITS(1) [],
+
+        /// When FEAT_PAuth is implemented, controls the use of the top byte of instruction
+        /// addresses for address matching.
+        ///
+        /// 0 TCR_EL2.TBI applies to Instruction and Data accesses.
+        /// 1 TCR_EL2.TBI applies to Data accesses only.
+        TBID OFFSET(29) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[62] of the last level descriptors to be
+        /// used by hardware, if TCR_EL2.HPD is set.
+        HWU62 OFFSET(28) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[61] of the last level descriptors to be
+        /// used by hardware, if TCR_EL2.HPD is set.
+        HWU61 OFFSET(27) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[60] of the last level descriptors to be
+        /// used by hardware, if TCR_EL2.HPD is set.
+        HWU60 OFFSET(26) NUMBITS(1) [],
+
+        /// When FEAT_HPDS2 is implemented, allows bit[59] of the last level descriptors to be
+        /// used by hardware, if TCR_EL2.HPD is set.
+        HWU59 OFFSET(25) NUMBITS(1) [],
+
+        /// When FEAT_HPDS is implemented, disables the hierarchical permissions in the
+        /// translation tables.
+        ///
+        /// 0 Hierarchical permissions are enabled.
+        /// 1 Hierarchical permissions are disabled.
+        HPD OFFSET(24) NUMBITS(1) [
+            Enable = 0,
+            Disable = 1
+        ],
 
         /// When FEAT_HAFDBS is implemented hardware can update the dirty flags in the stage1
         /// descriptors
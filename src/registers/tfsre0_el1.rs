@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Tag Fault Status Register - EL0
+//!
+//! Holds accumulated asynchronous Tag Check Faults occurring at EL0.
+//!
+//! Accessed through its encoding `S3_0_C5_C6_1`, as assemblers only accept the name with FEAT_MTE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub TFSRE0_EL1 [
+        /// Tag Check Fault. Asynchronous Tag Check Fault on an access made through the upper
+        /// address range, i.e. with bit\[55\] of the virtual address set.
+        ///
+        /// 0 No fault.
+        /// 1 At least one asynchronous Tag Check Fault has occurred since this bit was last cleared.
+        TF1 OFFSET(1) NUMBITS(1) [],
+
+        /// Tag Check Fault. Asynchronous Tag Check Fault on an access made through the lower
+        /// address range, i.e. with bit\[55\] of the virtual address clear.
+        ///
+        /// 0 No fault.
+        /// 1 At least one asynchronous Tag Check Fault has occurred since this bit was last cleared.
+        TF0 OFFSET(0) NUMBITS(1) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TFSRE0_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c5_c6_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TFSRE0_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c5_c6_1", "x");
+}
+
+pub const TFSRE0_EL1: Reg = Reg {};
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Translation Table Base Register 1 - EL12
+//!
+//! When HCR_EL2.E2H is 1, accesses TTBR1_EL1 from EL2, where the TTBR1_EL1 encoding is redirected
+//! to the corresponding EL2 register. Uses the bitfields of TTBR1_EL1.
+//!
+//! Accessed through its encoding `S3_5_C2_C0_1`, as assemblers only accept the name with FEAT_VHE
+//! enabled.
+
+use super::TTBR1_EL1;
+use tock_registers::interfaces::{Readable, Writeable};
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = TTBR1_EL1::Register;
+
+    sys_coproc_read_raw!(u64, "s3_5_c2_c0_1", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = TTBR1_EL1::Register;
+
+    sys_coproc_write_raw!(u64, "s3_5_c2_c0_1", "x");
+}
+
+impl Reg {
+    #[inline(always)]
+    pub fn get_baddr(&self) -> u64 {
+        self.read(TTBR1_EL1::BADDR) << 1
+    }
+
+    #[inline(always)]
+    pub fn set_baddr(&self, addr: u64) {
+        self.write(TTBR1_EL1::BADDR.val(addr >> 1));
+    }
+}
+
+pub const TTBR1_EL12: Reg = Reg {};
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! User Access Override
+//!
+//! Allows access to the User Access Override bit.
+//!
+//! Accessed through its encoding `S3_0_C4_C2_4`, as assemblers only accept the name with FEAT_UAO
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub UAO [
+        /// User Access Override.
+        ///
+        /// 0 The behavior of LDTR* and STTR* instructions is as defined in the base Armv8
+        ///   architecture.
+        /// 1 LDTR* and STTR* instructions executed at EL1, or at EL2 with HCR_EL2.{E2H, TGE} ==
+        ///   {1, 1}, behave as the equivalent LDR* and STR* instructions.
+        UAO OFFSET(23) NUMBITS(1) [
+            Disabled = 0,
+            Enabled = 1
+        ]
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = UAO::Register;
+
+    sys_coproc_read_raw!(u64, "s3_0_c4_c2_4", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = UAO::Register;
+
+    sys_coproc_write_raw!(u64, "s3_0_c4_c2_4", "x");
+}
+
+impl Reg {
+    /// Writes PSTATE.UAO using the `MSR UAO, #<imm>` form, which does not need a general-purpose
+    /// register.
+    #[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+    #[inline]
+    pub fn write_imm(&self, value: bool) {
+        match () {
+            // `msr uao, #<imm>`, encoded directly: no `.arch_extension` enables it, and `.arch`
+            // would also change the architecture the rest of the function is assembled for.
+            #[cfg(target_arch = "aarch64")]
+            () => unsafe {
+                if value {
+                    core::arch::asm!(".inst 0xd500417f", options(nostack))
+                } else {
+                    core::arch::asm!(".inst 0xd500407f", options(nostack))
+                }
+            },
+
+            #[cfg(not(target_arch = "aarch64"))]
+            () => unimplemented!(),
+        }
+    }
+}
+
+pub const UAO: Reg = Reg {};
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtualization Multiprocessor ID Register - EL2
+//!
+//! Holds the value of the Virtualization Multiprocessor ID. This is the value returned by EL1
+//! reads of MPIDR_EL1.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub VMPIDR_EL2 [
+        /// Affinity level 3.
+        Aff3 OFFSET(32) NUMBITS(8) [],
+
+        /// Indicates a Uniprocessor system.
+        U OFFSET(30) NUMBITS(1) [
+            MultiprocessorSystem = 0,
+            UniprocessorSystem = 1
+        ],
+
+        /// Indicates whether the lowest level of affinity consists of logical PEs that are
+        /// implemented using a multithreading type approach.
+        MT OFFSET(24) NUMBITS(1) [],
+
+        /// Affinity level 2.
+        Aff2 OFFSET(16) NUMBITS(8) [],
+
+        /// Affinity level 1.
+        Aff1 OFFSET(8) NUMBITS(8) [],
+
+        /// Affinity level 0.
+        Aff0 OFFSET(0) NUMBITS(8) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = VMPIDR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "VMPIDR_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = VMPIDR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "VMPIDR_EL2", "x");
+}
+
+pub const VMPIDR_EL2: Reg = Reg {};
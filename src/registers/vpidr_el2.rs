@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtualization Processor ID Register - EL2
+//!
+//! Holds the value of the Virtualization Processor ID. This is the value returned by EL1 reads of
+//! MIDR_EL1.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub VPIDR_EL2 [
+        /// The Implementer code.
+        Implementer OFFSET(24) NUMBITS(8) [],
+
+        /// The variant number.
+        Variant OFFSET(20) NUMBITS(4) [],
+
+        /// The architecture.
+        Architecture OFFSET(16) NUMBITS(4) [],
+
+        /// The primary part number.
+        PartNum OFFSET(4) NUMBITS(12) [],
+
+        /// The revision number.
+        Revision OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = VPIDR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "VPIDR_EL2", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = VPIDR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "VPIDR_EL2", "x");
+}
+
+pub const VPIDR_EL2: Reg = Reg {};
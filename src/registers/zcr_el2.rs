@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! SVE Control Register - EL2
+//!
+//! Controls aspects of SVE visible at EL2.
+//!
+//! Accessed through its encoding `S3_4_C1_C2_0`, as assemblers only accept the name with FEAT_SVE
+//! enabled.
+
+use tock_registers::{
+    interfaces::{Readable, Writeable},
+    register_bitfields,
+};
+
+register_bitfields! {u64,
+    pub ZCR_EL2 [
+        /// Requests an effective non-streaming SVE vector length at EL2 of (LEN+1)*128 bits.
+        ///
+        /// The effective vector length is the largest implemented length that is not greater
+        /// than the requested one, and is further constrained by ZCR_EL3.LEN.
+        LEN OFFSET(0) NUMBITS(4) []
+    ]
+}
+
+pub struct Reg;
+
+impl Readable for Reg {
+    type T = u64;
+    type R = ZCR_EL2::Register;
+
+    sys_coproc_read_raw!(u64, "s3_4_c1_c2_0", "x");
+}
+
+impl Writeable for Reg {
+    type T = u64;
+    type R = ZCR_EL2::Register;
+
+    sys_coproc_write_raw!(u64, "s3_4_c1_c2_0", "x");
+}
+
+pub const ZCR_EL2: Reg = Reg {};
@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Semihosting for AArch64.
+//!
+//! Semihosting lets code running on the target communicate with a debugger or emulator on the host
+//! (for example `qemu-system-aarch64 -semihosting`) to print output, access host files, read clocks
+//! or terminate the session. On AArch64, a semihosting call is made with `HLT #0xF000`, with the
+//! operation number in `W0` and a pointer to the parameter block in `X1`. The result is returned
+//! in `X0`.
+//!
+//! Note that without an attached host that handles semihosting calls, executing `HLT` results in
+//! an exception.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use core::fmt::Write;
+//! use cortex_a::semihosting::{self, hio};
+//!
+//! if let Some(mut stdout) = hio::hstdout() {
+//!     writeln!(stdout, "Hello from the target!").ok();
+//! }
+//!
+//! semihosting::exit(0);
+//! ```
+
+pub mod hio;
+
+use core::ffi::CStr;
+
+/// Semihosting operation numbers.
+pub mod nr {
+    pub const SYS_OPEN: usize = 0x01;
+    pub const SYS_CLOSE: usize = 0x02;
+    pub const SYS_WRITEC: usize = 0x03;
+    pub const SYS_WRITE0: usize = 0x04;
+    pub const SYS_WRITE: usize = 0x05;
+    pub const SYS_READ: usize = 0x06;
+    pub const SYS_CLOCK: usize = 0x10;
+    pub const SYS_GET_CMDLINE: usize = 0x15;
+    pub const SYS_EXIT: usize = 0x18;
+    pub const SYS_EXIT_EXTENDED: usize = 0x20;
+    pub const SYS_ELAPSED: usize = 0x30;
+    pub const SYS_TICKFREQ: usize = 0x31;
+}
+
+/// Reason code reported to the host by [`exit`]: the application exited normally.
+const ADP_STOPPED_APPLICATION_EXIT: usize = 0x20026;
+
+/// Performs a semihosting call with a raw parameter.
+///
+/// # Safety
+///
+/// `arg` must be valid for the operation `nr`. If it is a pointer to a parameter block, the block
+/// and all buffers it references must be valid for the host to read and/or write.
+#[cfg_attr(not(target_arch = "aarch64"), allow(unused_variables))]
+#[inline(always)]
+pub unsafe fn syscall1(nr: usize, arg: usize) -> usize {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let ret;
+            core::arch::asm!("hlt #0xf000", inout("x0") nr => ret, in("x1") arg, options(nostack));
+            ret
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Performs a semihosting call with a pointer to a parameter block.
+///
+/// # Safety
+///
+/// `arg` must be the parameter block expected by the operation `nr`, and all buffers it references
+/// must be valid for the host to read and/or write.
+#[inline(always)]
+pub unsafe fn syscall<T>(nr: usize, arg: &T) -> usize {
+    syscall1(nr, arg as *const T as usize)
+}
+
+/// A file handle on the host.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Handle(usize);
+
+impl Handle {
+    /// Returns the raw handle value as used by the host.
+    pub fn as_raw(self) -> usize {
+        self.0
+    }
+}
+
+/// File open modes, corresponding to the ISO C `fopen()` mode strings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(usize)]
+pub enum OpenMode {
+    /// `r`
+    Read = 0,
+    /// `rb`
+    ReadBinary = 1,
+    /// `r+`
+    ReadUpdate = 2,
+    /// `r+b`
+    ReadUpdateBinary = 3,
+    /// `w`
+    Write = 4,
+    /// `wb`
+    WriteBinary = 5,
+    /// `w+`
+    WriteUpdate = 6,
+    /// `w+b`
+    WriteUpdateBinary = 7,
+    /// `a`
+    Append = 8,
+    /// `ab`
+    AppendBinary = 9,
+    /// `a+`
+    AppendUpdate = 10,
+    /// `a+b`
+    AppendUpdateBinary = 11,
+}
+
+/// Writes a single character to the debug channel (SYS_WRITEC).
+#[inline]
+pub fn writec(c: u8) {
+    unsafe {
+        syscall(nr::SYS_WRITEC, &c);
+    }
+}
+
+/// Writes a null-terminated string to the debug channel (SYS_WRITE0).
+#[inline]
+pub fn write0(s: &CStr) {
+    unsafe {
+        syscall1(nr::SYS_WRITE0, s.as_ptr() as usize);
+    }
+}
+
+/// Opens a file on the host (SYS_OPEN).
+///
+/// The special path `:tt` refers to the console of the host. Opening it with [`OpenMode::Read`]
+/// yields stdin, with [`OpenMode::Write`] stdout and with [`OpenMode::Append`] stderr.
+///
+/// Returns `None` if the host failed to open the file.
+#[inline]
+pub fn open(path: &CStr, mode: OpenMode) -> Option<Handle> {
+    let path = path.to_bytes();
+    let block = [path.as_ptr() as usize, mode as usize, path.len()];
+    let ret = unsafe { syscall(nr::SYS_OPEN, &block) };
+
+    if ret as isize == -1 {
+        None
+    } else {
+        Some(Handle(ret))
+    }
+}
+
+/// Closes a file on the host (SYS_CLOSE).
+///
+/// Returns `false` if the host failed to close the file.
+#[inline]
+pub fn close(handle: Handle) -> bool {
+    let block = [handle.0];
+
+    unsafe { syscall(nr::SYS_CLOSE, &block) == 0 }
+}
+
+/// Writes the contents of `buf` to a file on the host (SYS_WRITE).
+///
+/// Returns the number of bytes that were written.
+#[inline]
+pub fn write(handle: Handle, buf: &[u8]) -> usize {
+    let block = [handle.0, buf.as_ptr() as usize, buf.len()];
+    let not_written = unsafe { syscall(nr::SYS_WRITE, &block) };
+
+    buf.len().saturating_sub(not_written)
+}
+
+/// Reads from a file on the host into `buf` (SYS_READ).
+///
+/// Returns the number of bytes that were read. A return value of `0` indicates end of file.
+#[inline]
+pub fn read(handle: Handle, buf: &mut [u8]) -> usize {
+    let block = [handle.0, buf.as_mut_ptr() as usize, buf.len()];
+    let not_read = unsafe { syscall(nr::SYS_READ, &block) };
+
+    buf.len().saturating_sub(not_read)
+}
+
+/// Returns the number of centiseconds since the execution started (SYS_CLOCK).
+#[inline]
+pub fn clock() -> Option<usize> {
+    let ret = unsafe { syscall1(nr::SYS_CLOCK, 0) };
+
+    if ret as isize == -1 {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+/// Returns the number of elapsed target ticks since the execution started (SYS_ELAPSED).
+///
+/// Use [`tickfreq`] to convert the value to seconds.
+#[inline]
+pub fn elapsed() -> Option<u64> {
+    let mut ticks: u64 = 0;
+    let ret = unsafe { syscall1(nr::SYS_ELAPSED, &mut ticks as *mut u64 as usize) };
+
+    if ret == 0 {
+        Some(ticks)
+    } else {
+        None
+    }
+}
+
+/// Returns the tick frequency of [`elapsed`] in ticks per second (SYS_TICKFREQ).
+#[inline]
+pub fn tickfreq() -> Option<usize> {
+    let ret = unsafe { syscall1(nr::SYS_TICKFREQ, 0) };
+
+    if ret as isize == -1 {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+/// Reads the command line used to start the execution into `buf` (SYS_GET_CMDLINE).
+///
+/// Returns the command line without its null terminator, or `None` if `buf` is too small or the
+/// host failed to provide it.
+#[inline]
+pub fn get_cmdline(buf: &mut [u8]) -> Option<&[u8]> {
+    let mut block = [buf.as_mut_ptr() as usize, buf.len()];
+    let ret = unsafe { syscall1(nr::SYS_GET_CMDLINE, block.as_mut_ptr() as usize) };
+
+    if ret == 0 {
+        let len = block[1].min(buf.len());
+        Some(&buf[..len])
+    } else {
+        None
+    }
+}
+
+/// Terminates the execution and reports `code` as the exit status to the host
+/// (SYS_EXIT_EXTENDED).
+///
+/// If the host does not stop the execution, this function spins forever.
+#[inline]
+pub fn exit(code: u32) -> ! {
+    let block = [ADP_STOPPED_APPLICATION_EXIT, code as usize];
+
+    unsafe {
+        syscall(nr::SYS_EXIT_EXTENDED, &block);
+    }
+
+    #[allow(clippy::empty_loop)]
+    loop {}
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Host I/O.
+
+use super::{Handle, OpenMode};
+use core::{ffi::CStr, fmt};
+
+/// A byte stream to the host, e.g. its stdout or stderr.
+#[derive(Copy, Clone, Debug)]
+pub struct HostStream {
+    handle: Handle,
+}
+
+impl HostStream {
+    /// Attempts to write the entire `buf` to the host.
+    ///
+    /// Returns `false` if the host stopped accepting data before all bytes were written.
+    pub fn write_all(&mut self, mut buf: &[u8]) -> bool {
+        while !buf.is_empty() {
+            match super::write(self.handle, buf) {
+                0 => return false,
+                n => buf = &buf[n..],
+            }
+        }
+
+        true
+    }
+}
+
+impl fmt::Write for HostStream {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.write_all(s.as_bytes()) {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+fn open_tt(mode: OpenMode) -> Option<HostStream> {
+    let tt = unsafe { CStr::from_bytes_with_nul_unchecked(b":tt\0") };
+
+    super::open(tt, mode).map(|handle| HostStream { handle })
+}
+
+/// Opens the host's stdout.
+pub fn hstdout() -> Option<HostStream> {
+    open_tt(OpenMode::Write)
+}
+
+/// Opens the host's stderr.
+pub fn hstderr() -> Option<HostStream> {
+    open_tt(OpenMode::Append)
+}
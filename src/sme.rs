@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Scalable Matrix Extension.
+//!
+//! Streaming mode control, streaming vector length management and context sizing for SME.
+//! Vector lengths are given in bytes.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::sme;
+//!
+//! if sme::is_implemented() {
+//!     sme::enable();
+//!
+//!     let svl = sme::streaming_vector_length();
+//!     let context_bytes = sme::context_size(svl, false);
+//!
+//!     unsafe {
+//!         sme::start();
+//!         // ... streaming SVE and ZA code.
+//!         sme::stop();
+//!     }
+//! }
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{CurrentEL, CPACR_EL1, ID_AA64PFR1_EL1, SMCR_EL1, SMCR_EL2, SMCR_EL3, SVCR},
+    sve::{self, Error},
+};
+use tock_registers::interfaces::{ReadWriteable, Readable};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::asm;
+
+/// Size of the SME2 ZT0 register in bytes.
+pub const ZT0_SIZE: usize = 64;
+
+/// Size of a context buffer that can hold the SME state for any streaming vector length.
+pub const MAX_CONTEXT_SIZE: usize = context_size(sve::MAX_VL, true);
+
+/// Return whether SME is implemented.
+pub fn is_implemented() -> bool {
+    ID_AA64PFR1_EL1.read(ID_AA64PFR1_EL1::SME) != 0
+}
+
+/// Return whether SME2, and with it the ZT0 register, is implemented.
+pub fn is_sme2_implemented() -> bool {
+    ID_AA64PFR1_EL1.read(ID_AA64PFR1_EL1::SME) >= 2
+}
+
+/// Stop trapping SME instructions and SVCR and SMCR_EL1 accesses at EL1 and EL0.
+pub fn enable() {
+    CPACR_EL1.modify(CPACR_EL1::SMEN::TrapNothing);
+    barrier::isb(barrier::SY);
+}
+
+/// Trap SME instructions and SVCR and SMCR_EL1 accesses at EL1 and EL0.
+pub fn disable() {
+    CPACR_EL1.modify(CPACR_EL1::SMEN::TrapEl0El1);
+    barrier::isb(barrier::SY);
+}
+
+/// Return the effective streaming vector length in bytes at the current Exception level.
+///
+/// SME must not be trapped at the current Exception level.
+/// The result is read again on every call, as it changes with writes to `SMCR_ELx.LEN`.
+#[inline(always)]
+pub fn streaming_vector_length() -> usize {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let svl: usize;
+            unsafe {
+                asm!(
+                    ".arch_extension sme",
+                    "rdsvl {v}, #1",
+                    v = out(reg) svl,
+                    options(nostack)
+                );
+            }
+            svl
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Limit the streaming vector length at EL1 and EL0 to at most `bytes`.
+pub fn set_max_streaming_vector_length_el1(bytes: usize) -> Result<(), Error> {
+    SMCR_EL1.modify(SMCR_EL1::LEN.val(sve::len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Limit the streaming vector length at EL2 and below to at most `bytes`.
+pub fn set_max_streaming_vector_length_el2(bytes: usize) -> Result<(), Error> {
+    SMCR_EL2.modify(SMCR_EL2::LEN.val(sve::len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Limit the streaming vector length at all Exception levels to at most `bytes`.
+pub fn set_max_streaming_vector_length_el3(bytes: usize) -> Result<(), Error> {
+    SMCR_EL3.modify(SMCR_EL3::LEN.val(sve::len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Return the largest streaming vector length in bytes available at the current Exception level.
+///
+/// Temporarily lifts the limit of the current Exception level's `SMCR_ELx` and restores it
+/// afterwards. At EL0, this returns the effective streaming vector length.
+pub fn max_streaming_vector_length() -> usize {
+    let max = (sve::MAX_VL / 16 - 1) as u64;
+
+    match CurrentEL.read_as_enum(CurrentEL::EL) {
+        Some(CurrentEL::EL::Value::EL1) => {
+            let len = SMCR_EL1.read(SMCR_EL1::LEN);
+            SMCR_EL1.modify(SMCR_EL1::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let svl = streaming_vector_length();
+            SMCR_EL1.modify(SMCR_EL1::LEN.val(len));
+            barrier::isb(barrier::SY);
+            svl
+        }
+        Some(CurrentEL::EL::Value::EL2) => {
+            let len = SMCR_EL2.read(SMCR_EL2::LEN);
+            SMCR_EL2.modify(SMCR_EL2::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let svl = streaming_vector_length();
+            SMCR_EL2.modify(SMCR_EL2::LEN.val(len));
+            barrier::isb(barrier::SY);
+            svl
+        }
+        Some(CurrentEL::EL::Value::EL3) => {
+            let len = SMCR_EL3.read(SMCR_EL3::LEN);
+            SMCR_EL3.modify(SMCR_EL3::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let svl = streaming_vector_length();
+            SMCR_EL3.modify(SMCR_EL3::LEN.val(len));
+            barrier::isb(barrier::SY);
+            svl
+        }
+        _ => streaming_vector_length(),
+    }
+}
+
+/// Return whether the PE is in Streaming SVE mode.
+pub fn is_streaming() -> bool {
+    SVCR.is_set(SVCR::SM)
+}
+
+/// Return whether ZA storage is enabled.
+pub fn is_za_enabled() -> bool {
+    SVCR.is_set(SVCR::ZA)
+}
+
+macro_rules! smstart_smstop {
+    ($(#[$attr:meta])* $name:ident, $insn:literal) => {
+        $(#[$attr])*
+        ///
+        /// # Safety
+        ///
+        /// The SVE, Advanced SIMD and floating-point registers are zeroed on entry to and exit
+        /// from Streaming SVE mode, and are declared clobbered. ZA is zeroed when it is enabled
+        /// or disabled, and the caller must ensure it holds no live data. In Streaming SVE mode,
+        /// most Advanced SIMD instructions are illegal unless `SMCR_ELx.FA64` is set.
+        #[inline(always)]
+        pub unsafe fn $name() {
+            match () {
+                #[cfg(target_arch = "aarch64")]
+                // Not `nomem`: the mode change affects which memory accesses are legal.
+                () => asm!(
+                    ".arch_extension sme",
+                    $insn,
+                    out("v0") _,
+                    out("v1") _,
+                    out("v2") _,
+                    out("v3") _,
+                    out("v4") _,
+                    out("v5") _,
+                    out("v6") _,
+                    out("v7") _,
+                    out("v8") _,
+                    out("v9") _,
+                    out("v10") _,
+                    out("v11") _,
+                    out("v12") _,
+                    out("v13") _,
+                    out("v14") _,
+                    out("v15") _,
+                    out("v16") _,
+                    out("v17") _,
+                    out("v18") _,
+                    out("v19") _,
+                    out("v20") _,
+                    out("v21") _,
+                    out("v22") _,
+                    out("v23") _,
+                    out("v24") _,
+                    out("v25") _,
+                    out("v26") _,
+                    out("v27") _,
+                    out("v28") _,
+                    out("v29") _,
+                    out("v30") _,
+                    out("v31") _,
+                    out("p0") _,
+                    out("p1") _,
+                    out("p2") _,
+                    out("p3") _,
+                    out("p4") _,
+                    out("p5") _,
+                    out("p6") _,
+                    out("p7") _,
+                    out("p8") _,
+                    out("p9") _,
+                    out("p10") _,
+                    out("p11") _,
+                    out("p12") _,
+                    out("p13") _,
+                    out("p14") _,
+                    out("p15") _,
+                    out("ffr") _,
+                    options(nostack)
+                ),
+
+                #[cfg(not(target_arch = "aarch64"))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+smstart_smstop!(
+    /// Enter Streaming SVE mode and enable ZA storage.
+    start,
+    "smstart"
+);
+
+smstart_smstop!(
+    /// Exit Streaming SVE mode and disable ZA storage.
+    stop,
+    "smstop"
+);
+
+smstart_smstop!(
+    /// Enter Streaming SVE mode.
+    start_streaming,
+    "smstart sm"
+);
+
+smstart_smstop!(
+    /// Exit Streaming SVE mode.
+    stop_streaming,
+    "smstop sm"
+);
+
+smstart_smstop!(
+    /// Enable ZA storage.
+    enable_za,
+    "smstart za"
+);
+
+smstart_smstop!(
+    /// Disable ZA storage.
+    disable_za,
+    "smstop za"
+);
+
+/// Return the number of bytes of the ZA array for a streaming vector length of `svl` bytes.
+pub const fn za_size(svl: usize) -> usize {
+    svl * svl
+}
+
+/// Return the number of bytes needed to save the SME state for a streaming vector length of `svl`
+/// bytes.
+///
+/// This covers the streaming SVE registers, ZA and, if `sme2` is set, ZT0.
+pub const fn context_size(svl: usize, sme2: bool) -> usize {
+    sve::context_size(svl) + za_size(svl) + if sme2 { ZT0_SIZE } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_sizes() {
+        assert_eq!(za_size(64), 4096);
+        assert_eq!(context_size(64, false), sve::context_size(64) + 4096);
+        assert_eq!(context_size(64, true), sve::context_size(64) + 4096 + 64);
+        assert_eq!(MAX_CONTEXT_SIZE, sve::MAX_CONTEXT_SIZE + 65536 + 64);
+    }
+}
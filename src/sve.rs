@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Scalable Vector Extension.
+//!
+//! Vector length management and context sizing for SVE. Vector lengths are given in bytes.
+//!
+//! For lazy context switching, leave SVE disabled with [`disable`] and [`enable`] it from the
+//! handler of the resulting trap, after saving the previous owner's context into a buffer of
+//! [`context_size`] bytes.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::sve;
+//!
+//! if sve::is_implemented() {
+//!     sve::enable();
+//!
+//!     // Limit EL1 and EL0 to 256-bit vectors.
+//!     sve::set_max_vector_length_el1(32).unwrap();
+//!
+//!     let vl = sve::vector_length();
+//!     let context_bytes = sve::context_size(vl);
+//! }
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{CurrentEL, CPACR_EL1, ID_AA64PFR0_EL1, ZCR_EL1, ZCR_EL2, ZCR_EL3},
+};
+use tock_registers::interfaces::{ReadWriteable, Readable};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::asm;
+
+/// The architectural maximum vector length in bytes.
+pub const MAX_VL: usize = 256;
+
+/// Size of a context buffer that can hold the SVE registers for any vector length.
+pub const MAX_CONTEXT_SIZE: usize = context_size(MAX_VL);
+
+/// Errors reported when constraining the vector length.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The vector length is not a multiple of 16 bytes between 16 and [`MAX_VL`].
+    InvalidLength,
+}
+
+/// Return whether SVE is implemented.
+pub fn is_implemented() -> bool {
+    ID_AA64PFR0_EL1.read(ID_AA64PFR0_EL1::SVE) != 0
+}
+
+/// Stop trapping SVE instructions and ZCR_EL1 accesses at EL1 and EL0.
+pub fn enable() {
+    CPACR_EL1.modify(CPACR_EL1::ZEN::TrapNothing);
+    barrier::isb(barrier::SY);
+}
+
+/// Trap SVE instructions and ZCR_EL1 accesses at EL1 and EL0.
+pub fn disable() {
+    CPACR_EL1.modify(CPACR_EL1::ZEN::TrapEl0El1);
+    barrier::isb(barrier::SY);
+}
+
+/// Return the effective vector length in bytes at the current Exception level.
+///
+/// SVE must not be trapped at the current Exception level.
+/// The result is read again on every call, as it changes with writes to `ZCR_ELx.LEN`.
+#[inline(always)]
+pub fn vector_length() -> usize {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => {
+            let vl: usize;
+            unsafe {
+                asm!(
+                    ".arch_extension sve",
+                    "rdvl {v}, #1",
+                    v = out(reg) vl,
+                    options(nostack)
+                );
+            }
+            vl
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+/// Return the `LEN` field encoding for a vector length of `bytes`.
+pub(crate) fn len_field(bytes: usize) -> Result<u64, Error> {
+    if bytes == 0 || bytes > MAX_VL || bytes & 15 != 0 {
+        return Err(Error::InvalidLength);
+    }
+
+    Ok((bytes / 16 - 1) as u64)
+}
+
+/// Limit the vector length at EL1 and EL0 to at most `bytes`.
+pub fn set_max_vector_length_el1(bytes: usize) -> Result<(), Error> {
+    ZCR_EL1.modify(ZCR_EL1::LEN.val(len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Limit the vector length at EL2 and below to at most `bytes`.
+pub fn set_max_vector_length_el2(bytes: usize) -> Result<(), Error> {
+    ZCR_EL2.modify(ZCR_EL2::LEN.val(len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Limit the vector length at all Exception levels to at most `bytes`.
+pub fn set_max_vector_length_el3(bytes: usize) -> Result<(), Error> {
+    ZCR_EL3.modify(ZCR_EL3::LEN.val(len_field(bytes)?));
+    barrier::isb(barrier::SY);
+
+    Ok(())
+}
+
+/// Return the largest vector length in bytes available at the current Exception level.
+///
+/// Temporarily lifts the limit of the current Exception level's `ZCR_ELx` and restores it
+/// afterwards. At EL0, this returns the effective vector length.
+pub fn max_vector_length() -> usize {
+    let max = (MAX_VL / 16 - 1) as u64;
+
+    match CurrentEL.read_as_enum(CurrentEL::EL) {
+        Some(CurrentEL::EL::Value::EL1) => {
+            let len = ZCR_EL1.read(ZCR_EL1::LEN);
+            ZCR_EL1.modify(ZCR_EL1::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let vl = vector_length();
+            ZCR_EL1.modify(ZCR_EL1::LEN.val(len));
+            barrier::isb(barrier::SY);
+            vl
+        }
+        Some(CurrentEL::EL::Value::EL2) => {
+            let len = ZCR_EL2.read(ZCR_EL2::LEN);
+            ZCR_EL2.modify(ZCR_EL2::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let vl = vector_length();
+            ZCR_EL2.modify(ZCR_EL2::LEN.val(len));
+            barrier::isb(barrier::SY);
+            vl
+        }
+        Some(CurrentEL::EL::Value::EL3) => {
+            let len = ZCR_EL3.read(ZCR_EL3::LEN);
+            ZCR_EL3.modify(ZCR_EL3::LEN.val(max));
+            barrier::isb(barrier::SY);
+            let vl = vector_length();
+            ZCR_EL3.modify(ZCR_EL3::LEN.val(len));
+            barrier::isb(barrier::SY);
+            vl
+        }
+        _ => vector_length(),
+    }
+}
+
+/// Return the number of bytes needed to save the SVE registers for a vector length of `vl` bytes.
+///
+/// This covers the 32 Z registers of `vl` bytes each, and the 16 P registers and FFR of `vl / 8`
+/// bytes each.
+pub const fn context_size(vl: usize) -> usize {
+    32 * vl + 17 * (vl / 8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_length_encoding() {
+        assert_eq!(len_field(16), Ok(0));
+        assert_eq!(len_field(64), Ok(3));
+        assert_eq!(len_field(256), Ok(15));
+        assert_eq!(len_field(0), Err(Error::InvalidLength));
+        assert_eq!(len_field(24), Err(Error::InvalidLength));
+        assert_eq!(len_field(272), Err(Error::InvalidLength));
+    }
+
+    #[test]
+    fn context_sizes() {
+        assert_eq!(context_size(16), 512 + 34);
+        assert_eq!(MAX_CONTEXT_SIZE, 8192 + 544);
+    }
+}
@@ -0,0 +1,347 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! System register encodings and trap emulation.
+//!
+//! [`Encoding`] maps between the `op0, op1, CRn, CRm, op2` encoding of a system register and its
+//! name, for every register defined in the `registers` module. [`dispatch`] emulates an `MSR` or
+//! `MRS` access that was trapped to EL2 and reported with `ESR_EL2.EC == TrappedMsrMrs`.
+//!
+//! # Example
+//!
+//! ```
+//! use cortex_a::sysreg::{self, Encoding, Handler};
+//!
+//! struct Vcpu {
+//!     actlr: u64,
+//! }
+//!
+//! const HANDLERS: &[Handler<Vcpu>] = &[Handler {
+//!     encoding: Encoding::new(3, 0, 1, 0, 1),
+//!     read: Some(|vcpu| vcpu.actlr),
+//!     write: Some(|vcpu, value| vcpu.actlr = value),
+//! }];
+//!
+//! let mut vcpu = Vcpu { actlr: 0x42 };
+//! let mut x = [0; 31];
+//! let mut elr = 0x8_0000;
+//!
+//! // `MRS X3, ACTLR_EL1` trapped with ESR_EL2 = 0x6232_0461.
+//! let access = sysreg::dispatch(HANDLERS, &mut vcpu, 0x6232_0461, &mut x, &mut elr).unwrap();
+//!
+//! assert_eq!(access.encoding.name(), Some("ACTLR_EL1"));
+//! assert_eq!(x[3], 0x42);
+//! assert_eq!(elr, 0x8_0004);
+//! ```
+
+mod table;
+
+use core::fmt;
+
+/// Exception Class of trapped `MSR`, `MRS` and System instructions.
+const EC_TRAPPED_MSR_MRS: u64 = 0b01_1000;
+
+/// The encoding of a system register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Encoding {
+    pub op0: u8,
+    pub op1: u8,
+    pub crn: u8,
+    pub crm: u8,
+    pub op2: u8,
+}
+
+impl Encoding {
+    /// Create an encoding from its fields.
+    pub const fn new(op0: u8, op1: u8, crn: u8, crm: u8, op2: u8) -> Encoding {
+        Encoding {
+            op0,
+            op1,
+            crn,
+            crm,
+            op2,
+        }
+    }
+
+    /// Return the encoding of the register called `name`, as spelled in the `registers` module.
+    pub fn from_name(name: &str) -> Option<Encoding> {
+        table::REGISTERS
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(encoding, _)| *encoding)
+    }
+
+    /// Return the name of the register with this encoding, if it is defined in the `registers`
+    /// module.
+    pub fn name(&self) -> Option<&'static str> {
+        table::REGISTERS
+            .binary_search_by(|(encoding, _)| encoding.cmp(self))
+            .ok()
+            .map(|i| table::REGISTERS[i].1)
+    }
+}
+
+impl fmt::Display for Encoding {
+    /// Formats the encoding in the generic `S<op0>_<op1>_C<n>_C<m>_<op2>` syntax.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "S{}_{}_C{}_C{}_{}",
+            self.op0, self.op1, self.crn, self.crm, self.op2
+        )
+    }
+}
+
+/// A trapped `MSR` or `MRS` access.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Access {
+    /// The accessed register.
+    pub encoding: Encoding,
+    /// The general purpose register transferred, where 31 is the zero register.
+    pub rt: u8,
+    /// Whether the access is an `MRS`, i.e. a read of the system register.
+    pub is_read: bool,
+}
+
+impl Access {
+    /// Decode the ISS of a trapped `MSR` or `MRS` access.
+    pub const fn from_iss(iss: u32) -> Access {
+        Access {
+            encoding: Encoding {
+                op0: ((iss >> 20) & 0b11) as u8,
+                op2: ((iss >> 17) & 0b111) as u8,
+                op1: ((iss >> 14) & 0b111) as u8,
+                crn: ((iss >> 10) & 0b1111) as u8,
+                crm: ((iss >> 1) & 0b1111) as u8,
+            },
+            rt: ((iss >> 5) & 0b1_1111) as u8,
+            is_read: iss & 1 != 0,
+        }
+    }
+
+    /// Decode a syndrome value as reported in `ESR_ELx`.
+    ///
+    /// Returns `None` if the Exception Class is not `TrappedMsrMrs`.
+    pub const fn from_esr(esr: u64) -> Option<Access> {
+        if (esr >> 26) & 0b11_1111 != EC_TRAPPED_MSR_MRS {
+            return None;
+        }
+
+        Some(Access::from_iss((esr & 0x1ff_ffff) as u32))
+    }
+}
+
+/// Emulation of a trapped system register.
+///
+/// `C` is the caller's vCPU state, handed to the handlers.
+pub struct Handler<C> {
+    /// The emulated register.
+    pub encoding: Encoding,
+    /// Called for `MRS` accesses. The access is unhandled if this is `None`.
+    pub read: Option<fn(&mut C) -> u64>,
+    /// Called for `MSR` accesses. The access is unhandled if this is `None`.
+    pub write: Option<fn(&mut C, u64)>,
+}
+
+/// Errors reported by [`dispatch`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The syndrome does not report a trapped `MSR` or `MRS` access.
+    NotSysRegTrap,
+    /// No handler emulates the access.
+    Unhandled(Access),
+}
+
+/// Emulate the trapped `MSR` or `MRS` access reported by the syndrome `esr`.
+///
+/// Looks up the handler for the accessed register in `handlers`, transfers the value from or to
+/// the guest's saved general purpose registers `x`, and advances the guest's saved `elr` past the
+/// trapped instruction. On error, neither `x` nor `elr` are modified.
+pub fn dispatch<C>(
+    handlers: &[Handler<C>],
+    ctx: &mut C,
+    esr: u64,
+    x: &mut [u64; 31],
+    elr: &mut u64,
+) -> Result<Access, Error> {
+    let access = Access::from_esr(esr).ok_or(Error::NotSysRegTrap)?;
+    let handler = handlers
+        .iter()
+        .find(|h| h.encoding == access.encoding)
+        .ok_or(Error::Unhandled(access))?;
+    let rt = access.rt as usize;
+
+    if access.is_read {
+        let read = handler.read.ok_or(Error::Unhandled(access))?;
+        let value = read(ctx);
+        if rt != 31 {
+            x[rt] = value;
+        }
+    } else {
+        let write = handler.write.ok_or(Error::Unhandled(access))?;
+        write(ctx, if rt != 31 { x[rt] } else { 0 });
+    }
+
+    // ESR_ELx.IL distinguishes 32-bit from 16-bit trapped instructions.
+    *elr = elr.wrapping_add(if esr & (1 << 25) != 0 { 4 } else { 2 });
+
+    Ok(access)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted_and_unique() {
+        assert!(table::REGISTERS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn names() {
+        let sctlr = Encoding::new(3, 0, 1, 0, 0);
+        assert_eq!(sctlr.name(), Some("SCTLR_EL1"));
+        assert_eq!(Encoding::from_name("SCTLR_EL1"), Some(sctlr));
+        assert_eq!(
+            Encoding::from_name("SCTLR_EL12"),
+            Some(Encoding::new(3, 5, 1, 0, 0))
+        );
+        assert_eq!(
+            Encoding::from_name("CurrentEL"),
+            Some(Encoding::new(3, 0, 4, 2, 2))
+        );
+        assert_eq!(Encoding::new(3, 7, 15, 15, 7).name(), None);
+
+        for (encoding, name) in table::REGISTERS.iter() {
+            assert_eq!(Encoding::from_name(name), Some(*encoding));
+            assert_eq!(encoding.name(), Some(*name));
+        }
+    }
+
+    struct Vcpu {
+        value: u64,
+    }
+
+    const HANDLERS: &[Handler<Vcpu>] = &[
+        Handler {
+            encoding: Encoding::new(3, 0, 13, 0, 1),
+            read: Some(|vcpu| vcpu.value),
+            write: Some(|vcpu, value| vcpu.value = value),
+        },
+        Handler {
+            encoding: Encoding::new(3, 0, 0, 0, 0),
+            read: Some(|_| 0x410f_d034),
+            write: None,
+        },
+    ];
+
+    /// Syndrome of a trapped access with IL set.
+    fn esr(encoding: Encoding, rt: u8, is_read: bool) -> u64 {
+        (EC_TRAPPED_MSR_MRS << 26)
+            | (1 << 25)
+            | ((encoding.op0 as u64) << 20)
+            | ((encoding.op2 as u64) << 17)
+            | ((encoding.op1 as u64) << 14)
+            | ((encoding.crn as u64) << 10)
+            | ((rt as u64) << 5)
+            | ((encoding.crm as u64) << 1)
+            | is_read as u64
+    }
+
+    #[test]
+    fn dispatch_reads_and_writes() {
+        let contextidr = Encoding::from_name("CONTEXTIDR_EL1").unwrap();
+        let mut vcpu = Vcpu { value: 7 };
+        let mut x = [0; 31];
+        let mut elr = 0x1000;
+
+        let access = dispatch(
+            HANDLERS,
+            &mut vcpu,
+            esr(contextidr, 5, true),
+            &mut x,
+            &mut elr,
+        );
+        assert_eq!(
+            access,
+            Ok(Access {
+                encoding: contextidr,
+                rt: 5,
+                is_read: true
+            })
+        );
+        assert_eq!(x[5], 7);
+        assert_eq!(elr, 0x1004);
+
+        x[9] = 0x55;
+        dispatch(
+            HANDLERS,
+            &mut vcpu,
+            esr(contextidr, 9, false),
+            &mut x,
+            &mut elr,
+        )
+        .unwrap();
+        assert_eq!(vcpu.value, 0x55);
+        assert_eq!(elr, 0x1008);
+
+        // The zero register.
+        dispatch(
+            HANDLERS,
+            &mut vcpu,
+            esr(contextidr, 31, false),
+            &mut x,
+            &mut elr,
+        )
+        .unwrap();
+        assert_eq!(vcpu.value, 0);
+        dispatch(
+            HANDLERS,
+            &mut vcpu,
+            esr(contextidr, 31, true),
+            &mut x,
+            &mut elr,
+        )
+        .unwrap();
+        assert_eq!(x, {
+            let mut expected = [0; 31];
+            expected[5] = 7;
+            expected[9] = 0x55;
+            expected
+        });
+    }
+
+    #[test]
+    fn dispatch_errors() {
+        let midr = Encoding::from_name("MIDR_EL1").unwrap();
+        let mut vcpu = Vcpu { value: 0 };
+        let mut x = [0; 31];
+        let mut elr = 0x1000;
+
+        let write = esr(midr, 0, false);
+        assert_eq!(
+            dispatch(HANDLERS, &mut vcpu, write, &mut x, &mut elr),
+            Err(Error::Unhandled(Access::from_esr(write).unwrap()))
+        );
+
+        let unknown = esr(Encoding::from_name("TCR_EL1").unwrap(), 0, true);
+        assert!(matches!(
+            dispatch(HANDLERS, &mut vcpu, unknown, &mut x, &mut elr),
+            Err(Error::Unhandled(_))
+        ));
+
+        // A data abort.
+        assert_eq!(
+            dispatch(HANDLERS, &mut vcpu, 0x9200_0046, &mut x, &mut elr),
+            Err(Error::NotSysRegTrap)
+        );
+        assert_eq!(elr, 0x1000);
+
+        dispatch(HANDLERS, &mut vcpu, esr(midr, 0, true), &mut x, &mut elr).unwrap();
+        assert_eq!(x[0], 0x410f_d034);
+    }
+}
@@ -0,0 +1,322 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Encodings of the system registers defined in `registers`, sorted by encoding.
+
+use super::Encoding;
+
+pub(super) static REGISTERS: [(Encoding, &str); 309] = [
+    (Encoding::new(2, 0, 0, 0, 4), "DBGBVR0_EL1"),
+    (Encoding::new(2, 0, 0, 0, 5), "DBGBCR0_EL1"),
+    (Encoding::new(2, 0, 0, 0, 6), "DBGWVR0_EL1"),
+    (Encoding::new(2, 0, 0, 0, 7), "DBGWCR0_EL1"),
+    (Encoding::new(2, 0, 0, 1, 4), "DBGBVR1_EL1"),
+    (Encoding::new(2, 0, 0, 1, 5), "DBGBCR1_EL1"),
+    (Encoding::new(2, 0, 0, 1, 6), "DBGWVR1_EL1"),
+    (Encoding::new(2, 0, 0, 1, 7), "DBGWCR1_EL1"),
+    (Encoding::new(2, 0, 0, 2, 2), "MDSCR_EL1"),
+    (Encoding::new(2, 0, 0, 2, 4), "DBGBVR2_EL1"),
+    (Encoding::new(2, 0, 0, 2, 5), "DBGBCR2_EL1"),
+    (Encoding::new(2, 0, 0, 2, 6), "DBGWVR2_EL1"),
+    (Encoding::new(2, 0, 0, 2, 7), "DBGWCR2_EL1"),
+    (Encoding::new(2, 0, 0, 3, 4), "DBGBVR3_EL1"),
+    (Encoding::new(2, 0, 0, 3, 5), "DBGBCR3_EL1"),
+    (Encoding::new(2, 0, 0, 3, 6), "DBGWVR3_EL1"),
+    (Encoding::new(2, 0, 0, 3, 7), "DBGWCR3_EL1"),
+    (Encoding::new(2, 0, 0, 4, 4), "DBGBVR4_EL1"),
+    (Encoding::new(2, 0, 0, 4, 5), "DBGBCR4_EL1"),
+    (Encoding::new(2, 0, 0, 4, 6), "DBGWVR4_EL1"),
+    (Encoding::new(2, 0, 0, 4, 7), "DBGWCR4_EL1"),
+    (Encoding::new(2, 0, 0, 5, 4), "DBGBVR5_EL1"),
+    (Encoding::new(2, 0, 0, 5, 5), "DBGBCR5_EL1"),
+    (Encoding::new(2, 0, 0, 5, 6), "DBGWVR5_EL1"),
+    (Encoding::new(2, 0, 0, 5, 7), "DBGWCR5_EL1"),
+    (Encoding::new(2, 0, 0, 6, 4), "DBGBVR6_EL1"),
+    (Encoding::new(2, 0, 0, 6, 5), "DBGBCR6_EL1"),
+    (Encoding::new(2, 0, 0, 6, 6), "DBGWVR6_EL1"),
+    (Encoding::new(2, 0, 0, 6, 7), "DBGWCR6_EL1"),
+    (Encoding::new(2, 0, 0, 7, 4), "DBGBVR7_EL1"),
+    (Encoding::new(2, 0, 0, 7, 5), "DBGBCR7_EL1"),
+    (Encoding::new(2, 0, 0, 7, 6), "DBGWVR7_EL1"),
+    (Encoding::new(2, 0, 0, 7, 7), "DBGWCR7_EL1"),
+    (Encoding::new(2, 0, 0, 8, 4), "DBGBVR8_EL1"),
+    (Encoding::new(2, 0, 0, 8, 5), "DBGBCR8_EL1"),
+    (Encoding::new(2, 0, 0, 8, 6), "DBGWVR8_EL1"),
+    (Encoding::new(2, 0, 0, 8, 7), "DBGWCR8_EL1"),
+    (Encoding::new(2, 0, 0, 9, 4), "DBGBVR9_EL1"),
+    (Encoding::new(2, 0, 0, 9, 5), "DBGBCR9_EL1"),
+    (Encoding::new(2, 0, 0, 9, 6), "DBGWVR9_EL1"),
+    (Encoding::new(2, 0, 0, 9, 7), "DBGWCR9_EL1"),
+    (Encoding::new(2, 0, 0, 10, 4), "DBGBVR10_EL1"),
+    (Encoding::new(2, 0, 0, 10, 5), "DBGBCR10_EL1"),
+    (Encoding::new(2, 0, 0, 10, 6), "DBGWVR10_EL1"),
+    (Encoding::new(2, 0, 0, 10, 7), "DBGWCR10_EL1"),
+    (Encoding::new(2, 0, 0, 11, 4), "DBGBVR11_EL1"),
+    (Encoding::new(2, 0, 0, 11, 5), "DBGBCR11_EL1"),
+    (Encoding::new(2, 0, 0, 11, 6), "DBGWVR11_EL1"),
+    (Encoding::new(2, 0, 0, 11, 7), "DBGWCR11_EL1"),
+    (Encoding::new(2, 0, 0, 12, 4), "DBGBVR12_EL1"),
+    (Encoding::new(2, 0, 0, 12, 5), "DBGBCR12_EL1"),
+    (Encoding::new(2, 0, 0, 12, 6), "DBGWVR12_EL1"),
+    (Encoding::new(2, 0, 0, 12, 7), "DBGWCR12_EL1"),
+    (Encoding::new(2, 0, 0, 13, 4), "DBGBVR13_EL1"),
+    (Encoding::new(2, 0, 0, 13, 5), "DBGBCR13_EL1"),
+    (Encoding::new(2, 0, 0, 13, 6), "DBGWVR13_EL1"),
+    (Encoding::new(2, 0, 0, 13, 7), "DBGWCR13_EL1"),
+    (Encoding::new(2, 0, 0, 14, 4), "DBGBVR14_EL1"),
+    (Encoding::new(2, 0, 0, 14, 5), "DBGBCR14_EL1"),
+    (Encoding::new(2, 0, 0, 14, 6), "DBGWVR14_EL1"),
+    (Encoding::new(2, 0, 0, 14, 7), "DBGWCR14_EL1"),
+    (Encoding::new(2, 0, 0, 15, 4), "DBGBVR15_EL1"),
+    (Encoding::new(2, 0, 0, 15, 5), "DBGBCR15_EL1"),
+    (Encoding::new(2, 0, 0, 15, 6), "DBGWVR15_EL1"),
+    (Encoding::new(2, 0, 0, 15, 7), "DBGWCR15_EL1"),
+    (Encoding::new(2, 0, 1, 0, 4), "OSLAR_EL1"),
+    (Encoding::new(2, 0, 1, 1, 4), "OSLSR_EL1"),
+    (Encoding::new(2, 0, 1, 3, 4), "OSDLR_EL1"),
+    (Encoding::new(2, 3, 0, 1, 0), "MDCCSR_EL0"),
+    (Encoding::new(3, 0, 0, 0, 0), "MIDR_EL1"),
+    (Encoding::new(3, 0, 0, 0, 5), "MPIDR_EL1"),
+    (Encoding::new(3, 0, 0, 4, 0), "ID_AA64PFR0_EL1"),
+    (Encoding::new(3, 0, 0, 4, 1), "ID_AA64PFR1_EL1"),
+    (Encoding::new(3, 0, 0, 5, 0), "ID_AA64DFR0_EL1"),
+    (Encoding::new(3, 0, 0, 6, 0), "ID_AA64ISAR0_EL1"),
+    (Encoding::new(3, 0, 0, 6, 1), "ID_AA64ISAR1_EL1"),
+    (Encoding::new(3, 0, 0, 6, 2), "ID_AA64ISAR2_EL1"),
+    (Encoding::new(3, 0, 0, 7, 0), "ID_AA64MMFR0_EL1"),
+    (Encoding::new(3, 0, 0, 7, 1), "ID_AA64MMFR1_EL1"),
+    (Encoding::new(3, 0, 0, 7, 2), "ID_AA64MMFR2_EL1"),
+    (Encoding::new(3, 0, 1, 0, 0), "SCTLR_EL1"),
+    (Encoding::new(3, 0, 1, 0, 1), "ACTLR_EL1"),
+    (Encoding::new(3, 0, 1, 0, 2), "CPACR_EL1"),
+    (Encoding::new(3, 0, 1, 0, 5), "RGSR_EL1"),
+    (Encoding::new(3, 0, 1, 0, 6), "GCR_EL1"),
+    (Encoding::new(3, 0, 1, 2, 0), "ZCR_EL1"),
+    (Encoding::new(3, 0, 1, 2, 4), "SMPRI_EL1"),
+    (Encoding::new(3, 0, 1, 2, 6), "SMCR_EL1"),
+    (Encoding::new(3, 0, 2, 0, 0), "TTBR0_EL1"),
+    (Encoding::new(3, 0, 2, 0, 1), "TTBR1_EL1"),
+    (Encoding::new(3, 0, 2, 0, 2), "TCR_EL1"),
+    (Encoding::new(3, 0, 2, 1, 0), "APIAKEYLO_EL1"),
+    (Encoding::new(3, 0, 2, 1, 1), "APIAKEYHI_EL1"),
+    (Encoding::new(3, 0, 2, 1, 2), "APIBKEYLO_EL1"),
+    (Encoding::new(3, 0, 2, 1, 3), "APIBKEYHI_EL1"),
+    (Encoding::new(3, 0, 2, 2, 0), "APDAKEYLO_EL1"),
+    (Encoding::new(3, 0, 2, 2, 1), "APDAKEYHI_EL1"),
+    (Encoding::new(3, 0, 2, 2, 2), "APDBKEYLO_EL1"),
+    (Encoding::new(3, 0, 2, 2, 3), "APDBKEYHI_EL1"),
+    (Encoding::new(3, 0, 2, 3, 0), "APGAKEYLO_EL1"),
+    (Encoding::new(3, 0, 2, 3, 1), "APGAKEYHI_EL1"),
+    (Encoding::new(3, 0, 4, 0, 0), "SPSR_EL1"),
+    (Encoding::new(3, 0, 4, 0, 1), "ELR_EL1"),
+    (Encoding::new(3, 0, 4, 1, 0), "SP_EL0"),
+    (Encoding::new(3, 0, 4, 2, 0), "SPSel"),
+    (Encoding::new(3, 0, 4, 2, 2), "CurrentEL"),
+    (Encoding::new(3, 0, 4, 2, 3), "PAN"),
+    (Encoding::new(3, 0, 4, 2, 4), "UAO"),
+    (Encoding::new(3, 0, 4, 3, 0), "ALLINT"),
+    (Encoding::new(3, 0, 5, 1, 0), "AFSR0_EL1"),
+    (Encoding::new(3, 0, 5, 1, 1), "AFSR1_EL1"),
+    (Encoding::new(3, 0, 5, 2, 0), "ESR_EL1"),
+    (Encoding::new(3, 0, 5, 6, 0), "TFSR_EL1"),
+    (Encoding::new(3, 0, 5, 6, 1), "TFSRE0_EL1"),
+    (Encoding::new(3, 0, 6, 0, 0), "FAR_EL1"),
+    (Encoding::new(3, 0, 7, 4, 0), "PAR_EL1"),
+    (Encoding::new(3, 0, 9, 14, 1), "PMINTENSET_EL1"),
+    (Encoding::new(3, 0, 9, 14, 2), "PMINTENCLR_EL1"),
+    (Encoding::new(3, 0, 10, 2, 0), "MAIR_EL1"),
+    (Encoding::new(3, 0, 10, 3, 0), "AMAIR_EL1"),
+    (Encoding::new(3, 0, 12, 0, 0), "VBAR_EL1"),
+    (Encoding::new(3, 0, 13, 0, 1), "CONTEXTIDR_EL1"),
+    (Encoding::new(3, 0, 13, 0, 4), "TPIDR_EL1"),
+    (Encoding::new(3, 0, 14, 1, 0), "CNTKCTL_EL1"),
+    (Encoding::new(3, 1, 0, 0, 0), "CCSIDR_EL1"),
+    (Encoding::new(3, 1, 0, 0, 1), "CLIDR_EL1"),
+    (Encoding::new(3, 1, 0, 0, 4), "GMID_EL1"),
+    (Encoding::new(3, 1, 0, 0, 6), "SMIDR_EL1"),
+    (Encoding::new(3, 2, 0, 0, 0), "CSSELR_EL1"),
+    (Encoding::new(3, 3, 4, 2, 0), "NZCV"),
+    (Encoding::new(3, 3, 4, 2, 1), "DAIF"),
+    (Encoding::new(3, 3, 4, 2, 2), "SVCR"),
+    (Encoding::new(3, 3, 4, 2, 5), "DIT"),
+    (Encoding::new(3, 3, 4, 2, 6), "SSBS"),
+    (Encoding::new(3, 3, 4, 2, 7), "TCO"),
+    (Encoding::new(3, 3, 4, 4, 0), "FPCR"),
+    (Encoding::new(3, 3, 4, 4, 1), "FPSR"),
+    (Encoding::new(3, 3, 9, 12, 0), "PMCR_EL0"),
+    (Encoding::new(3, 3, 9, 12, 1), "PMCNTENSET_EL0"),
+    (Encoding::new(3, 3, 9, 12, 2), "PMCNTENCLR_EL0"),
+    (Encoding::new(3, 3, 9, 12, 3), "PMOVSCLR_EL0"),
+    (Encoding::new(3, 3, 9, 12, 5), "PMSELR_EL0"),
+    (Encoding::new(3, 3, 9, 12, 6), "PMCEID0_EL0"),
+    (Encoding::new(3, 3, 9, 12, 7), "PMCEID1_EL0"),
+    (Encoding::new(3, 3, 9, 13, 0), "PMCCNTR_EL0"),
+    (Encoding::new(3, 3, 9, 13, 1), "PMXEVTYPER_EL0"),
+    (Encoding::new(3, 3, 9, 13, 2), "PMXEVCNTR_EL0"),
+    (Encoding::new(3, 3, 9, 14, 0), "PMUSERENR_EL0"),
+    (Encoding::new(3, 3, 9, 14, 3), "PMOVSSET_EL0"),
+    (Encoding::new(3, 3, 13, 0, 2), "TPIDR_EL0"),
+    (Encoding::new(3, 3, 13, 0, 3), "TPIDRRO_EL0"),
+    (Encoding::new(3, 3, 14, 0, 0), "CNTFRQ_EL0"),
+    (Encoding::new(3, 3, 14, 0, 1), "CNTPCT_EL0"),
+    (Encoding::new(3, 3, 14, 0, 2), "CNTVCT_EL0"),
+    (Encoding::new(3, 3, 14, 2, 0), "CNTP_TVAL_EL0"),
+    (Encoding::new(3, 3, 14, 2, 1), "CNTP_CTL_EL0"),
+    (Encoding::new(3, 3, 14, 2, 2), "CNTP_CVAL_EL0"),
+    (Encoding::new(3, 3, 14, 3, 0), "CNTV_TVAL_EL0"),
+    (Encoding::new(3, 3, 14, 3, 1), "CNTV_CTL_EL0"),
+    (Encoding::new(3, 3, 14, 3, 2), "CNTV_CVAL_EL0"),
+    (Encoding::new(3, 3, 14, 8, 0), "PMEVCNTR0_EL0"),
+    (Encoding::new(3, 3, 14, 8, 1), "PMEVCNTR1_EL0"),
+    (Encoding::new(3, 3, 14, 8, 2), "PMEVCNTR2_EL0"),
+    (Encoding::new(3, 3, 14, 8, 3), "PMEVCNTR3_EL0"),
+    (Encoding::new(3, 3, 14, 8, 4), "PMEVCNTR4_EL0"),
+    (Encoding::new(3, 3, 14, 8, 5), "PMEVCNTR5_EL0"),
+    (Encoding::new(3, 3, 14, 8, 6), "PMEVCNTR6_EL0"),
+    (Encoding::new(3, 3, 14, 8, 7), "PMEVCNTR7_EL0"),
+    (Encoding::new(3, 3, 14, 9, 0), "PMEVCNTR8_EL0"),
+    (Encoding::new(3, 3, 14, 9, 1), "PMEVCNTR9_EL0"),
+    (Encoding::new(3, 3, 14, 9, 2), "PMEVCNTR10_EL0"),
+    (Encoding::new(3, 3, 14, 9, 3), "PMEVCNTR11_EL0"),
+    (Encoding::new(3, 3, 14, 9, 4), "PMEVCNTR12_EL0"),
+    (Encoding::new(3, 3, 14, 9, 5), "PMEVCNTR13_EL0"),
+    (Encoding::new(3, 3, 14, 9, 6), "PMEVCNTR14_EL0"),
+    (Encoding::new(3, 3, 14, 9, 7), "PMEVCNTR15_EL0"),
+    (Encoding::new(3, 3, 14, 10, 0), "PMEVCNTR16_EL0"),
+    (Encoding::new(3, 3, 14, 10, 1), "PMEVCNTR17_EL0"),
+    (Encoding::new(3, 3, 14, 10, 2), "PMEVCNTR18_EL0"),
+    (Encoding::new(3, 3, 14, 10, 3), "PMEVCNTR19_EL0"),
+    (Encoding::new(3, 3, 14, 10, 4), "PMEVCNTR20_EL0"),
+    (Encoding::new(3, 3, 14, 10, 5), "PMEVCNTR21_EL0"),
+    (Encoding::new(3, 3, 14, 10, 6), "PMEVCNTR22_EL0"),
+    (Encoding::new(3, 3, 14, 10, 7), "PMEVCNTR23_EL0"),
+    (Encoding::new(3, 3, 14, 11, 0), "PMEVCNTR24_EL0"),
+    (Encoding::new(3, 3, 14, 11, 1), "PMEVCNTR25_EL0"),
+    (Encoding::new(3, 3, 14, 11, 2), "PMEVCNTR26_EL0"),
+    (Encoding::new(3, 3, 14, 11, 3), "PMEVCNTR27_EL0"),
+    (Encoding::new(3, 3, 14, 11, 4), "PMEVCNTR28_EL0"),
+    (Encoding::new(3, 3, 14, 11, 5), "PMEVCNTR29_EL0"),
+    (Encoding::new(3, 3, 14, 11, 6), "PMEVCNTR30_EL0"),
+    (Encoding::new(3, 3, 14, 12, 0), "PMEVTYPER0_EL0"),
+    (Encoding::new(3, 3, 14, 12, 1), "PMEVTYPER1_EL0"),
+    (Encoding::new(3, 3, 14, 12, 2), "PMEVTYPER2_EL0"),
+    (Encoding::new(3, 3, 14, 12, 3), "PMEVTYPER3_EL0"),
+    (Encoding::new(3, 3, 14, 12, 4), "PMEVTYPER4_EL0"),
+    (Encoding::new(3, 3, 14, 12, 5), "PMEVTYPER5_EL0"),
+    (Encoding::new(3, 3, 14, 12, 6), "PMEVTYPER6_EL0"),
+    (Encoding::new(3, 3, 14, 12, 7), "PMEVTYPER7_EL0"),
+    (Encoding::new(3, 3, 14, 13, 0), "PMEVTYPER8_EL0"),
+    (Encoding::new(3, 3, 14, 13, 1), "PMEVTYPER9_EL0"),
+    (Encoding::new(3, 3, 14, 13, 2), "PMEVTYPER10_EL0"),
+    (Encoding::new(3, 3, 14, 13, 3), "PMEVTYPER11_EL0"),
+    (Encoding::new(3, 3, 14, 13, 4), "PMEVTYPER12_EL0"),
+    (Encoding::new(3, 3, 14, 13, 5), "PMEVTYPER13_EL0"),
+    (Encoding::new(3, 3, 14, 13, 6), "PMEVTYPER14_EL0"),
+    (Encoding::new(3, 3, 14, 13, 7), "PMEVTYPER15_EL0"),
+    (Encoding::new(3, 3, 14, 14, 0), "PMEVTYPER16_EL0"),
+    (Encoding::new(3, 3, 14, 14, 1), "PMEVTYPER17_EL0"),
+    (Encoding::new(3, 3, 14, 14, 2), "PMEVTYPER18_EL0"),
+    (Encoding::new(3, 3, 14, 14, 3), "PMEVTYPER19_EL0"),
+    (Encoding::new(3, 3, 14, 14, 4), "PMEVTYPER20_EL0"),
+    (Encoding::new(3, 3, 14, 14, 5), "PMEVTYPER21_EL0"),
+    (Encoding::new(3, 3, 14, 14, 6), "PMEVTYPER22_EL0"),
+    (Encoding::new(3, 3, 14, 14, 7), "PMEVTYPER23_EL0"),
+    (Encoding::new(3, 3, 14, 15, 0), "PMEVTYPER24_EL0"),
+    (Encoding::new(3, 3, 14, 15, 1), "PMEVTYPER25_EL0"),
+    (Encoding::new(3, 3, 14, 15, 2), "PMEVTYPER26_EL0"),
+    (Encoding::new(3, 3, 14, 15, 3), "PMEVTYPER27_EL0"),
+    (Encoding::new(3, 3, 14, 15, 4), "PMEVTYPER28_EL0"),
+    (Encoding::new(3, 3, 14, 15, 5), "PMEVTYPER29_EL0"),
+    (Encoding::new(3, 3, 14, 15, 6), "PMEVTYPER30_EL0"),
+    (Encoding::new(3, 3, 14, 15, 7), "PMCCFILTR_EL0"),
+    (Encoding::new(3, 4, 0, 0, 0), "VPIDR_EL2"),
+    (Encoding::new(3, 4, 0, 0, 5), "VMPIDR_EL2"),
+    (Encoding::new(3, 4, 1, 0, 0), "SCTLR_EL2"),
+    (Encoding::new(3, 4, 1, 0, 1), "ACTLR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 0), "HCR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 1), "MDCR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 2), "CPTR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 3), "HSTR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 4), "HFGRTR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 5), "HFGWTR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 6), "HFGITR_EL2"),
+    (Encoding::new(3, 4, 1, 1, 7), "HACR_EL2"),
+    (Encoding::new(3, 4, 1, 2, 0), "ZCR_EL2"),
+    (Encoding::new(3, 4, 1, 2, 6), "SMCR_EL2"),
+    (Encoding::new(3, 4, 2, 0, 0), "TTBR0_EL2"),
+    (Encoding::new(3, 4, 2, 0, 1), "TTBR1_EL2"),
+    (Encoding::new(3, 4, 2, 0, 2), "TCR_EL2"),
+    (Encoding::new(3, 4, 2, 1, 0), "VTTBR_EL2"),
+    (Encoding::new(3, 4, 2, 1, 2), "VTCR_EL2"),
+    (Encoding::new(3, 4, 3, 0, 0), "DACR32_EL2"),
+    (Encoding::new(3, 4, 4, 0, 0), "SPSR_EL2"),
+    (Encoding::new(3, 4, 4, 0, 1), "ELR_EL2"),
+    (Encoding::new(3, 4, 4, 1, 0), "SP_EL1"),
+    (Encoding::new(3, 4, 4, 3, 0), "SPSR_irq"),
+    (Encoding::new(3, 4, 4, 3, 1), "SPSR_abt"),
+    (Encoding::new(3, 4, 4, 3, 2), "SPSR_und"),
+    (Encoding::new(3, 4, 4, 3, 3), "SPSR_fiq"),
+    (Encoding::new(3, 4, 5, 0, 1), "IFSR32_EL2"),
+    (Encoding::new(3, 4, 5, 1, 0), "AFSR0_EL2"),
+    (Encoding::new(3, 4, 5, 1, 1), "AFSR1_EL2"),
+    (Encoding::new(3, 4, 5, 2, 0), "ESR_EL2"),
+    (Encoding::new(3, 4, 5, 3, 0), "FPEXC32_EL2"),
+    (Encoding::new(3, 4, 6, 0, 0), "FAR_EL2"),
+    (Encoding::new(3, 4, 6, 0, 4), "HPFAR_EL2"),
+    (Encoding::new(3, 4, 10, 2, 0), "MAIR_EL2"),
+    (Encoding::new(3, 4, 10, 3, 0), "AMAIR_EL2"),
+    (Encoding::new(3, 4, 12, 0, 0), "VBAR_EL2"),
+    (Encoding::new(3, 4, 13, 0, 1), "CONTEXTIDR_EL2"),
+    (Encoding::new(3, 4, 13, 0, 2), "TPIDR_EL2"),
+    (Encoding::new(3, 4, 14, 0, 3), "CNTVOFF_EL2"),
+    (Encoding::new(3, 4, 14, 1, 0), "CNTHCTL_EL2"),
+    (Encoding::new(3, 5, 1, 0, 0), "SCTLR_EL12"),
+    (Encoding::new(3, 5, 1, 0, 2), "CPACR_EL12"),
+    (Encoding::new(3, 5, 1, 2, 0), "ZCR_EL12"),
+    (Encoding::new(3, 5, 1, 2, 6), "SMCR_EL12"),
+    (Encoding::new(3, 5, 2, 0, 0), "TTBR0_EL12"),
+    (Encoding::new(3, 5, 2, 0, 1), "TTBR1_EL12"),
+    (Encoding::new(3, 5, 2, 0, 2), "TCR_EL12"),
+    (Encoding::new(3, 5, 4, 0, 0), "SPSR_EL12"),
+    (Encoding::new(3, 5, 4, 0, 1), "ELR_EL12"),
+    (Encoding::new(3, 5, 5, 1, 0), "AFSR0_EL12"),
+    (Encoding::new(3, 5, 5, 1, 1), "AFSR1_EL12"),
+    (Encoding::new(3, 5, 5, 2, 0), "ESR_EL12"),
+    (Encoding::new(3, 5, 5, 6, 0), "TFSR_EL12"),
+    (Encoding::new(3, 5, 6, 0, 0), "FAR_EL12"),
+    (Encoding::new(3, 5, 10, 2, 0), "MAIR_EL12"),
+    (Encoding::new(3, 5, 10, 3, 0), "AMAIR_EL12"),
+    (Encoding::new(3, 5, 12, 0, 0), "VBAR_EL12"),
+    (Encoding::new(3, 5, 13, 0, 1), "CONTEXTIDR_EL12"),
+    (Encoding::new(3, 5, 14, 1, 0), "CNTKCTL_EL12"),
+    (Encoding::new(3, 5, 14, 2, 0), "CNTP_TVAL_EL02"),
+    (Encoding::new(3, 5, 14, 2, 1), "CNTP_CTL_EL02"),
+    (Encoding::new(3, 5, 14, 2, 2), "CNTP_CVAL_EL02"),
+    (Encoding::new(3, 5, 14, 3, 0), "CNTV_TVAL_EL02"),
+    (Encoding::new(3, 5, 14, 3, 1), "CNTV_CTL_EL02"),
+    (Encoding::new(3, 5, 14, 3, 2), "CNTV_CVAL_EL02"),
+    (Encoding::new(3, 6, 1, 0, 0), "SCTLR_EL3"),
+    (Encoding::new(3, 6, 1, 0, 1), "ACTLR_EL3"),
+    (Encoding::new(3, 6, 1, 1, 0), "SCR_EL3"),
+    (Encoding::new(3, 6, 1, 1, 2), "CPTR_EL3"),
+    (Encoding::new(3, 6, 1, 2, 0), "ZCR_EL3"),
+    (Encoding::new(3, 6, 1, 2, 6), "SMCR_EL3"),
+    (Encoding::new(3, 6, 1, 3, 1), "MDCR_EL3"),
+    (Encoding::new(3, 6, 2, 0, 0), "TTBR0_EL3"),
+    (Encoding::new(3, 6, 2, 0, 2), "TCR_EL3"),
+    (Encoding::new(3, 6, 4, 0, 0), "SPSR_EL3"),
+    (Encoding::new(3, 6, 4, 0, 1), "ELR_EL3"),
+    (Encoding::new(3, 6, 5, 1, 0), "AFSR0_EL3"),
+    (Encoding::new(3, 6, 5, 1, 1), "AFSR1_EL3"),
+    (Encoding::new(3, 6, 5, 2, 0), "ESR_EL3"),
+    (Encoding::new(3, 6, 6, 0, 0), "FAR_EL3"),
+    (Encoding::new(3, 6, 10, 2, 0), "MAIR_EL3"),
+    (Encoding::new(3, 6, 12, 0, 0), "VBAR_EL3"),
+    (Encoding::new(3, 6, 12, 0, 1), "RVBAR_EL3"),
+    (Encoding::new(3, 6, 12, 0, 2), "RMR_EL3"),
+    (Encoding::new(3, 6, 13, 0, 2), "TPIDR_EL3"),
+];
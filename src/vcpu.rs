@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Guest EL1 system register context.
+//!
+//! [`GuestSysRegs`] holds the EL1 and EL0 system register state of a vCPU for world switches, and
+//! [`GuestAArch32SysRegs`] the additional state of a vCPU whose EL1 uses AArch32.
+//! Registers that are redirected under VHE are accessed through
+//! [`vhe::guest`](crate::vhe::guest), so the `*_EL12` and `*_EL02` aliases are used when running as
+//! a VHE host. [`vhe::init`](crate::vhe::init) must have been called beforehand.
+//!
+//! All accesses go through a [`Backend`], which allows saving and restoring to be exercised
+//! without hardware.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::{vcpu::GuestSysRegs, vhe};
+//!
+//! vhe::init();
+//!
+//! let mut prev = GuestSysRegs::default();
+//! let next = GuestSysRegs::default();
+//!
+//! prev.save();
+//! unsafe { next.restore() };
+//! ```
+
+use crate::{registers::*, vhe::guest};
+use tock_registers::interfaces::{Readable, Writeable};
+
+/// Access to the system registers of a guest.
+pub trait Backend {
+    /// Read the guest's `reg`.
+    fn read(&self, reg: SysReg) -> u64;
+
+    /// Write `value` to the guest's `reg`.
+    fn write(&mut self, reg: SysReg, value: u64);
+}
+
+/// Accesses the guest's system registers on the executing PE.
+pub struct Hardware;
+
+macro_rules! guest_sys_regs {
+    ($(
+        $(#[$struct_attr:meta])*
+        $struct:ident {
+            $($(#[$attr:meta])* $field:ident: $name:ident => $reg:path,)*
+        }
+    )*) => {
+        /// The system registers held by [`GuestSysRegs`] and [`GuestAArch32SysRegs`].
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        pub enum SysReg {
+            $($($name,)*)*
+        }
+
+        impl SysReg {
+            /// All registers, in the order they are saved and restored.
+            pub const ALL: &'static [SysReg] = &[$($(SysReg::$name,)*)*];
+        }
+
+        $(
+            $(#[$struct_attr])*
+            #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+            pub struct $struct {
+                $($(#[$attr])* pub $field: u64,)*
+            }
+
+            impl $struct {
+                /// Save the guest's registers through `backend`.
+                pub fn save_with<B: Backend>(&mut self, backend: &B) {
+                    $(self.$field = backend.read(SysReg::$name);)*
+                }
+
+                /// Restore the guest's registers through `backend`.
+                pub fn restore_with<B: Backend>(&self, backend: &mut B) {
+                    $(backend.write(SysReg::$name, self.$field);)*
+                }
+            }
+        )*
+
+        impl Backend for Hardware {
+            fn read(&self, reg: SysReg) -> u64 {
+                match reg {
+                    $($(SysReg::$name => $reg.get(),)*)*
+                }
+            }
+
+            fn write(&mut self, reg: SysReg, value: u64) {
+                match reg {
+                    $($(SysReg::$name => $reg.set(value),)*)*
+                }
+            }
+        }
+    };
+}
+
+guest_sys_regs! {
+    /// EL1 and EL0 system register state of a guest.
+    GuestSysRegs {
+        sctlr_el1: SCTLR_EL1 => guest::SCTLR_EL1,
+        actlr_el1: ACTLR_EL1 => ACTLR_EL1,
+        cpacr_el1: CPACR_EL1 => guest::CPACR_EL1,
+        ttbr0_el1: TTBR0_EL1 => guest::TTBR0_EL1,
+        ttbr1_el1: TTBR1_EL1 => guest::TTBR1_EL1,
+        tcr_el1: TCR_EL1 => guest::TCR_EL1,
+        mair_el1: MAIR_EL1 => guest::MAIR_EL1,
+        amair_el1: AMAIR_EL1 => guest::AMAIR_EL1,
+        vbar_el1: VBAR_EL1 => guest::VBAR_EL1,
+        contextidr_el1: CONTEXTIDR_EL1 => guest::CONTEXTIDR_EL1,
+        esr_el1: ESR_EL1 => guest::ESR_EL1,
+        far_el1: FAR_EL1 => guest::FAR_EL1,
+        afsr0_el1: AFSR0_EL1 => guest::AFSR0_EL1,
+        afsr1_el1: AFSR1_EL1 => guest::AFSR1_EL1,
+        par_el1: PAR_EL1 => PAR_EL1,
+        elr_el1: ELR_EL1 => guest::ELR_EL1,
+        spsr_el1: SPSR_EL1 => guest::SPSR_EL1,
+        sp_el0: SP_EL0 => SP_EL0,
+        sp_el1: SP_EL1 => SP_EL1,
+        tpidr_el0: TPIDR_EL0 => TPIDR_EL0,
+        tpidrro_el0: TPIDRRO_EL0 => TPIDRRO_EL0,
+        tpidr_el1: TPIDR_EL1 => TPIDR_EL1,
+        csselr_el1: CSSELR_EL1 => CSSELR_EL1,
+        mdscr_el1: MDSCR_EL1 => MDSCR_EL1,
+        cntkctl_el1: CNTKCTL_EL1 => guest::CNTKCTL_EL1,
+        cntp_ctl_el0: CNTP_CTL_EL0 => guest::CNTP_CTL_EL0,
+        cntp_cval_el0: CNTP_CVAL_EL0 => guest::CNTP_CVAL_EL0,
+        cntv_ctl_el0: CNTV_CTL_EL0 => guest::CNTV_CTL_EL0,
+        cntv_cval_el0: CNTV_CVAL_EL0 => guest::CNTV_CVAL_EL0,
+    }
+
+    /// System register state of an AArch32 EL1 guest that has no AArch64 equivalent.
+    ///
+    /// The other AArch32 registers are architecturally mapped to the registers in
+    /// [`GuestSysRegs`], which must be switched as well. The SPSRs of the banked modes hold the
+    /// AArch32 format of [`SPSR_EL2`](mod@SPSR_EL2).
+    GuestAArch32SysRegs {
+        dacr32_el2: DACR32_EL2 => DACR32_EL2,
+        ifsr32_el2: IFSR32_EL2 => IFSR32_EL2,
+        fpexc32_el2: FPEXC32_EL2 => FPEXC32_EL2,
+        spsr_abt: SPSR_abt => SPSR_abt,
+        spsr_und: SPSR_und => SPSR_und,
+        spsr_irq: SPSR_irq => SPSR_irq,
+        spsr_fiq: SPSR_fiq => SPSR_fiq,
+    }
+}
+
+impl GuestSysRegs {
+    /// Save the guest's registers from the executing PE.
+    pub fn save(&mut self) {
+        self.save_with(&Hardware);
+    }
+
+    /// Restore the guest's registers to the executing PE.
+    ///
+    /// # Safety
+    ///
+    /// Must be called at EL2, and the values must have been saved from, or be valid for, the
+    /// guest. In particular, the registers of a guest that is not running must not alias the
+    /// state of the running kernel, which is the case at EL1 and in a VHE host for registers that
+    /// are not redirected, such as `TPIDR_EL1`.
+    pub unsafe fn restore(&self) {
+        self.restore_with(&mut Hardware);
+    }
+}
+
+impl GuestAArch32SysRegs {
+    /// Save the guest's registers from the executing PE.
+    ///
+    /// Must be called at EL2 on a PE that supports AArch32 at EL1, as the registers are UNDEFINED
+    /// otherwise.
+    pub fn save(&mut self) {
+        self.save_with(&Hardware);
+    }
+
+    /// Restore the guest's registers to the executing PE.
+    ///
+    /// # Safety
+    ///
+    /// Must be called at EL2 on a PE that supports AArch32 at EL1, and the values must have been
+    /// saved from, or be valid for, the guest.
+    pub unsafe fn restore(&self) {
+        self.restore_with(&mut Hardware);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Mock {
+        regs: [u64; SysReg::ALL.len()],
+    }
+
+    impl Backend for Mock {
+        fn read(&self, reg: SysReg) -> u64 {
+            self.regs[reg as usize]
+        }
+
+        fn write(&mut self, reg: SysReg, value: u64) {
+            self.regs[reg as usize] = value;
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut src = Mock {
+            regs: [0; SysReg::ALL.len()],
+        };
+        for (i, reg) in SysReg::ALL.iter().enumerate() {
+            src.regs[*reg as usize] = 0x1000_0000_0000_0000 | (i as u64 + 1);
+        }
+
+        let mut saved = GuestSysRegs::default();
+        saved.save_with(&src);
+        let mut saved32 = GuestAArch32SysRegs::default();
+        saved32.save_with(&src);
+        assert_eq!(saved32.spsr_fiq, src.read(SysReg::SPSR_fiq));
+
+        let mut dst = Mock {
+            regs: [0; SysReg::ALL.len()],
+        };
+        saved.restore_with(&mut dst);
+        saved32.restore_with(&mut dst);
+
+        for reg in SysReg::ALL {
+            assert_eq!(dst.read(*reg), src.read(*reg), "{:?}", reg);
+        }
+
+        let mut resaved = GuestSysRegs::default();
+        resaved.save_with(&dst);
+        assert_eq!(resaved, saved);
+    }
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtualization Host Extensions.
+//!
+//! With `HCR_EL2.E2H` set, a kernel written for EL1 can run at EL2: most of its `*_EL1` and
+//! `*_EL0` register accesses are redirected to the EL2 counterparts, while the `*_EL12` and
+//! `*_EL02` aliases reach the EL1 and EL0 state of a guest.
+//!
+//! This module records the mode the kernel runs in and provides registers that pick the right
+//! encoding for it:
+//!
+//! - [`TPIDR`] is the thread ID register of the kernel itself, which is not redirected.
+//! - [`guest`] holds the EL1 and EL0 registers of a guest, for a hypervisor that runs either at EL2
+//!   with or without VHE.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::vhe;
+//! use tock_registers::interfaces::{Readable, Writeable};
+//!
+//! vhe::init();
+//!
+//! // Works at EL1 and at EL2 with VHE.
+//! let per_cpu = vhe::TPIDR.get();
+//!
+//! // Switch to a guest.
+//! vhe::guest::SCTLR_EL1.set(0x30d0_0800);
+//! vhe::guest::VBAR_EL1.set(0xffff_0000_0000_0800);
+//! ```
+
+use crate::{
+    asm::barrier,
+    registers::{CurrentEL, HCR_EL2, ID_AA64MMFR1_EL1},
+};
+use core::sync::atomic::{AtomicU8, Ordering};
+use tock_registers::interfaces::{ReadWriteable, Readable};
+
+/// The Exception level and configuration the kernel runs in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// At EL1.
+    El1,
+    /// At EL2, with `HCR_EL2.E2H` clear.
+    El2,
+    /// At EL2, with `HCR_EL2.E2H` set.
+    El2Host,
+}
+
+impl Mode {
+    const fn from_u8(value: u8) -> Mode {
+        match value {
+            1 => Mode::El2,
+            2 => Mode::El2Host,
+            _ => Mode::El1,
+        }
+    }
+}
+
+static MODE: AtomicU8 = AtomicU8::new(Mode::El1 as u8);
+
+/// Return whether the Virtualization Host Extensions are implemented.
+pub fn is_implemented() -> bool {
+    ID_AA64MMFR1_EL1.read(ID_AA64MMFR1_EL1::VH) != 0
+}
+
+/// Return the mode the kernel currently runs in.
+///
+/// Must be called at EL1 or EL2.
+pub fn current_mode() -> Mode {
+    match CurrentEL.read_as_enum(CurrentEL::EL) {
+        Some(CurrentEL::EL::Value::EL2) if HCR_EL2.is_set(HCR_EL2::E2H) => Mode::El2Host,
+        Some(CurrentEL::EL::Value::EL2) => Mode::El2,
+        _ => Mode::El1,
+    }
+}
+
+/// Record the mode the kernel runs in, for use by the registers of this module, and return it.
+///
+/// Must be called on every core before the registers of this module are used, and again after
+/// `HCR_EL2.E2H` is changed other than through [`enable`].
+pub fn init() -> Mode {
+    let mode = current_mode();
+    MODE.store(mode as u8, Ordering::Relaxed);
+
+    mode
+}
+
+/// Return the mode recorded by [`init`].
+///
+/// Defaults to [`Mode::El1`] before [`init`] is called.
+#[inline(always)]
+pub fn mode() -> Mode {
+    Mode::from_u8(MODE.load(Ordering::Relaxed))
+}
+
+/// Set `HCR_EL2.E2H` to run the kernel as a VHE host at EL2.
+///
+/// # Safety
+///
+/// Must be called at EL2 with VHE implemented. The MMU and the exception vectors must be
+/// configured through the redirected `*_EL1` registers, i.e. with the EL2 register layouts that
+/// apply when `HCR_EL2.E2H` is set.
+pub unsafe fn enable() {
+    HCR_EL2.modify(HCR_EL2::E2H::EnableOsAtEl2);
+    barrier::isb(barrier::SY);
+
+    MODE.store(Mode::El2Host as u8, Ordering::Relaxed);
+}
+
+macro_rules! select_reg {
+    ($(#[$attr:meta])* $module:ident, $name:ident, $R:ty, $el1:ident, $el2:ident, $use_el2:expr) => {
+        mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+            use crate::registers as regs;
+            use tock_registers::interfaces::{Readable, Writeable};
+
+            pub struct Reg;
+
+            impl Readable for Reg {
+                type T = u64;
+                type R = $R;
+
+                #[inline]
+                fn get(&self) -> u64 {
+                    if $use_el2 {
+                        regs::$el2.get()
+                    } else {
+                        regs::$el1.get()
+                    }
+                }
+            }
+
+            impl Writeable for Reg {
+                type T = u64;
+                type R = $R;
+
+                #[inline]
+                fn set(&self, value: u64) {
+                    if $use_el2 {
+                        regs::$el2.set(value)
+                    } else {
+                        regs::$el1.set(value)
+                    }
+                }
+            }
+
+            $(#[$attr])*
+            pub const $name: Reg = Reg {};
+        }
+
+        pub use $module::$name;
+    };
+}
+
+select_reg!(
+    /// Software Thread ID Register of the kernel: `TPIDR_EL1` at EL1 and `TPIDR_EL2` at EL2.
+    tpidr,
+    TPIDR,
+    (),
+    TPIDR_EL1,
+    TPIDR_EL2,
+    mode() != Mode::El1
+);
+
+/// EL1 and EL0 registers of a guest.
+///
+/// Accessed through the `*_EL12` and `*_EL02` aliases in [`Mode::El2Host`], and through the
+/// registers of the same name otherwise. Each register uses the bitfields of the register of the
+/// same name.
+pub mod guest {
+    use super::{mode, Mode};
+
+    macro_rules! guest_reg {
+        ($module:ident, $name:ident, $R:ty, $alias:ident) => {
+            select_reg!($module, $name, $R, $name, $alias, mode() == Mode::El2Host);
+        };
+    }
+
+    guest_reg!(sctlr_el1, SCTLR_EL1, regs::SCTLR_EL1::Register, SCTLR_EL12);
+    guest_reg!(cpacr_el1, CPACR_EL1, regs::CPACR_EL1::Register, CPACR_EL12);
+    guest_reg!(zcr_el1, ZCR_EL1, regs::ZCR_EL1::Register, ZCR_EL12);
+    guest_reg!(smcr_el1, SMCR_EL1, regs::SMCR_EL1::Register, SMCR_EL12);
+    guest_reg!(ttbr0_el1, TTBR0_EL1, regs::TTBR0_EL1::Register, TTBR0_EL12);
+    guest_reg!(ttbr1_el1, TTBR1_EL1, regs::TTBR1_EL1::Register, TTBR1_EL12);
+    guest_reg!(tcr_el1, TCR_EL1, regs::TCR_EL1::Register, TCR_EL12);
+    guest_reg!(spsr_el1, SPSR_EL1, regs::SPSR_EL1::Register, SPSR_EL12);
+    guest_reg!(elr_el1, ELR_EL1, (), ELR_EL12);
+    guest_reg!(afsr0_el1, AFSR0_EL1, (), AFSR0_EL12);
+    guest_reg!(afsr1_el1, AFSR1_EL1, (), AFSR1_EL12);
+    guest_reg!(esr_el1, ESR_EL1, regs::ESR_EL1::Register, ESR_EL12);
+    guest_reg!(tfsr_el1, TFSR_EL1, regs::TFSR_EL1::Register, TFSR_EL12);
+    guest_reg!(far_el1, FAR_EL1, (), FAR_EL12);
+    guest_reg!(mair_el1, MAIR_EL1, regs::MAIR_EL1::Register, MAIR_EL12);
+    guest_reg!(amair_el1, AMAIR_EL1, (), AMAIR_EL12);
+    guest_reg!(vbar_el1, VBAR_EL1, (), VBAR_EL12);
+    guest_reg!(
+        contextidr_el1,
+        CONTEXTIDR_EL1,
+        regs::CONTEXTIDR_EL1::Register,
+        CONTEXTIDR_EL12
+    );
+    guest_reg!(
+        cntkctl_el1,
+        CNTKCTL_EL1,
+        regs::CNTKCTL_EL1::Register,
+        CNTKCTL_EL12
+    );
+    guest_reg!(cntp_tval_el0, CNTP_TVAL_EL0, (), CNTP_TVAL_EL02);
+    guest_reg!(
+        cntp_ctl_el0,
+        CNTP_CTL_EL0,
+        regs::CNTP_CTL_EL0::Register,
+        CNTP_CTL_EL02
+    );
+    guest_reg!(cntp_cval_el0, CNTP_CVAL_EL0, (), CNTP_CVAL_EL02);
+    guest_reg!(cntv_tval_el0, CNTV_TVAL_EL0, (), CNTV_TVAL_EL02);
+    guest_reg!(
+        cntv_ctl_el0,
+        CNTV_CTL_EL0,
+        regs::CNTV_CTL_EL0::Register,
+        CNTV_CTL_EL02
+    );
+    guest_reg!(cntv_cval_el0, CNTV_CVAL_EL0, (), CNTV_CVAL_EL02);
+}
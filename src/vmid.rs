@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//
+// Copyright (c) 2018-2022 by the author(s)
+//
+// Author(s):
+//   - Andre Richter <andre.o.richter@gmail.com>
+
+//! Virtual Machine IDentifier allocation.
+//!
+//! [`Allocator`] hands out VMIDs to the stage 2 address spaces of [`Vm`]s, with the same
+//! generation and rollover semantics as the [ASID allocator](crate::asid). A rollover invalidates
+//! the TLB entries of all VMIDs with `TLBI ALLE1IS`, as `TLBI VMALLS12E1IS` only affects the VMID
+//! currently held in `VTTBR_EL2`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use cortex_a::vmid::{Allocator, Vm, VmidBits};
+//!
+//! static VMIDS: Allocator<4> = Allocator::new();
+//!
+//! // On every CPU.
+//! let bits = VmidBits::supported();
+//! unsafe { bits.configure() };
+//!
+//! // Once.
+//! VMIDS.init(bits);
+//!
+//! # let cpu = 0;
+//! # let table = 0x8_0000;
+//! let vm = Vm::new(table);
+//!
+//! // Before entering a vCPU of `vm`, with exceptions masked.
+//! VMIDS.activate(cpu, &vm);
+//! ```
+
+use crate::{
+    asid::{self, AsidBits},
+    asm::barrier,
+    registers::{ID_AA64MMFR1_EL1, VTCR_EL2, VTTBR_EL2},
+};
+use core::sync::atomic::{AtomicU64, Ordering};
+use tock_registers::interfaces::{ReadWriteable, Readable, Writeable};
+
+/// Size of the VMIDs supported by the implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VmidBits {
+    Bits8,
+    Bits16,
+}
+
+impl VmidBits {
+    /// Return the VMID size supported by the executing PE, as reported by
+    /// `ID_AA64MMFR1_EL1.VMIDBits`.
+    pub fn supported() -> VmidBits {
+        match ID_AA64MMFR1_EL1.read_as_enum(ID_AA64MMFR1_EL1::VMIDBits) {
+            Some(ID_AA64MMFR1_EL1::VMIDBits::Value::Bits16) => VmidBits::Bits16,
+            _ => VmidBits::Bits8,
+        }
+    }
+
+    /// Return the number of VMIDs, including the reserved VMID 0.
+    pub const fn count(self) -> usize {
+        match self {
+            VmidBits::Bits8 => 1 << 8,
+            VmidBits::Bits16 => 1 << 16,
+        }
+    }
+
+    /// Select the VMID size in `VTCR_EL2.VS` of the executing PE.
+    ///
+    /// # Safety
+    ///
+    /// Must be called at EL2 with no guest running, and the size must be supported.
+    pub unsafe fn configure(self) {
+        VTCR_EL2.modify(match self {
+            VmidBits::Bits8 => VTCR_EL2::VS::Bits8,
+            VmidBits::Bits16 => VTCR_EL2::VS::Bits16,
+        });
+        barrier::isb(barrier::SY);
+    }
+}
+
+/// A virtual machine whose stage 2 address space is tagged with a VMID while it is active.
+#[derive(Debug)]
+pub struct Vm {
+    context: AtomicU64,
+    table: u64,
+}
+
+impl Vm {
+    /// Create a virtual machine with the stage 2 translation table at physical address `table`,
+    /// which has not been assigned a VMID yet.
+    pub const fn new(table: u64) -> Vm {
+        Vm {
+            context: AtomicU64::new(0),
+            table,
+        }
+    }
+
+    /// Return the physical address of the stage 2 translation table.
+    pub fn table(&self) -> u64 {
+        self.table
+    }
+
+    /// Return the VMID last assigned to the virtual machine, which is only valid while it is
+    /// active.
+    pub fn vmid(&self) -> Option<u16> {
+        match self.context.load(Ordering::Relaxed) {
+            0 => None,
+            context => Some(context as u16),
+        }
+    }
+}
+
+/// A VMID allocator for up to `CPUS` CPUs.
+///
+/// VMID 0 is never handed out, and can be used by the host.
+#[derive(Default)]
+pub struct Allocator<const CPUS: usize> {
+    inner: asid::Allocator<CPUS>,
+}
+
+impl<const CPUS: usize> Allocator<CPUS> {
+    /// Create an allocator that uses 8-bit VMIDs until [`Allocator::init`] is called.
+    pub const fn new() -> Allocator<CPUS> {
+        Allocator {
+            inner: asid::Allocator::new(),
+        }
+    }
+
+    /// Set the size of the VMIDs to hand out, which must have been configured with
+    /// [`VmidBits::configure`] on all CPUs.
+    ///
+    /// Must be called before the first VMID is allocated.
+    pub fn init(&self, bits: VmidBits) {
+        self.inner.init(match bits {
+            VmidBits::Bits8 => AsidBits::Bits8,
+            VmidBits::Bits16 => AsidBits::Bits16,
+        });
+    }
+
+    /// Return the VMID that `vm` is to be tagged with when activated on `cpu`.
+    ///
+    /// Calls `flush` with the allocator locked when a new generation begins. `flush` must then
+    /// invalidate all stage 1 and stage 2 EL1&0 TLB entries of all CPUs, and complete before it
+    /// returns.
+    ///
+    /// Must be called with exceptions masked, and the returned VMID must be used on `cpu` until
+    /// the next allocation on it.
+    pub fn allocate<F: FnOnce()>(&self, cpu: usize, vm: &Vm, flush: F) -> u16 {
+        self.inner.allocate_context(cpu, &vm.context, flush) as u16
+    }
+
+    /// Switch `cpu` to the stage 2 address space of `vm`, which must be the executing CPU.
+    ///
+    /// Allocates a VMID for `vm` and writes it to `VTTBR_EL2` together with the address of the
+    /// translation table, after all TLB maintenance of a rollover has completed.
+    ///
+    /// Must be called at EL2 with exceptions masked. The translation table must have been made
+    /// visible to the table walker beforehand.
+    pub fn activate(&self, cpu: usize, vm: &Vm) {
+        let vmid = self.allocate(cpu, vm, flush_tlb);
+
+        VTTBR_EL2.write(VTTBR_EL2::VMID.val(vmid as u64) + VTTBR_EL2::BADDR.val(vm.table >> 1));
+        barrier::isb(barrier::SY);
+    }
+}
+
+/// Invalidate all stage 1 and stage 2 EL1&0 TLB entries of the Inner Shareable domain.
+fn flush_tlb() {
+    match () {
+        #[cfg(target_arch = "aarch64")]
+        () => unsafe {
+            core::arch::asm!(
+                "dsb ishst",
+                "tlbi alle1is",
+                "dsb ish",
+                "isb",
+                options(nostack)
+            )
+        },
+
+        #[cfg(not(target_arch = "aarch64"))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::{boxed::Box, vec::Vec};
+
+    #[test]
+    fn rollover() {
+        let vmids: Box<Allocator<2>> = Box::default();
+        let vms: Vec<Vm> = (0..512).map(|i| Vm::new(i * 0x1000)).collect();
+        let mut flushes = 0;
+
+        let kept = vmids.allocate(1, &vms[0], || flushes += 1);
+        for (i, vm) in vms[1..255].iter().enumerate() {
+            assert_eq!(vmids.allocate(0, vm, || flushes += 1), i as u16 + 2);
+        }
+        assert_eq!(flushes, 0);
+
+        for vm in vms[255..].iter() {
+            let vmid = vmids.allocate(0, vm, || flushes += 1);
+            assert_ne!(vmid, 0);
+            assert_ne!(vmid, kept);
+        }
+        assert_eq!(flushes, 2);
+
+        // Still running on CPU 1.
+        assert_eq!(vmids.allocate(1, &vms[0], || flushes += 1), kept);
+        assert_eq!(vms[0].vmid(), Some(kept));
+    }
+
+    #[test]
+    fn sixteen_bits() {
+        let vmids: Box<Allocator<1>> = Box::default();
+        vmids.init(VmidBits::Bits16);
+
+        for i in 0..1000 {
+            let vm = Vm::new(0);
+            assert_eq!(vmids.allocate(0, &vm, || panic!("rollover")), i + 1);
+        }
+    }
+}